@@ -44,6 +44,20 @@ pub enum Descriptor {
     Result(Box<Descriptor>),
     Unit,
     NonNull,
+    Tuple(Vec<Descriptor>),
+    Map {
+        key: Box<Descriptor>,
+        value: Box<Descriptor>,
+        /// Whether the map is represented as a plain JS object instead of a
+        /// `Map`, as requested with `#[wasm_bindgen(map_as = "object")]`.
+        as_object: bool,
+    },
+    /// A JS iterable iterator, or an async one if `is_async`, yielding the
+    /// items of a Rust iterator or stream.
+    Iterator {
+        item: Box<Descriptor>,
+        is_async: bool,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -150,6 +164,24 @@ impl Descriptor {
             UNIT => Descriptor::Unit,
             CLAMPED => Descriptor::_decode(data, true),
             NONNULL => Descriptor::NonNull,
+            TUPLE => {
+                let len = get(data);
+                let elements = (0..len).map(|_| Descriptor::_decode(data, false)).collect();
+                Descriptor::Tuple(elements)
+            }
+            MAP => Descriptor::Map {
+                key: Box::new(Descriptor::_decode(data, false)),
+                value: Box::new(Descriptor::_decode(data, false)),
+                as_object: false,
+            },
+            ITERATOR => Descriptor::Iterator {
+                item: Box::new(Descriptor::_decode(data, false)),
+                is_async: false,
+            },
+            ASYNC_ITERATOR => Descriptor::Iterator {
+                item: Box::new(Descriptor::_decode(data, false)),
+                is_async: true,
+            },
             other => panic!("unknown descriptor: {other}"),
         }
     }
@@ -161,6 +193,19 @@ impl Descriptor {
         }
     }
 
+    /// Switches maps passed directly as this type, or wrapped in an `Option`,
+    /// `Result` or reference, to be represented as plain JS objects.
+    fn map_as_object(&mut self) {
+        match self {
+            Descriptor::Map { as_object, .. } => *as_object = true,
+            Descriptor::Option(d)
+            | Descriptor::Result(d)
+            | Descriptor::Ref(d)
+            | Descriptor::RefMut(d) => d.map_as_object(),
+            _ => {}
+        }
+    }
+
     pub fn vector_kind(&self) -> Option<VectorKind> {
         let inner = match *self {
             Descriptor::String | Descriptor::CachedString => return Some(VectorKind::String),
@@ -225,6 +270,18 @@ impl Closure {
 }
 
 impl Function {
+    /// Represents all maps in the arguments and return value of this function
+    /// as plain JS objects instead of `Map`s.
+    pub fn map_as_object(&mut self) {
+        for arg in self.arguments.iter_mut() {
+            arg.map_as_object();
+        }
+        self.ret.map_as_object();
+        if let Some(inner_ret) = &mut self.inner_ret {
+            inner_ret.map_as_object();
+        }
+    }
+
     fn decode(data: &mut &[u32]) -> Function {
         let shim_idx = get(data);
         let arguments = (0..get(data))
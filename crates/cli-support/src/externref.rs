@@ -154,6 +154,9 @@ fn import_xform(
                     externref: None,
                 }));
             }
+            // Maps passed as plain objects are still owned externref
+            // arguments, only converted on the JS side.
+            Instruction::ObjectFromMap { .. } => {}
             _ => match instr.stack_change {
                 StackChange::Modified { pushed, popped } => {
                     for _ in 0..popped {
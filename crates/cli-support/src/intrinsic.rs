@@ -90,5 +90,7 @@ intrinsics! {
         ClockNs = "__wbindgen_clock_ns",
         SpinTimeout = "__wbindgen_spin_timeout",
         AtomicsPause = "__wbindgen_atomics_pause",
+        MakeIterator = "__wbindgen_make_iterator",
+        MakeAsyncIterator = "__wbindgen_make_async_iterator",
     }
 }
@@ -33,6 +33,12 @@ pub struct Builder<'a, 'b> {
     catch: bool,
     /// Whether or not we're logging the error coming out of this intrinsic
     log_error: bool,
+    /// Whether this is the shim of a JSPI `suspending` import.
+    suspending: bool,
+    /// Whether this is the shim of a JSPI `promising` export.
+    promising: bool,
+    /// The error class thrown by this export, documented with `@throws`.
+    throws: Option<String>,
 }
 
 /// Helper struct used to create JS to process all instructions in an adapter
@@ -63,6 +69,11 @@ pub struct JsBuilder<'a, 'b> {
     /// use to translate the `arg.get` instruction.
     args: Vec<String>,
 
+    /// Whether the main function invocation returns a promise that has to be
+    /// awaited, which is the case for JSPI `suspending` imports and
+    /// `promising` exports.
+    suspend: bool,
+
     /// The Wasm interface types "stack". The expressions pushed onto this stack
     /// are intended to be *pure*, and if they're not, they should be pushed
     /// into the `prelude`, assigned to a variable, and the variable should be
@@ -125,6 +136,9 @@ impl<'a, 'b> Builder<'a, 'b> {
             method: None,
             classless_this: false,
             catch: false,
+            suspending: false,
+            promising: false,
+            throws: None,
         }
     }
 
@@ -148,6 +162,18 @@ impl<'a, 'b> Builder<'a, 'b> {
         self.log_error = log;
     }
 
+    pub fn suspending(&mut self, suspending: bool) {
+        self.suspending = suspending;
+    }
+
+    pub fn promising(&mut self, promising: bool) {
+        self.promising = promising;
+    }
+
+    pub fn throws(&mut self, throws: Option<String>) {
+        self.throws = throws;
+    }
+
     pub fn process(
         &mut self,
         adapter: &Adapter,
@@ -178,6 +204,7 @@ impl<'a, 'b> Builder<'a, 'b> {
         // method, so the leading parameter is the this pointer stored on
         // the JS object, so synthesize that here.
         let mut js = JsBuilder::new(self.cx, debug_name);
+        js.suspend = self.suspending || self.promising;
         if let Some(consumes_self) = self.method {
             let _ = params.next();
             if js.cx.config.generate_reset_state {
@@ -296,6 +323,16 @@ impl<'a, 'b> Builder<'a, 'b> {
             js.pre_try + &js.prelude
         };
 
+        // Rust's stack in linear memory isn't switched by JSPI, so `promising`
+        // calls are queued to run one at a time. Arguments are only lowered
+        // into Wasm memory once it's their turn.
+        let call = if self.promising {
+            js.cx.expose_call_promising();
+            format!("return queuePromising(async () => {{\n{call}}});\n")
+        } else {
+            call
+        };
+
         // Generate a try/catch block in debug mode which handles unexpected and
         // unhandled exceptions, typically used on imports. This currently just
         // logs what happened, but keeps the exception being thrown to propagate
@@ -608,6 +645,12 @@ impl<'a, 'b> Builder<'a, 'b> {
                 ret.push_str(v);
             }
         }
+        if let Some(throws) = &self.throws {
+            if !ret.is_empty() && !ret.ends_with('\n') {
+                ret.push('\n');
+            }
+            ret.push_str(&format!("@throws {{{throws}}}"));
+        }
         ret
     }
 
@@ -637,6 +680,12 @@ impl<'a, 'b> Builder<'a, 'b> {
             ts_doc.push_str("@returns ");
             ts_doc.push_str(ret_desc);
         }
+        if let Some(throws) = &self.throws {
+            if !ts_doc.is_empty() && !ts_doc.ends_with('\n') {
+                ts_doc.push('\n');
+            }
+            ts_doc.push_str(&format!("@throws {{{throws}}}"));
+        }
         ts_doc
     }
 }
@@ -647,6 +696,7 @@ impl<'a, 'b> JsBuilder<'a, 'b> {
             cx,
             debug_name,
             args: Vec::new(),
+            suspend: false,
             tmp: 0,
             pre_try: String::new(),
             finally: String::new(),
@@ -904,6 +954,7 @@ fn instruction(
                 &mut js.prelude,
                 log_error,
                 &mut should_check_aborted,
+                js.suspend,
             )?;
 
             // And then figure out how to actually handle where the call
@@ -1174,6 +1225,26 @@ fn instruction(
             }
         }
 
+        Instruction::MapFromObject { key, optional } => {
+            let val = js.pop();
+            let i = js.tmp();
+            js.prelude(&format!("const obj{i} = {val};"));
+            // Object keys are always strings, so convert them back first.
+            let entries = match key {
+                AdapterType::String => format!("Object.entries(obj{i})"),
+                AdapterType::S64 | AdapterType::U64 | AdapterType::S128 | AdapterType::U128 => {
+                    format!("Object.entries(obj{i}).map(([k, v]) => [BigInt(k), v])")
+                }
+                _ => format!("Object.entries(obj{i}).map(([k, v]) => [Number(k), v])"),
+            };
+            if *optional {
+                js.cx.expose_is_like_none();
+                js.push(format!("isLikeNone(obj{i}) ? obj{i} : new Map({entries})"));
+            } else {
+                js.push(format!("new Map({entries})"));
+            }
+        }
+
         Instruction::I32FromOptionU32Sentinel => {
             let val = js.pop();
             js.cx.expose_is_like_none();
@@ -1404,6 +1475,19 @@ fn instruction(
             js.push(format!("{take_object}({val})"));
         }
 
+        Instruction::ObjectFromMap { optional } => {
+            let val = js.pop();
+            if *optional {
+                let i = js.tmp();
+                js.prelude(&format!("const map{i} = {val};"));
+                js.push(format!(
+                    "map{i} === undefined ? undefined : Object.fromEntries(map{i})"
+                ));
+            } else {
+                js.push(format!("Object.fromEntries({val})"));
+            }
+        }
+
         Instruction::StringFromChar => {
             let val = js.pop();
             js.push(format!("String.fromCodePoint({val})"));
@@ -1744,13 +1828,27 @@ impl Invocation {
         prelude: &mut String,
         log_error: &mut bool,
         handle_error: &mut bool,
+        suspend: bool,
     ) -> Result<String, Error> {
         match self {
-            Invocation::Core { id, export_id, .. } => {
+            Invocation::Core {
+                id,
+                export_id,
+                defer,
+            } => {
                 let name = match export_id {
                     Some(eid) => cx.module.exports.get(*eid).name.clone(),
                     None => cx.export_name_of(*id),
                 };
+                if suspend && !defer {
+                    // JSPI: run the export on a new stack that can be
+                    // suspended by `suspending` imports.
+                    cx.expose_call_promising();
+                    return Ok(format!(
+                        "await callPromising(wasm.{name}, [{}])",
+                        args.join(", ")
+                    ));
+                }
                 Ok(format!("wasm.{name}({})", args.join(", ")))
             }
             Invocation::Adapter(id) => {
@@ -1769,7 +1867,12 @@ impl Invocation {
                 if cx.import_never_handle_error(import) {
                     *handle_error = false;
                 }
-                cx.invoke_import(import, kind, args, variadic, prelude)
+                let call = cx.invoke_import(import, kind, args, variadic, prelude)?;
+                if suspend {
+                    Ok(format!("await {call}"))
+                } else {
+                    Ok(call)
+                }
             }
         }
     }
@@ -1783,12 +1886,12 @@ impl Invocation {
 }
 
 #[derive(Debug, Clone, Copy)]
-enum TypePosition {
+pub enum TypePosition {
     Argument,
     Return,
 }
 
-fn adapter2ts(
+pub fn adapter2ts(
     ty: &AdapterType,
     position: TypePosition,
     dst: &mut String,
@@ -1836,5 +1939,53 @@ fn adapter2ts(
             dst.push_str(name);
         }
         AdapterType::Function => dst.push_str("any"),
+        AdapterType::Tuple(tys) => {
+            dst.push('[');
+            let mut refs = refs;
+            for (i, ty) in tys.iter().enumerate() {
+                if i != 0 {
+                    dst.push_str(", ");
+                }
+                // Elements are converted with `JsValue::from` and
+                // `TryFromJsValue`, which only use `undefined` for `None`.
+                adapter2ts(ty, TypePosition::Return, dst, refs.as_deref_mut(), name_map);
+            }
+            dst.push(']');
+        }
+        AdapterType::Map {
+            key,
+            value,
+            as_object,
+        } => {
+            let mut refs = refs;
+            dst.push_str(if *as_object { "Record<" } else { "Map<" });
+            match &**key {
+                // Plain objects turn `bigint` keys into strings.
+                AdapterType::S64 | AdapterType::U64 | AdapterType::S128 | AdapterType::U128
+                    if *as_object =>
+                {
+                    dst.push_str("string")
+                }
+                key => adapter2ts(
+                    key,
+                    TypePosition::Return,
+                    dst,
+                    refs.as_deref_mut(),
+                    name_map,
+                ),
+            }
+            dst.push_str(", ");
+            adapter2ts(value, TypePosition::Return, dst, refs, name_map);
+            dst.push('>');
+        }
+        AdapterType::Iterator { item, is_async } => {
+            dst.push_str(if *is_async {
+                "AsyncIterableIterator<"
+            } else {
+                "IterableIterator<"
+            });
+            adapter2ts(item, TypePosition::Return, dst, refs, name_map);
+            dst.push('>');
+        }
     }
 }
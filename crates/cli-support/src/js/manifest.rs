@@ -0,0 +1,502 @@
+//! A JSON manifest describing the interface of the generated bindings, for
+//! tooling which would otherwise have to parse the generated TypeScript.
+
+use super::binding::{adapter2ts, TypePosition};
+use super::{iter_adapter, Context, ContextAdapterKind};
+use crate::wit::{
+    Adapter, AdapterJsImportKind, AdapterKind, AdapterType, AuxExport, AuxExportKind,
+    AuxExportedMethodKind, AuxImport, AuxTraitMethod, AuxValue, Instruction, JsImport,
+    JsImportName,
+};
+use anyhow::Error;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Bumped whenever the format of the manifest changes incompatibly.
+const MANIFEST_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct Manifest<'a> {
+    version: u32,
+    exports: Vec<Export<'a>>,
+    imports: Vec<Import>,
+    snippets: Vec<String>,
+    local_modules: Vec<String>,
+    npm_dependencies: BTreeMap<&'a str, &'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ExportKind {
+    Function,
+    Class,
+    Constructor,
+    Method,
+    Getter,
+    Setter,
+    Enum,
+    StringEnum,
+    PlainObject,
+    Trait,
+    Error,
+}
+
+#[derive(Serialize)]
+struct Export<'a> {
+    name: &'a str,
+    kind: ExportKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    class: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    namespace: Option<&'a [String]>,
+    #[serde(rename = "static", skip_serializing_if = "std::ops::Not::not")]
+    is_static: bool,
+    #[serde(rename = "async", skip_serializing_if = "std::ops::Not::not")]
+    asyncness: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<Vec<Argument>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    returns: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    throws: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variants: Option<Vec<Variant<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fields: Option<Vec<Argument>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    methods: Option<Vec<TraitMethod<'a>>>,
+    comments: &'a str,
+}
+
+#[derive(Serialize)]
+struct Argument {
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+    optional: bool,
+}
+
+#[derive(Serialize)]
+struct Variant<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<i64>,
+    comments: &'a str,
+}
+
+/// A method JS objects need to implement to be passed as a Rust trait.
+#[derive(Serialize)]
+struct TraitMethod<'a> {
+    name: &'a str,
+    args: Vec<Argument>,
+    returns: String,
+    comments: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ImportKind {
+    Function,
+    Constructor,
+    Method,
+    Getter,
+    Setter,
+    Static,
+}
+
+#[derive(Serialize)]
+struct Import {
+    /// The module imported from, or `None` for globals.
+    module: Option<String>,
+    /// The imported item, including any namespace.
+    name: String,
+    kind: ImportKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    member: Option<String>,
+    args: Vec<Argument>,
+    returns: String,
+}
+
+impl Context<'_> {
+    /// Returns the manifest of the bindings as pretty-printed JSON.
+    ///
+    /// This must be called after `finalize`, once all npm dependencies have
+    /// been collected.
+    pub fn manifest(&self) -> Result<String, Error> {
+        let mut exports = Vec::new();
+        let mut imports = Vec::new();
+
+        for s in self.aux.structs.iter().filter(|s| !s.private) {
+            exports.push(Export {
+                namespace: s.js_namespace.as_deref(),
+                ..Export::new(&s.name, ExportKind::Class, &s.comments)
+            });
+        }
+        for e in self.aux.enums.values().filter(|e| !e.private) {
+            let variants = e
+                .variants
+                .iter()
+                .map(|(name, value, comments)| Variant {
+                    name,
+                    value: Some(*value),
+                    comments,
+                })
+                .collect();
+            exports.push(Export {
+                namespace: e.js_namespace.as_deref(),
+                variants: Some(variants),
+                ..Export::new(&e.name, ExportKind::Enum, &e.comments)
+            });
+        }
+        for e in self.aux.string_enums.values() {
+            // String enums have no variant names in JS, only their values.
+            let variants = e
+                .variant_values
+                .iter()
+                .map(|value| Variant {
+                    name: value,
+                    value: None,
+                    comments: "",
+                })
+                .collect();
+            exports.push(Export {
+                variants: Some(variants),
+                ..Export::new(&e.name, ExportKind::StringEnum, &e.comments)
+            });
+        }
+        for p in self.aux.plain_objects.iter().filter(|p| !p.private) {
+            let fields = p
+                .fields
+                .iter()
+                .map(|field| Argument {
+                    name: field.name.clone(),
+                    ty: self.manifest_ts(&field.ty, TypePosition::Return),
+                    optional: matches!(field.ty, AdapterType::Option(_)),
+                })
+                .collect();
+            exports.push(Export {
+                fields: Some(fields),
+                ..Export::new(&p.name, ExportKind::PlainObject, &p.comments)
+            });
+        }
+        for t in self.aux.traits.iter() {
+            let methods = t
+                .methods
+                .iter()
+                .map(|method| self.manifest_trait_method(method))
+                .collect();
+            exports.push(Export {
+                methods: Some(methods),
+                ..Export::new(&t.name, ExportKind::Trait, &t.comments)
+            });
+        }
+        for e in self.aux.errors.iter() {
+            let variants = e
+                .variants
+                .iter()
+                .map(|name| Variant {
+                    name,
+                    value: None,
+                    comments: "",
+                })
+                .collect();
+            exports.push(Export {
+                variants: Some(variants),
+                ..Export::new(&e.name, ExportKind::Error, &e.comments)
+            });
+        }
+
+        for (_, adapter, kind) in iter_adapter(self.aux, self.wit, self.module) {
+            match kind {
+                ContextAdapterKind::Export(export) => {
+                    exports.push(self.manifest_export(export, adapter));
+                }
+                ContextAdapterKind::Import(_) => {
+                    let AdapterKind::Local { instructions } = &adapter.kind else {
+                        continue;
+                    };
+                    let called = instructions.iter().find_map(|i| match i.instr {
+                        Instruction::CallAdapter(f) => Some(f),
+                        _ => None,
+                    });
+                    let Some(called) = called else { continue };
+                    let Some(import) = self.aux.import_map.get(&called) else {
+                        continue;
+                    };
+                    let import_adapter = &self.wit.adapters[&called];
+                    let AdapterKind::Import { kind, .. } = &import_adapter.kind else {
+                        continue;
+                    };
+                    if let Some(import) = self.manifest_import(import, *kind, import_adapter) {
+                        imports.push(import);
+                    }
+                }
+                ContextAdapterKind::Adapter => {}
+            }
+        }
+
+        exports.sort_by(|a, b| (a.class, a.name).cmp(&(b.class, b.name)));
+        imports.sort_by(|a, b| (&a.module, &a.name).cmp(&(&b.module, &b.name)));
+
+        let snippets = self
+            .aux
+            .snippets
+            .iter()
+            .flat_map(|(identifier, list)| {
+                (0..list.len()).map(move |i| format!("snippets/{identifier}/inline{i}.js"))
+            })
+            .collect();
+        let mut local_modules: Vec<_> = self
+            .aux
+            .local_modules
+            .keys()
+            .map(|path| format!("snippets/{path}"))
+            .collect();
+        local_modules.sort();
+        let npm_dependencies = self
+            .npm_dependencies
+            .iter()
+            .map(|(name, (_, version))| (name.as_str(), version.as_str()))
+            .collect();
+
+        let manifest = Manifest {
+            version: MANIFEST_VERSION,
+            exports,
+            imports,
+            snippets,
+            local_modules,
+            npm_dependencies,
+        };
+        Ok(serde_json::to_string_pretty(&manifest)?)
+    }
+
+    fn manifest_export<'a>(&'a self, export: &'a AuxExport, adapter: &Adapter) -> Export<'a> {
+        let (name, kind, class, is_static) = match &export.kind {
+            AuxExportKind::Function(name) => (name.as_str(), ExportKind::Function, None, false),
+            AuxExportKind::FunctionThis(name) => (name.as_str(), ExportKind::Function, None, false),
+            AuxExportKind::Constructor(class) => {
+                ("constructor", ExportKind::Constructor, Some(class), false)
+            }
+            AuxExportKind::Method {
+                class,
+                name,
+                receiver,
+                kind,
+            } => {
+                let kind = match kind {
+                    AuxExportedMethodKind::Method => ExportKind::Method,
+                    AuxExportedMethodKind::Getter => ExportKind::Getter,
+                    AuxExportedMethodKind::Setter => ExportKind::Setter,
+                };
+                (name.as_str(), kind, Some(class), receiver.is_static())
+            }
+        };
+
+        // The receiver is passed as the leading parameter, but isn't an
+        // argument in JS.
+        let has_receiver = match &export.kind {
+            AuxExportKind::FunctionThis(_) => true,
+            AuxExportKind::Method { receiver, .. } => !receiver.is_static(),
+            _ => false,
+        };
+        let params = &adapter.params[usize::from(has_receiver)..];
+        let args = params
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| {
+                let data = export.args.as_ref().and_then(|args| args.get(i));
+                Argument {
+                    name: data.map_or_else(|| format!("arg{i}"), |data| data.name.clone()),
+                    ty: match data.and_then(|data| data.ty_override.clone()) {
+                        Some(ty) => ty,
+                        None => self.manifest_ts(ty, TypePosition::Argument),
+                    },
+                    optional: data.is_some_and(|data| data.optional)
+                        || matches!(ty, AdapterType::Option(_)),
+                }
+            })
+            .collect();
+
+        let returns = if matches!(export.kind, AuxExportKind::Constructor(_)) {
+            None
+        } else {
+            let ret = match &export.fn_ret_ty_override {
+                Some(ty) => ty.clone(),
+                None => self.manifest_ret(&adapter.inner_results),
+            };
+            Some(if export.asyncness || export.promising {
+                format!("Promise<{ret}>")
+            } else {
+                ret
+            })
+        };
+
+        Export {
+            class: class.map(|class| self.manifest_class_name(class)),
+            namespace: export.js_namespace.as_deref(),
+            is_static,
+            asyncness: export.asyncness || export.promising,
+            args: Some(args),
+            returns,
+            throws: export.throws.as_deref(),
+            ..Export::new(name, kind, &export.comments)
+        }
+    }
+
+    fn manifest_import(
+        &self,
+        import: &AuxImport,
+        kind: AdapterJsImportKind,
+        adapter: &Adapter,
+    ) -> Option<Import> {
+        let (js, kind, member) = match import {
+            AuxImport::Value(AuxValue::Bare(js)) => {
+                let kind = match kind {
+                    AdapterJsImportKind::Constructor => ImportKind::Constructor,
+                    AdapterJsImportKind::Method => ImportKind::Method,
+                    AdapterJsImportKind::Normal => ImportKind::Function,
+                };
+                (js, kind, None)
+            }
+            AuxImport::Value(AuxValue::Getter(js, field))
+            | AuxImport::Value(AuxValue::ClassGetter(js, field))
+            | AuxImport::StructuralClassGetter(js, field) => {
+                (js, ImportKind::Getter, Some(field.clone()))
+            }
+            AuxImport::Value(AuxValue::Setter(js, field))
+            | AuxImport::Value(AuxValue::ClassSetter(js, field))
+            | AuxImport::StructuralClassSetter(js, field) => {
+                (js, ImportKind::Setter, Some(field.clone()))
+            }
+            AuxImport::ValueWithThis(js, method) => (js, ImportKind::Method, Some(method.clone())),
+            AuxImport::Static { js, .. } => (js, ImportKind::Static, None),
+            _ => return None,
+        };
+        let (module, name) = self.manifest_import_name(js);
+
+        // Methods take the object they're called on as the leading parameter.
+        let params = match kind {
+            ImportKind::Method => adapter.params.get(1..).unwrap_or_default(),
+            _ => &adapter.params[..],
+        };
+        let args = params
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| Argument {
+                name: format!("arg{i}"),
+                ty: self.manifest_ts(ty, TypePosition::Argument),
+                optional: matches!(ty, AdapterType::Option(_)),
+            })
+            .collect();
+        let returns = self.manifest_ret(&adapter.results);
+
+        Some(Import {
+            module,
+            name,
+            kind,
+            member,
+            args,
+            returns,
+        })
+    }
+
+    /// Trait methods are called from Rust, so their arguments are typed like
+    /// the return values of exports, and vice versa.
+    fn manifest_trait_method<'a>(&self, method: &'a AuxTraitMethod) -> TraitMethod<'a> {
+        let args = method
+            .args
+            .iter()
+            .map(|arg| Argument {
+                name: arg.name.clone(),
+                ty: match &arg.ty_override {
+                    Some(ty) => ty.clone(),
+                    None => self.manifest_ts(&arg.ty, TypePosition::Return),
+                },
+                optional: false,
+            })
+            .collect();
+        let returns = match (&method.ret_ty_override, &method.ret) {
+            (Some(ty), _) => ty.clone(),
+            (None, Some(ty)) => self.manifest_ts(ty, TypePosition::Argument),
+            (None, None) => "void".to_string(),
+        };
+        TraitMethod {
+            name: &method.name,
+            args,
+            returns,
+            comments: &method.comments,
+        }
+    }
+
+    /// Exports refer to classes by their Rust name, which may differ from the
+    /// name they're exported to JS with.
+    fn manifest_class_name<'a>(&'a self, class: &'a str) -> &'a str {
+        self.aux
+            .structs
+            .iter()
+            .find(|s| s.rust_name == class)
+            .map_or(class, |s| s.name.as_str())
+    }
+
+    fn manifest_import_name(&self, js: &JsImport) -> (Option<String>, String) {
+        let (module, mut name) = match &js.name {
+            JsImportName::Global { name } | JsImportName::VendorPrefixed { name, .. } => {
+                (None, name.clone())
+            }
+            JsImportName::Module { module, name } => (Some(module.clone()), name.clone()),
+            JsImportName::LocalModule { module, name } => {
+                (Some(self.config.local_module_name(module)), name.clone())
+            }
+            JsImportName::InlineJs {
+                unique_crate_identifier,
+                snippet_idx_in_crate,
+                name,
+            } => (
+                Some(
+                    self.config
+                        .inline_js_module_name(unique_crate_identifier, *snippet_idx_in_crate),
+                ),
+                name.clone(),
+            ),
+        };
+        for field in js.fields.iter() {
+            name.push('.');
+            name.push_str(field);
+        }
+        (module, name)
+    }
+
+    fn manifest_ret(&self, results: &[AdapterType]) -> String {
+        match results {
+            [] => "void".to_string(),
+            [ty] => self.manifest_ts(ty, TypePosition::Return),
+            _ => "[any]".to_string(),
+        }
+    }
+
+    fn manifest_ts(&self, ty: &AdapterType, position: TypePosition) -> String {
+        let mut ts = String::new();
+        adapter2ts(ty, position, &mut ts, None, &self.qualified_to_js_name);
+        ts
+    }
+}
+
+impl<'a> Export<'a> {
+    fn new(name: &'a str, kind: ExportKind, comments: &'a str) -> Self {
+        Export {
+            name,
+            kind,
+            class: None,
+            namespace: None,
+            is_static: false,
+            asyncness: false,
+            args: None,
+            returns: None,
+            throws: None,
+            variants: None,
+            fields: None,
+            methods: None,
+            comments,
+        }
+    }
+}
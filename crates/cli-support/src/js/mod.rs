@@ -6,18 +6,23 @@ use crate::transforms::{
 };
 use crate::wasm_conventions;
 use crate::wit::{
-    Adapter, AdapterId, AdapterJsImportKind, AuxExportedMethodKind, AuxReceiverKind, AuxStringEnum,
-    AuxValue,
+    Adapter, AdapterId, AdapterJsImportKind, AdapterType, AuxExportedMethodKind, AuxReceiverKind,
+    AuxStringEnum, AuxValue,
 };
 use crate::wit::{AdapterKind, Instruction, InstructionData};
-use crate::wit::{AuxEnum, AuxExport, AuxExportKind, AuxImport, AuxStruct};
+use crate::wit::{
+    AuxEnum, AuxError, AuxExport, AuxExportKind, AuxImport, AuxPlainObject, AuxSourceLocation,
+    AuxStruct, AuxTrait,
+};
 use crate::wit::{JsImport, JsImportName, NonstandardWitSection, WasmBindgenAux};
 use crate::{
-    is_wasi_import, Bindgen, EncodeInto, OutputMode, INIT_EXTERNREF_TABLE_NAME, PLACEHOLDER_MODULE,
+    is_wasi_import, Bindgen, EncodeInto, OutputMode, SpinTimeout, INIT_EXTERNREF_TABLE_NAME,
+    PLACEHOLDER_MODULE,
 };
 
 use anyhow::{anyhow, bail, Context as _, Error};
-use binding::TsReference;
+use binding::{adapter2ts, TsReference, TypePosition};
+use source_map::SourceMapBuilder;
 use std::borrow::Cow;
 use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
@@ -30,6 +35,9 @@ use wasm_bindgen_shared::escape_string;
 use wasm_bindgen_shared::identifier::{is_valid_ident, to_valid_ident};
 
 mod binding;
+mod manifest;
+mod source_map;
+mod wasi_shim;
 
 macro_rules! region {
     ($ctx:expr, $name:literal, $code:block) => {
@@ -82,6 +90,9 @@ pub struct Context<'a> {
     /// require the type or requires only the type.
     used_string_enums: HashSet<String>,
 
+    /// The identifiers of the generated error classes, by JS name.
+    error_classes: HashMap<String, String>,
+
     exported_classes: BTreeMap<String, ExportedClass>,
 
     /// Public module exports
@@ -91,6 +102,14 @@ pub struct Context<'a> {
     /// they're defined in as well as their version specification.
     pub npm_dependencies: HashMap<String, (PathBuf, String)>,
 
+    /// Records the Rust locations of the generated items.
+    source_map: SourceMapBuilder,
+
+    /// The source maps of the generated JS and of the bundler start file, if
+    /// the locations of any items were known.
+    pub js_source_map: Option<String>,
+    pub start_source_map: Option<String>,
+
     /// A mapping from the memory IDs as we see them to an index for that memory,
     /// used in function names, as well as all the kinds of views we've created
     /// of that memory.
@@ -111,6 +130,9 @@ pub struct Context<'a> {
     /// Whether this is a WASI module.
     wasi: bool,
 
+    /// Whether the WASI imports are provided by the built-in shim.
+    wasi_shim: bool,
+
     /// If exception handling / unwinding is enabled.
     unwind_enabled: bool,
 
@@ -179,6 +201,8 @@ struct ExportedClass {
     js_name: Option<String>,
     /// The namespace-qualified name (used for wasm symbol references)
     qualified_name: Option<String>,
+    /// The location of the struct in Rust code, if known
+    location: Option<AuxSourceLocation>,
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -212,11 +236,13 @@ impl<'a> Context<'a> {
         config: &'a Bindgen,
         wit: &'a NonstandardWitSection,
         aux: &'a WasmBindgenAux,
+        wait: bool,
     ) -> Result<Context<'a>, Error> {
         let mut typescript = "/* tslint:disable */\n/* eslint-disable */\n".to_string();
         if config.wasi {
             typescript.push_str("import * as __wwrr from './wwrr.js';\n");
         }
+        let wasi_shim = config.wasi_shim && module.imports.iter().any(is_wasi_import);
 
         Ok(Context {
             globals: String::new(),
@@ -230,6 +256,7 @@ impl<'a> Context<'a> {
             wasm_import_definitions: Default::default(),
             typescript_refs: Default::default(),
             used_string_enums: Default::default(),
+            error_classes: Default::default(),
             exported_classes: Default::default(),
             exports: Default::default(),
             config,
@@ -237,13 +264,17 @@ impl<'a> Context<'a> {
             unwind_enabled: has_local_exception_tags(module),
             module,
             npm_dependencies: Default::default(),
+            source_map: Default::default(),
+            js_source_map: None,
+            start_source_map: None,
             wit,
             aux,
             memories: Default::default(),
             table_indices: Default::default(),
             stack_pointer_shim_injected: false,
-            wait: config.wait,
+            wait,
             wasi: config.wasi,
+            wasi_shim,
             qualified_to_rust_name: Default::default(),
             qualified_to_js_name: Default::default(),
         })
@@ -533,7 +564,17 @@ impl<'a> Context<'a> {
             ts.push_str(&node_atomics_ts);
         }
 
-        Ok((self.globals.to_owned(), ts, start))
+        let mut js = self.globals.to_owned();
+        self.js_source_map = self
+            .source_map
+            .finish(&mut js, &format!("{module_name}.js"));
+        if let Some(start) = &mut start {
+            self.start_source_map = self
+                .source_map
+                .finish(start, &format!("{module_name}_bg.js"));
+        }
+
+        Ok((js, ts, start))
     }
 
     fn generate_esm_cjs_imports(&mut self, module_name: &str, has_memory: bool) -> String {
@@ -733,6 +774,11 @@ impl<'a> Context<'a> {
         } else {
             ""
         };
+        let wasi_param = if self.wasi_shim {
+            ", wasi?: { args?: string[], env?: Record<string, string> }"
+        } else {
+            ""
+        };
         let arg_optional = if has_module_or_path_optional { "?" } else { "" };
         // With TypeScript 3.8.3, I'm seeing that any "export"s at the root level cause TypeScript to ignore all "declare" statements.
         // So using "declare" everywhere for at least the NoModules option.
@@ -753,12 +799,12 @@ impl<'a> Context<'a> {
                 * Instantiates the given `module`, which can either be bytes or\n\
                 * a precompiled `WebAssembly.Module`.\n\
                 *\n\
-                * @param {{{{ module: SyncInitInput{memory_param}{stack_size} }}}} module - Passing `SyncInitInput` directly is deprecated.\n\
+                * @param {{{{ module: SyncInitInput{memory_param}{stack_size}{wasi_param} }}}} module - Passing `SyncInitInput` directly is deprecated.\n\
                 {memory_doc}\
                 *\n\
                 * @returns {{InitOutput}}\n\
                 */\n\
-                export function initSync(module: {{ module: SyncInitInput{memory_param}{stack_size} }} | SyncInitInput{memory_param}): InitOutput;\n\n\
+                export function initSync(module: {{ module: SyncInitInput{memory_param}{stack_size}{wasi_param} }} | SyncInitInput{memory_param}): InitOutput;\n\n\
                 "
             ));
 
@@ -776,13 +822,13 @@ impl<'a> Context<'a> {
             * If `module_or_path` is {{RequestInfo}} or {{URL}}, makes a request and\n\
             * for everything else, calls `WebAssembly.instantiate` directly.\n\
             *\n\
-            * @param {{{{ module_or_path: InitInput | Promise<InitInput>{memory_param}{stack_size} }}}} module_or_path - Passing `InitInput` directly is deprecated.\n\
+            * @param {{{{ module_or_path: InitInput | Promise<InitInput>{memory_param}{stack_size}{wasi_param} }}}} module_or_path - Passing `InitInput` directly is deprecated.\n\
             {memory_doc}\
             *\n\
             * @returns {{Promise<InitOutput>}}\n\
             */\n\
             {setup_function_declaration} \
-                (module_or_path{arg_optional}: {{ module_or_path: InitInput | Promise<InitInput>{memory_param}{stack_size} }} | InitInput | Promise<InitInput>{memory_param}): Promise<InitOutput>;\n",
+                (module_or_path{arg_optional}: {{ module_or_path: InitInput | Promise<InitInput>{memory_param}{stack_size}{wasi_param} }} | InitInput | Promise<InitInput>{memory_param}): Promise<InitOutput>;\n",
         ))
     }
 
@@ -1004,14 +1050,14 @@ export const __wbg_memory: WebAssembly.Memory;
             function initSync(module{init_memory_arg}) {{
                 if (wasm !== undefined) return wasm;
 
-                {init_stack_size}
+                {init_stack_size}{init_wasi}
                 if (module !== undefined) {{
                     if (Object.getPrototypeOf(module) === Object.prototype) {{
-                        ({{module{init_memory_arg}{init_stack_size_arg}}} = module)
+                        ({{module{init_memory_arg}{init_stack_size_arg}{init_wasi_arg}}} = module)
                     }} else {{
                         console.warn('using deprecated parameters for `initSync()`; pass a single object instead')
                     }}
-                }}
+                }}{configure_wasi}
 
                 const imports = __wbg_get_imports({init_memory_arg_alone});
                 if (!(module instanceof WebAssembly.Module)) {{
@@ -1024,14 +1070,14 @@ export const __wbg_memory: WebAssembly.Memory;
             async function __wbg_init(module_or_path{init_memory_arg}) {{
                 if (wasm !== undefined) return wasm;
 
-                {init_stack_size}
+                {init_stack_size}{init_wasi}
                 if (module_or_path !== undefined) {{
                     if (Object.getPrototypeOf(module_or_path) === Object.prototype) {{
-                        ({{module_or_path{init_memory_arg}{init_stack_size_arg}}} = module_or_path)
+                        ({{module_or_path{init_memory_arg}{init_stack_size_arg}{init_wasi_arg}}} = module_or_path)
                     }} else {{
                         console.warn('using deprecated parameters for the initialization function; pass a single object instead')
                     }}
-                }}
+                }}{configure_wasi}
 
                 {default_module_path}
                 const imports = __wbg_get_imports({init_memory_arg_alone});
@@ -1082,6 +1128,13 @@ export const __wbg_memory: WebAssembly.Memory;
             } else {
                 ""
             },
+            init_wasi = if self.wasi_shim { "\nlet wasi;" } else { "" },
+            init_wasi_arg = if self.wasi_shim { ", wasi" } else { "" },
+            configure_wasi = if self.wasi_shim {
+                "\n__wbg_wasi_configure(wasi);"
+            } else {
+                ""
+            },
         )
         } else {
             format!(
@@ -1430,6 +1483,9 @@ if (require('worker_threads').isMainThread) {{
         let qualified = class.qualified_name.as_deref().unwrap_or(js_name);
         let mut dst = format!("class {identifier} {{\n");
         let mut ts_dst = dst.clone();
+        if let Some(location) = &class.location {
+            dst.insert_str(0, &self.source_map.marker(location));
+        }
 
         if !class.has_constructor {
             // declare the constructor as private to prevent direct instantiation
@@ -2763,6 +2819,81 @@ if (require('worker_threads').isMainThread) {{
         Ok(())
     }
 
+    /// Like `handleError`, but for `suspending` imports, where the exception
+    /// is only observed once the returned promise rejects.
+    fn expose_handle_error_async(&mut self) -> Result<(), Error> {
+        if self
+            .intrinsics
+            .as_ref()
+            .unwrap()
+            .contains_key("handle_error_async")
+        {
+            return Ok(());
+        }
+        let store = self
+            .aux
+            .exn_store
+            .ok_or_else(|| anyhow!("failed to find `__wbindgen_exn_store` intrinsic"))?;
+        let store = self.export_name_of(store);
+        let store = match (self.aux.externref_table, self.aux.externref_alloc) {
+            (Some(table), Some(alloc)) => {
+                let add = self.expose_add_to_externref_table(table, alloc);
+                format!("wasm.{store}({add}(e));")
+            }
+            _ => {
+                self.expose_add_heap_object();
+                format!("wasm.{store}(addHeapObject(e));")
+            }
+        };
+        intrinsic(&mut self.intrinsics, "handle_error_async".into(), || {
+            format!(
+                "
+                async function handleErrorAsync(f, args) {{
+                    try {{
+                        return await f.apply(this, args);
+                    }} catch (e) {{
+                        {store}
+                    }}
+                }}
+                ",
+            )
+            .into()
+        });
+        Ok(())
+    }
+
+    /// Calls a `promising` export through its `WebAssembly.promising`
+    /// wrapper, which is created once per export.
+    ///
+    /// Rust's stack in linear memory isn't switched when a `promising` call
+    /// is suspended, so calls are queued with `queuePromising` and run one at
+    /// a time, as they would otherwise overwrite each other's stack.
+    fn expose_call_promising(&mut self) {
+        intrinsic(&mut self.intrinsics, "call_promising".into(), || {
+            "
+            const PROMISING_WRAPPERS = new WeakMap();
+
+            function callPromising(f, args) {
+                let wrapper = PROMISING_WRAPPERS.get(f);
+                if (wrapper === undefined) {
+                    wrapper = WebAssembly.promising(f);
+                    PROMISING_WRAPPERS.set(f, wrapper);
+                }
+                return wrapper(...args);
+            }
+
+            let promisingQueue = Promise.resolve();
+
+            function queuePromising(f) {
+                const ret = promisingQueue.then(f);
+                promisingQueue = ret.then(() => {}, () => {});
+                return ret;
+            }
+            "
+            .into()
+        });
+    }
+
     fn expose_log_error(&mut self) {
         intrinsic(&mut self.intrinsics, "log_error".into(), || {
             "
@@ -3039,6 +3170,84 @@ if (require('worker_threads').isMainThread) {{
         });
     }
 
+    /// Exposes `makeIterator`, which wraps the closure returned for a Rust
+    /// iterator in a JS iterable iterator.
+    ///
+    /// The closure returns the next item, or the sentinel it's called with
+    /// once the iterator is exhausted. It is destroyed as soon as the iterator
+    /// is done or closed with `return()`, and otherwise by the closure
+    /// finalization registry.
+    fn expose_make_iterator(&mut self) {
+        intrinsic(&mut self.intrinsics, "make_iterator".into(), || {
+            "
+            function makeIterator(next) {
+                const DONE = {};
+                let done = false;
+                const finish = () => {
+                    if (!done) {
+                        done = true;
+                        next._wbg_cb_unref();
+                    }
+                    return { done: true, value: undefined };
+                };
+                return {
+                    next() {
+                        if (done) return finish();
+                        const value = next(DONE);
+                        return value === DONE ? finish() : { done: false, value };
+                    },
+                    return: finish,
+                    [Symbol.iterator]() {
+                        return this;
+                    },
+                };
+            }
+            "
+            .into()
+        });
+    }
+
+    /// Same as `expose_make_iterator`, except that the closure returns a
+    /// `Promise` for the next item.
+    fn expose_make_async_iterator(&mut self) {
+        intrinsic(&mut self.intrinsics, "make_async_iterator".into(), || {
+            "
+            function makeAsyncIterator(next) {
+                const DONE = {};
+                let done = false;
+                let last = Promise.resolve();
+                const finish = () => {
+                    if (!done) {
+                        done = true;
+                        next._wbg_cb_unref();
+                    }
+                    return { done: true, value: undefined };
+                };
+                const step = () => {
+                    if (done) return finish();
+                    return next(DONE).then(value => value === DONE ? finish() : { done: false, value });
+                };
+                return {
+                    // Requests are queued so that the Rust stream is only
+                    // polled for one item at a time.
+                    next() {
+                        last = last.then(step, step);
+                        return last;
+                    },
+                    return() {
+                        last = last.then(finish, finish);
+                        return last;
+                    },
+                    [Symbol.asyncIterator]() {
+                        return this;
+                    },
+                };
+            }
+            "
+            .into()
+        });
+    }
+
     fn generate_reset_state(&mut self) -> Result<(), Error> {
         self.global("let __wbg_instance_id = 0;");
 
@@ -3108,6 +3317,12 @@ if (require('worker_threads').isMainThread) {{
             .to_string(),
         );
 
+        // CommonJS can't export `wasm` as a live binding, so the exports of
+        // the new instance have to be exported again.
+        if self.config.debug && !self.config.mode.uses_es_modules() {
+            reset_statements.push("exports.__wasm = wasm;".to_string());
+        }
+
         let function_body = format!("() {{\n{}}}", reset_statements.join("\n"));
 
         let identifier = self.generate_identifier("__wbg_reset_state");
@@ -3359,6 +3574,13 @@ if (require('worker_threads').isMainThread) {{
 
         self.generate_jstag_import();
         self.generate_wrapped_jstag_import();
+        self.generate_wasi_shim_imports()?;
+
+        // Error classes are instantiated by imports, so their identifiers need
+        // to be known before generating the adapters.
+        for e in self.aux.errors.iter() {
+            self.generate_error(e)?;
+        }
 
         for (id, adapter, kind) in iter_adapter(self.aux, self.wit, self.module) {
             let instrs = match &adapter.kind {
@@ -3380,6 +3602,14 @@ if (require('worker_threads').isMainThread) {{
         for (_, e) in crate::sorted_iter(&self.aux.enums) {
             self.generate_enum(e)?;
         }
+        // Plain objects may reference string enums, so they need to be
+        // generated first.
+        for p in self.aux.plain_objects.iter() {
+            self.generate_plain_object(p);
+        }
+        for t in self.aux.traits.iter() {
+            self.generate_trait(t);
+        }
         for (_, e) in crate::sorted_iter(&self.aux.string_enums) {
             self.generate_string_enum(e)?;
         }
@@ -3509,6 +3739,66 @@ function __wbg_handle_catch(e) {{
             .insert(id, "__wbindgen_wrapped_jstag".to_string());
     }
 
+    /// Generate the built-in shim for all WASI imports if it was requested.
+    fn generate_wasi_shim_imports(&mut self) -> Result<(), Error> {
+        if !self.wasi_shim {
+            return Ok(());
+        }
+
+        let memory = wasm_conventions::get_memory(self.module)?;
+        let mem = self.export_name_of(memory);
+        self.global(&format!(
+            "function __wbg_wasi_buffer() {{ return wasm.{mem}.buffer; }}"
+        ));
+        self.global(wasi_shim::HELPERS);
+        define_export(
+            &mut self.exports,
+            "WasiExitError",
+            &[],
+            ExportEntry::Definition(ExportDefinition {
+                comments: None,
+                identifier: "WasiExitError".to_string(),
+                definition: wasi_shim::EXIT_ERROR.trim_start().to_string(),
+                ts_definition: wasi_shim::EXIT_ERROR_TS.to_string(),
+                ts_comments: None,
+                private: false,
+            }),
+        )?;
+        define_export(
+            &mut self.exports,
+            "configureWasi",
+            &[],
+            ExportEntry::Definition(ExportDefinition {
+                comments: None,
+                identifier: "configureWasi".to_string(),
+                definition: wasi_shim::CONFIGURE.trim_start().to_string(),
+                ts_definition: wasi_shim::CONFIGURE_TS.to_string(),
+                ts_comments: None,
+                private: false,
+            }),
+        )?;
+
+        let imports = self
+            .module
+            .imports
+            .iter()
+            .filter_map(|import| {
+                let js = wasi_shim::import(&import.module, &import.name)?;
+                Some((import.id(), js))
+            })
+            .collect::<Vec<_>>();
+        for (id, js) in imports {
+            // Provide the import from our own JS like any other intrinsic.
+            let import = self.module.imports.get_mut(id);
+            import.module = PLACEHOLDER_MODULE.to_owned();
+            import.name = format!("__wbg_wasi_{}", to_valid_ident(&import.name));
+            self.wasm_import_definitions
+                .insert(id, js.trim().to_owned());
+        }
+
+        Ok(())
+    }
+
     /// Registers import names for all `Global` imports first before we actually
     /// process any adapters.
     ///
@@ -3552,8 +3842,9 @@ function __wbg_handle_catch(e) {{
         kind: ContextAdapterKind,
     ) -> Result<(), Error> {
         let catch = self.aux.imports_with_catch.contains(&id);
+        let suspending = self.aux.imports_with_suspending.contains(&id);
         if let ContextAdapterKind::Import(core) = kind {
-            if !catch && self.attempt_direct_import(core, instrs)? {
+            if !catch && !suspending && self.attempt_direct_import(core, instrs)? {
                 return Ok(());
             }
         }
@@ -3563,9 +3854,11 @@ function __wbg_handle_catch(e) {{
         let mut builder = binding::Builder::new(self);
         builder.log_error(match kind {
             ContextAdapterKind::Export(_) | ContextAdapterKind::Adapter => false,
-            ContextAdapterKind::Import(_) => builder.cx.config.debug,
+            // `logError` can't observe the rejection of the returned promise.
+            ContextAdapterKind::Import(_) => builder.cx.config.debug && !suspending,
         });
         builder.catch(catch);
+        builder.suspending(suspending);
         let mut args = &None;
         let mut asyncness = false;
         let mut variadic = false;
@@ -3575,7 +3868,9 @@ function __wbg_handle_catch(e) {{
         match kind {
             ContextAdapterKind::Export(export) => {
                 args = &export.args;
-                asyncness = export.asyncness;
+                asyncness = export.asyncness || export.promising;
+                builder.promising(export.promising);
+                builder.throws(export.throws.clone());
                 variadic = export.variadic;
                 generate_jsdoc = export.generate_jsdoc;
                 ret_ty_override = &export.fn_ret_ty_override;
@@ -3635,6 +3930,19 @@ function __wbg_handle_catch(e) {{
 
         self.typescript_refs.extend(ts_refs);
 
+        // Mark the generated function with the location of the Rust item it
+        // was generated for, which ends up in the source map.
+        let aux = self.aux;
+        let location = match kind {
+            ContextAdapterKind::Export(export) => export.location.as_ref(),
+            ContextAdapterKind::Import(_) => aux.import_locations.get(&id),
+            ContextAdapterKind::Adapter => None,
+        };
+        let code = match location {
+            Some(location) => format!("{}{code}", self.source_map.marker(location)),
+            None => code,
+        };
+
         // Once we've got all the JS then put it in the right location depending
         // on what's being exported.
         match kind {
@@ -3648,6 +3956,7 @@ function __wbg_handle_catch(e) {{
                 // this is because if there are no arguments or return var description, `ts_doc`
                 // provides no additional value on top of what `ts_sig` already does
                 let ts_doc_opts = (ret_desc.is_some()
+                    || export.throws.is_some()
                     || args
                         .as_ref()
                         .is_some_and(|v| v.iter().any(|arg| arg.desc.is_some())))
@@ -3671,7 +3980,11 @@ function __wbg_handle_catch(e) {{
                             None
                         };
 
-                        let definition = format!("function {identifier}{code}\n");
+                        let definition = if export.promising {
+                            format!("async function {identifier}{code}\n")
+                        } else {
+                            format!("function {identifier}{code}\n")
+                        };
                         define_export(
                             &mut self.exports,
                             name,
@@ -3694,7 +4007,7 @@ function __wbg_handle_catch(e) {{
                         }
 
                         exported.has_constructor = true;
-                        exported.push("constructor", "", &js_docs, &code, &ts_docs, ts_sig);
+                        exported.push("constructor", "", false, &js_docs, &code, &ts_docs, ts_sig);
                     }
                     AuxExportKind::Method {
                         class,
@@ -3751,7 +4064,15 @@ function __wbg_handle_catch(e) {{
                             }
                         };
 
-                        exported.push(name, &prefix, &js_docs, &code, &ts_docs, ts);
+                        exported.push(
+                            name,
+                            &prefix,
+                            export.promising,
+                            &js_docs,
+                            &code,
+                            &ts_docs,
+                            ts,
+                        );
                     }
                 }
             }
@@ -3760,7 +4081,19 @@ function __wbg_handle_catch(e) {{
                 // instead of the JS handleError wrapper
                 let has_wasm_catch = self.aux.js_tag.is_some();
 
-                let code = if catch && !has_wasm_catch {
+                let code = if suspending {
+                    // JSPI suspends the calling WebAssembly stack until the
+                    // promise returned by the import settles.
+                    let code = if catch && !has_wasm_catch {
+                        self.expose_handle_error_async()?;
+                        format!(
+                            "function() {{ return handleErrorAsync(async function {code}, arguments); }}"
+                        )
+                    } else {
+                        format!("async function{code}")
+                    };
+                    format!("new WebAssembly.Suspending({code})")
+                } else if catch && !has_wasm_catch {
                     self.expose_handle_error()?;
                     format!("function() {{ return handleError(function {code}, arguments); }}")
                 } else if log_error {
@@ -4299,6 +4632,17 @@ function __wbg_handle_catch(e) {{
                 let identifier = self.require_class_unwrap(class);
                 Ok(format!("{identifier}.__unwrap({})", args[0]))
             }
+
+            AuxImport::NewError(name) => {
+                assert!(kind == AdapterJsImportKind::Normal);
+                assert!(!variadic);
+                assert_eq!(args.len(), 3);
+                let identifier = &self.error_classes[name];
+                Ok(format!(
+                    "new {identifier}({}, {}, {})",
+                    args[0], args[1], args[2]
+                ))
+            }
         }
     }
 
@@ -4673,14 +5017,31 @@ function __wbg_handle_catch(e) {{
 
             Intrinsic::SpinTimeout => {
                 assert_eq!(args.len(), 0);
-                r#"throw new Error("memory.atomic.wait32 timeout exceeded on main thread")"#
-                    .to_string()
+                let message = "memory.atomic.wait timeout exceeded on main thread";
+                match self.config.spin_timeout {
+                    SpinTimeout::Log => format!("console.warn(\"{message}, continuing to spin\")"),
+                    SpinTimeout::Throw | SpinTimeout::Trap => {
+                        format!("throw new Error(\"{message}\")")
+                    }
+                }
             }
 
             Intrinsic::AtomicsPause => {
                 assert_eq!(args.len(), 0);
                 "__wbg_atomics_pause_ref()".to_string()
             }
+
+            Intrinsic::MakeIterator => {
+                assert_eq!(args.len(), 1);
+                self.expose_make_iterator();
+                format!("makeIterator({})", args[0])
+            }
+
+            Intrinsic::MakeAsyncIterator => {
+                assert_eq!(args.len(), 1);
+                self.expose_make_async_iterator();
+                format!("makeAsyncIterator({})", args[0])
+            }
         };
         Ok(expr)
     }
@@ -4750,6 +5111,52 @@ function __wbg_handle_catch(e) {{
         Ok(())
     }
 
+    fn generate_error(&mut self, error: &AuxError) -> Result<(), Error> {
+        let identifier = self.generate_identifier(&error.name);
+        let kinds: Vec<_> = error.variants.iter().map(|v| format!("\"{v}\"")).collect();
+        let kind_ty = kinds.join(" | ");
+
+        let mut typescript = String::new();
+        if error.generate_typescript {
+            typescript.push_str(&format!(
+                "class {identifier} extends Error {{\n  \
+                   private constructor();\n  \
+                   readonly kind: {kind_ty};\n\
+                 }}\n"
+            ));
+        }
+
+        // The `cause` option is only passed if there is one, so that errors
+        // without a `source` don't get an own `cause` property.
+        let definition = format!(
+            "class {identifier} extends Error {{\n    \
+               constructor(kind, message, cause) {{\n        \
+                 super(message, cause === undefined ? undefined : {{ cause }});\n        \
+                 this.name = '{name}';\n        \
+                 this.kind = kind;\n    \
+               }}\n\
+             }}\n",
+            name = error.name,
+        );
+
+        define_export(
+            &mut self.exports,
+            &error.name,
+            &[],
+            ExportEntry::Definition(ExportDefinition {
+                identifier: identifier.clone(),
+                comments: Some(format_doc_comments(&error.comments, None)),
+                definition,
+                ts_definition: typescript,
+                ts_comments: Some(format_doc_comments(&error.comments, None)),
+                private: false,
+            }),
+        )?;
+        self.error_classes.insert(error.name.clone(), identifier);
+
+        Ok(())
+    }
+
     fn generate_string_enum(&mut self, string_enum: &AuxStringEnum) -> Result<(), Error> {
         let variants: Vec<_> = string_enum
             .variant_values
@@ -4789,6 +5196,107 @@ function __wbg_handle_catch(e) {{
         Ok(())
     }
 
+    fn generate_plain_object(&mut self, plain_object: &AuxPlainObject) {
+        if !plain_object.generate_typescript {
+            return;
+        }
+
+        self.typescript
+            .push_str(&format_doc_comments(&plain_object.comments, None));
+        if !plain_object.private {
+            self.typescript.push_str("export ");
+        }
+        self.typescript
+            .push_str(&format!("interface {} {{\n", plain_object.name));
+        for field in plain_object.fields.iter() {
+            if !field.generate_typescript {
+                continue;
+            }
+            for line in format_doc_comments(&field.comments, None).lines() {
+                self.typescript.push_str("  ");
+                self.typescript.push_str(line);
+                self.typescript.push('\n');
+            }
+            self.typescript.push_str("  ");
+            if is_valid_ident(&field.name) || field.name.bytes().all(|b| b.is_ascii_digit()) {
+                self.typescript.push_str(&field.name);
+            } else {
+                self.typescript.push_str(&format!("\"{}\"", field.name));
+            }
+            if let AdapterType::Option(_) = field.ty {
+                self.typescript.push('?');
+            }
+            self.typescript.push_str(": ");
+            adapter2ts(
+                &field.ty,
+                TypePosition::Return,
+                &mut self.typescript,
+                Some(&mut self.typescript_refs),
+                &self.qualified_to_js_name,
+            );
+            self.typescript.push_str(";\n");
+        }
+        self.typescript.push_str("}\n\n");
+    }
+
+    fn generate_trait(&mut self, trait_: &AuxTrait) {
+        if !trait_.generate_typescript {
+            return;
+        }
+
+        self.typescript
+            .push_str(&format_doc_comments(&trait_.comments, None));
+        self.typescript
+            .push_str(&format!("export interface {} {{\n", trait_.name));
+        for method in trait_.methods.iter() {
+            for line in format_doc_comments(&method.comments, None).lines() {
+                self.typescript.push_str("  ");
+                self.typescript.push_str(line);
+                self.typescript.push('\n');
+            }
+            self.typescript.push_str("  ");
+            if is_valid_ident(&method.name) {
+                self.typescript.push_str(&method.name);
+            } else {
+                self.typescript.push_str(&format!("\"{}\"", method.name));
+            }
+            self.typescript.push('(');
+            for (i, arg) in method.args.iter().enumerate() {
+                if i > 0 {
+                    self.typescript.push_str(", ");
+                }
+                self.typescript.push_str(&arg.name);
+                self.typescript.push_str(": ");
+                // Arguments are values handed to JS, so they are typed like
+                // the return values of exports, and vice versa.
+                match &arg.ty_override {
+                    Some(ty) => self.typescript.push_str(ty),
+                    None => adapter2ts(
+                        &arg.ty,
+                        TypePosition::Return,
+                        &mut self.typescript,
+                        Some(&mut self.typescript_refs),
+                        &self.qualified_to_js_name,
+                    ),
+                }
+            }
+            self.typescript.push_str("): ");
+            match (&method.ret_ty_override, &method.ret) {
+                (Some(ty), _) => self.typescript.push_str(ty),
+                (None, Some(ty)) => adapter2ts(
+                    ty,
+                    TypePosition::Argument,
+                    &mut self.typescript,
+                    Some(&mut self.typescript_refs),
+                    &self.qualified_to_js_name,
+                ),
+                (None, None) => self.typescript.push_str("void"),
+            }
+            self.typescript.push_str(";\n");
+        }
+        self.typescript.push_str("}\n\n");
+    }
+
     fn expose_string_enum(&mut self, string_enum_name: &str) {
         self.used_string_enums.insert(string_enum_name.to_string());
     }
@@ -4802,6 +5310,7 @@ function __wbg_handle_catch(e) {{
         class.js_namespace = struct_.js_namespace.as_ref().map(|ns| ns.to_vec());
         class.js_name = Some(struct_.name.clone());
         class.qualified_name = Some(struct_.qualified_name.clone());
+        class.location = struct_.location.clone();
         Ok(())
     }
 
@@ -5349,6 +5858,7 @@ impl ExportedClass {
         &mut self,
         function_name: &str,
         function_prefix: &str,
+        asyncness: bool,
         js_docs: &str,
         js: &str,
         ts_docs: &str,
@@ -5356,6 +5866,11 @@ impl ExportedClass {
     ) {
         self.contents.push_str(js_docs);
         self.contents.push_str(function_prefix);
+        // `async` is only valid in JS, the TypeScript signature already
+        // returns a `Promise`.
+        if asyncness {
+            self.contents.push_str("async ");
+        }
         self.contents.push_str(function_name);
         self.contents.push_str(js);
         self.contents.push('\n');
@@ -0,0 +1,181 @@
+//! Generation of source maps from the JS glue back to the Rust items it was
+//! generated for.
+//!
+//! While the JS is being assembled we don't know where each item will end up,
+//! so a marker is inserted at the start of each item instead. Once the final
+//! JS is known the markers are removed again and their lines are recorded.
+
+use crate::wit::AuxSourceLocation;
+use std::collections::{BTreeMap, HashMap};
+
+/// Delimits the index of a location in the generated JS, a control character
+/// which doesn't otherwise appear in it.
+const MARKER: char = '\u{1}';
+
+#[derive(Default)]
+pub struct SourceMapBuilder {
+    locations: Vec<AuxSourceLocation>,
+}
+
+#[derive(serde::Serialize)]
+struct SourceMap<'a> {
+    version: u32,
+    file: &'a str,
+    sources: Vec<&'a str>,
+    names: Vec<&'a str>,
+    mappings: String,
+}
+
+impl SourceMapBuilder {
+    /// Returns a marker to insert into the JS on the line where the item at
+    /// `location` starts.
+    pub fn marker(&mut self, location: &AuxSourceLocation) -> String {
+        self.locations.push(location.clone());
+        format!("{MARKER}{}{MARKER}", self.locations.len() - 1)
+    }
+
+    /// Removes all markers from `js` and returns a source map for it, or
+    /// `None` if it doesn't contain any markers.
+    ///
+    /// The lines of the source map are relative to the trimmed `js`, as the
+    /// output is written after resetting its indentation.
+    pub fn finish(&self, js: &mut String, file: &str) -> Option<String> {
+        if !js.contains(MARKER) {
+            return None;
+        }
+
+        // The first location on each line, by line.
+        let mut lines = BTreeMap::new();
+        let mut stripped = String::with_capacity(js.len());
+        let mut line = 0;
+        for (i, part) in js.split(MARKER).enumerate() {
+            if i % 2 == 0 {
+                line += part.matches('\n').count();
+                stripped.push_str(part);
+            } else {
+                let index: usize = part.parse().expect("invalid source map marker");
+                lines.entry(line).or_insert(index);
+            }
+        }
+        *js = stripped;
+
+        let leading = js.len() - js.trim_start().len();
+        let skipped_lines = js[..leading].matches('\n').count();
+
+        let mut sources = Vec::new();
+        let mut source_indices = HashMap::new();
+        let mut mappings = String::new();
+        let mut generated_line = 0;
+        let (mut prev_source, mut prev_line, mut prev_column) = (0, 0, 0);
+        for (line, index) in lines {
+            let location = &self.locations[index];
+            let line = line - skipped_lines;
+            while generated_line < line {
+                mappings.push(';');
+                generated_line += 1;
+            }
+
+            let source = *source_indices
+                .entry(location.file.as_str())
+                .or_insert_with(|| {
+                    sources.push(location.file.as_str());
+                    sources.len() as i64 - 1
+                });
+            // Rust lines are 1-based, source map lines are 0-based.
+            let source_line = i64::from(location.line) - 1;
+            let source_column = i64::from(location.column);
+
+            // Each segment is the generated column, which is always the start
+            // of the line, followed by the source file, line and column
+            // relative to the previous segment.
+            encode_vlq(&mut mappings, 0);
+            encode_vlq(&mut mappings, source - prev_source);
+            encode_vlq(&mut mappings, source_line - prev_line);
+            encode_vlq(&mut mappings, source_column - prev_column);
+            prev_source = source;
+            prev_line = source_line;
+            prev_column = source_column;
+        }
+
+        let map = SourceMap {
+            version: 3,
+            file,
+            sources,
+            names: Vec::new(),
+            mappings,
+        };
+        Some(serde_json::to_string(&map).unwrap())
+    }
+}
+
+/// Appends `value` as a base64 VLQ, as used by the `mappings` of source maps.
+fn encode_vlq(dst: &mut String, value: i64) {
+    const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut value = if value < 0 {
+        (-value << 1) | 1
+    } else {
+        value << 1
+    };
+    loop {
+        let mut digit = value & 0b11111;
+        value >>= 5;
+        if value > 0 {
+            digit |= 0b100000;
+        }
+        dst.push(BASE64[digit as usize] as char);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vlq(value: i64) -> String {
+        let mut dst = String::new();
+        encode_vlq(&mut dst, value);
+        dst
+    }
+
+    #[test]
+    fn vlq_values() {
+        assert_eq!(vlq(0), "A");
+        assert_eq!(vlq(1), "C");
+        assert_eq!(vlq(-1), "D");
+        assert_eq!(vlq(15), "e");
+        assert_eq!(vlq(16), "gB");
+        assert_eq!(vlq(-17), "jB");
+    }
+
+    #[test]
+    fn markers_are_mapped_to_lines() {
+        let location = |file: &str, line, column| AuxSourceLocation {
+            file: file.to_string(),
+            line,
+            column,
+        };
+        let mut builder = SourceMapBuilder::default();
+        let mut js = format!(
+            "\n\n{}class Foo {{\n    bar{}() {{}}\n}}\nfunction{}() {{}}\n",
+            builder.marker(&location("src/lib.rs", 3, 11)),
+            builder.marker(&location("src/lib.rs", 7, 11)),
+            builder.marker(&location("src/other.rs", 1, 7)),
+        );
+        let map = builder.finish(&mut js, "foo.js").unwrap();
+
+        assert!(!js.contains(MARKER));
+        assert_eq!(
+            map,
+            r#"{"version":3,"file":"foo.js","sources":["src/lib.rs","src/other.rs"],"names":[],"mappings":"AAEW;AAIA;;ACNJ"}"#
+        );
+    }
+
+    #[test]
+    fn no_markers() {
+        let mut js = "function foo() {}\n".to_string();
+        assert_eq!(SourceMapBuilder::default().finish(&mut js, "foo.js"), None);
+    }
+}
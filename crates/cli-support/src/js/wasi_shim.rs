@@ -0,0 +1,282 @@
+//! A built-in JS implementation of the WASI preview1 imports.
+//!
+//! This is used instead of the WWRR runtime when `--wasi-shim` is passed. The
+//! shim is single-threaded and only implements what's needed to run small
+//! WASI crates in JS hosts: standard output and error are forwarded to the
+//! console, clocks and randomness come from the host, and arguments and
+//! environment variables can be set by the caller. Everything else
+//! fails with `ENOSYS`.
+
+/// Returns the JS implementation of the given WASI import, or `None` if the
+/// import doesn't belong to WASI.
+pub(super) fn import(module: &str, name: &str) -> Option<&'static str> {
+    let js = match (module, name) {
+        ("wasi_snapshot_preview1", name) => match name {
+            "fd_write" => FD_WRITE,
+            "fd_read" => FD_READ,
+            "fd_close" | "fd_sync" | "fd_datasync" => FD_CLOSE,
+            "fd_fdstat_get" => FD_FDSTAT_GET,
+            "fd_prestat_get" | "fd_prestat_dir_name" => FD_PRESTAT,
+            "args_sizes_get" => ARGS_SIZES_GET,
+            "args_get" => ARGS_GET,
+            "environ_sizes_get" => ENVIRON_SIZES_GET,
+            "environ_get" => ENVIRON_GET,
+            "clock_res_get" => CLOCK_RES_GET,
+            "clock_time_get" => CLOCK_TIME_GET,
+            "random_get" => RANDOM_GET,
+            "proc_exit" => PROC_EXIT,
+            "sched_yield" => SCHED_YIELD,
+            _ => UNSUPPORTED,
+        },
+        ("wasi", "thread-spawn") => THREAD_SPAWN,
+        ("wasi", _) => UNSUPPORTED_THREADS,
+        _ => return None,
+    };
+    Some(js)
+}
+
+/// The error thrown by `proc_exit`, which is exported so that callers can
+/// tell an exit apart from other errors.
+pub(super) const EXIT_ERROR: &str = "
+class WasiExitError extends Error {
+    constructor(code) {
+        super(`WASI program exited with code ${code}`);
+        this.name = 'WasiExitError';
+        this.code = code;
+    }
+}
+";
+
+/// The TypeScript declaration of [`EXIT_ERROR`].
+pub(super) const EXIT_ERROR_TS: &str = "\
+class WasiExitError extends Error {
+  constructor(code: number);
+  /**
+   * The exit code passed to `proc_exit`.
+   */
+  readonly code: number;
+}
+";
+
+/// Sets the arguments and environment variables seen by the module. This is
+/// exported for every target, since only `web` and `no-modules` have an
+/// initialization function taking them.
+pub(super) const CONFIGURE: &str = "
+function configureWasi(options) {
+    __wbg_wasi_configure(options);
+}
+";
+
+/// The TypeScript declaration of [`CONFIGURE`].
+pub(super) const CONFIGURE_TS: &str = "\
+function configureWasi(options?: { args?: string[], env?: Record<string, string> }): void;
+";
+
+/// State and helpers shared by all WASI import implementations.
+pub(super) const HELPERS: &str = "
+const __wbg_wasi_ERRNO_SUCCESS = 0;
+const __wbg_wasi_ERRNO_BADF = 8;
+const __wbg_wasi_ERRNO_INVAL = 28;
+const __wbg_wasi_ERRNO_NOSYS = 52;
+
+let __wbg_wasi_args = [];
+let __wbg_wasi_env = {};
+
+function __wbg_wasi_configure(options) {
+    if (options === undefined) return;
+    if (options.args !== undefined) __wbg_wasi_args = Array.from(options.args, String);
+    if (options.env !== undefined) __wbg_wasi_env = { ...options.env };
+}
+
+function __wbg_wasi_view() {
+    return new DataView(__wbg_wasi_buffer());
+}
+
+// Views of shared memory can't be passed to `TextDecoder` or
+// `crypto.getRandomValues`, so data is always copied in and out.
+function __wbg_wasi_read_bytes(ptr, len) {
+    return new Uint8Array(__wbg_wasi_buffer(), ptr >>> 0, len >>> 0).slice();
+}
+
+function __wbg_wasi_write_strings(strings, ptrs, buf) {
+    const encoder = new TextEncoder();
+    const view = __wbg_wasi_view();
+    const memory = new Uint8Array(__wbg_wasi_buffer());
+    ptrs >>>= 0;
+    buf >>>= 0;
+    for (const s of strings) {
+        view.setUint32(ptrs, buf, true);
+        ptrs += 4;
+        const bytes = encoder.encode(s);
+        memory.set(bytes, buf);
+        memory[buf + bytes.length] = 0;
+        buf += bytes.length + 1;
+    }
+    return __wbg_wasi_ERRNO_SUCCESS;
+}
+
+function __wbg_wasi_write_sizes(strings, count, size) {
+    const encoder = new TextEncoder();
+    const view = __wbg_wasi_view();
+    view.setUint32(count >>> 0, strings.length, true);
+    view.setUint32(size >>> 0, strings.reduce((sum, s) => sum + encoder.encode(s).length + 1, 0), true);
+    return __wbg_wasi_ERRNO_SUCCESS;
+}
+
+function __wbg_wasi_environ() {
+    return Object.entries(__wbg_wasi_env).map(([key, value]) => `${key}=${value}`);
+}
+
+// Output is buffered per file descriptor and forwarded to the console
+// line by line.
+const __wbg_wasi_output = {
+    1: { decoder: new TextDecoder('utf-8'), line: '', log: (line) => console.log(line) },
+    2: { decoder: new TextDecoder('utf-8'), line: '', log: (line) => console.error(line) },
+};
+";
+
+const FD_WRITE: &str = "
+function(fd, iovs, iovs_len, nwritten) {
+    const output = __wbg_wasi_output[fd];
+    if (output === undefined) return __wbg_wasi_ERRNO_BADF;
+    const view = __wbg_wasi_view();
+    let written = 0;
+    for (let i = 0; i < iovs_len; i++) {
+        const ptr = view.getUint32((iovs >>> 0) + i * 8, true);
+        const len = view.getUint32((iovs >>> 0) + i * 8 + 4, true);
+        output.line += output.decoder.decode(__wbg_wasi_read_bytes(ptr, len), { stream: true });
+        written += len;
+    }
+    const lines = output.line.split('\\n');
+    output.line = lines.pop();
+    lines.forEach(output.log);
+    view.setUint32(nwritten >>> 0, written, true);
+    return __wbg_wasi_ERRNO_SUCCESS;
+}
+";
+
+const FD_READ: &str = "
+function(fd, iovs, iovs_len, nread) {
+    if (fd !== 0) return __wbg_wasi_ERRNO_BADF;
+    __wbg_wasi_view().setUint32(nread >>> 0, 0, true);
+    return __wbg_wasi_ERRNO_SUCCESS;
+}
+";
+
+const FD_CLOSE: &str = "
+function(fd) {
+    return fd <= 2 ? __wbg_wasi_ERRNO_SUCCESS : __wbg_wasi_ERRNO_BADF;
+}
+";
+
+const FD_FDSTAT_GET: &str = "
+function(fd, stat) {
+    if (fd > 2) return __wbg_wasi_ERRNO_BADF;
+    const view = __wbg_wasi_view();
+    stat >>>= 0;
+    // Character device without any flags or rights.
+    view.setUint8(stat, 2);
+    view.setUint16(stat + 2, 0, true);
+    view.setBigUint64(stat + 8, 0n, true);
+    view.setBigUint64(stat + 16, 0n, true);
+    return __wbg_wasi_ERRNO_SUCCESS;
+}
+";
+
+// There are no preopened directories.
+const FD_PRESTAT: &str = "
+function() {
+    return __wbg_wasi_ERRNO_BADF;
+}
+";
+
+const ARGS_SIZES_GET: &str = "
+function(count, size) {
+    return __wbg_wasi_write_sizes(__wbg_wasi_args, count, size);
+}
+";
+
+const ARGS_GET: &str = "
+function(ptrs, buf) {
+    return __wbg_wasi_write_strings(__wbg_wasi_args, ptrs, buf);
+}
+";
+
+const ENVIRON_SIZES_GET: &str = "
+function(count, size) {
+    return __wbg_wasi_write_sizes(__wbg_wasi_environ(), count, size);
+}
+";
+
+const ENVIRON_GET: &str = "
+function(ptrs, buf) {
+    return __wbg_wasi_write_strings(__wbg_wasi_environ(), ptrs, buf);
+}
+";
+
+const CLOCK_RES_GET: &str = "
+function(id, resolution) {
+    if (id > 3) return __wbg_wasi_ERRNO_INVAL;
+    __wbg_wasi_view().setBigUint64(resolution >>> 0, 1000n, true);
+    return __wbg_wasi_ERRNO_SUCCESS;
+}
+";
+
+const CLOCK_TIME_GET: &str = "
+function(id, precision, time) {
+    let ms;
+    switch (id) {
+        case 0: ms = performance.timeOrigin + performance.now(); break;
+        case 1: case 2: case 3: ms = performance.now(); break;
+        default: return __wbg_wasi_ERRNO_INVAL;
+    }
+    __wbg_wasi_view().setBigUint64(time >>> 0, BigInt(Math.round(ms * 1e6)), true);
+    return __wbg_wasi_ERRNO_SUCCESS;
+}
+";
+
+const RANDOM_GET: &str = "
+function(buf, len) {
+    buf >>>= 0;
+    len >>>= 0;
+    // `getRandomValues` is limited to 65536 bytes per call.
+    const chunk = new Uint8Array(Math.min(len, 65536));
+    for (let offset = 0; offset < len; offset += chunk.length) {
+        const bytes = chunk.subarray(0, Math.min(chunk.length, len - offset));
+        crypto.getRandomValues(bytes);
+        new Uint8Array(__wbg_wasi_buffer()).set(bytes, buf + offset);
+    }
+    return __wbg_wasi_ERRNO_SUCCESS;
+}
+";
+
+const PROC_EXIT: &str = "
+function(code) {
+    throw new WasiExitError(code);
+}
+";
+
+const SCHED_YIELD: &str = "
+function() {
+    return __wbg_wasi_ERRNO_SUCCESS;
+}
+";
+
+const UNSUPPORTED: &str = "
+function() {
+    return __wbg_wasi_ERRNO_NOSYS;
+}
+";
+
+// A negative return value signals that no thread could be spawned.
+const THREAD_SPAWN: &str = "
+function() {
+    return -__wbg_wasi_ERRNO_NOSYS;
+}
+";
+
+const UNSUPPORTED_THREADS: &str = "
+function() {
+    throw new Error('WASI threads are not supported by the built-in WASI shim');
+}
+";
@@ -21,11 +21,14 @@ mod interpreter;
 mod intrinsic;
 mod js;
 mod multivalue;
+mod package;
 mod transforms;
 pub mod wasm2es6js;
 mod wasm_conventions;
 mod wit;
 
+pub use crate::package::{Package, PackageMetadata};
+
 pub struct Bindgen {
     input: Input,
     out_name: Option<String>,
@@ -45,9 +48,14 @@ pub struct Bindgen {
     encode_into: EncodeInto,
     split_linked_modules: bool,
     wait: bool,
+    max_spin_ns: u64,
+    spin_timeout: SpinTimeout,
+    spin_telemetry: bool,
     wasi: bool,
+    wasi_shim: bool,
     wwrr_dir: Option<PathBuf>,
     generate_reset_state: bool,
+    manifest: bool,
 }
 
 pub struct Output {
@@ -62,6 +70,9 @@ struct Generated {
     js: String,
     ts: String,
     start: Option<String>,
+    js_source_map: Option<String>,
+    start_source_map: Option<String>,
+    manifest: Option<String>,
     snippets: BTreeMap<String, Vec<String>>,
     local_modules: HashMap<String, String>,
     npm_dependencies: HashMap<String, (PathBuf, String)>,
@@ -93,6 +104,17 @@ pub enum EncodeInto {
     Never,
 }
 
+/// What the wait transform does when a spinning wait exceeds its budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpinTimeout {
+    /// Traps with an `unreachable` instruction.
+    Trap,
+    /// Throws a JS error.
+    Throw,
+    /// Logs a warning and keeps spinning.
+    Log,
+}
+
 impl Bindgen {
     pub fn new() -> Bindgen {
         let externref =
@@ -119,9 +141,14 @@ impl Bindgen {
             omit_default_module_path: true,
             split_linked_modules: false,
             wait: false,
+            max_spin_ns: transforms::wait::DEFAULT_MAX_SPIN_NS,
+            spin_timeout: SpinTimeout::Throw,
+            spin_telemetry: false,
             wasi: false,
+            wasi_shim: false,
             wwrr_dir: env::var_os("WWRR_DIR").map(|v| v.into()),
             generate_reset_state: false,
+            manifest: false,
         }
     }
 
@@ -310,6 +337,36 @@ impl Bindgen {
         self
     }
 
+    /// Sets how long the wait transform spins on the main thread before
+    /// giving up, zero disables the limit. Defaults to ten seconds.
+    pub fn max_spin_ns(&mut self, max_spin_ns: u64) -> &mut Bindgen {
+        self.max_spin_ns = max_spin_ns;
+        self
+    }
+
+    /// Sets what happens when a spinning wait exceeds the maximum spin time.
+    pub fn spin_timeout(&mut self, spin_timeout: SpinTimeout) -> &mut Bindgen {
+        self.spin_timeout = spin_timeout;
+        self
+    }
+
+    /// Exports the `spin_ns_total` and `spin_count` globals, which record
+    /// how long and how often the main thread spun instead of waiting.
+    pub fn spin_telemetry(&mut self, spin_telemetry: bool) -> &mut Bindgen {
+        self.spin_telemetry = spin_telemetry;
+        self
+    }
+
+    /// Provides the WASI imports of a WASI module through a built-in JS shim
+    /// instead of the WWRR runtime.
+    ///
+    /// The shim is single-threaded and only supports console output, clocks,
+    /// randomness, arguments and environment variables.
+    pub fn wasi_shim(&mut self, wasi_shim: bool) -> &mut Bindgen {
+        self.wasi_shim = wasi_shim;
+        self
+    }
+
     /// Sets the path to the WWRR files.
     pub fn wwrr_dir(&mut self, wwrr_dir: impl AsRef<Path>) -> &mut Bindgen {
         self.wwrr_dir = Some(wwrr_dir.as_ref().to_path_buf());
@@ -321,6 +378,13 @@ impl Bindgen {
         self
     }
 
+    /// Whether to write a JSON manifest describing the exports and imports of
+    /// the generated bindings, `{stem}.manifest.json`.
+    pub fn manifest(&mut self, manifest: bool) -> &mut Bindgen {
+        self.manifest = manifest;
+        self
+    }
+
     pub fn generate<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
         self.generate_output()?.emit(path.as_ref())
     }
@@ -372,21 +436,36 @@ impl Bindgen {
             bail!("exported symbol \"default\" not allowed for --target web")
         }
 
-        // Check if module is targeting WASI.
-        self.wasi = module.imports.iter().any(is_wasi_import);
-        if self.wasi {
+        // Check if module is targeting WASI. The WWRR runtime is only needed
+        // if the WASI imports aren't provided by the built-in shim.
+        let wasi = module.imports.iter().any(is_wasi_import);
+        if wasi {
             log::debug!("WASI module detected");
         }
+        self.wasi = wasi && !self.wasi_shim;
+
+        // Without the WWRR runtime nobody else initializes reactor modules, so
+        // do it from the start function instead.
+        if wasi && self.wasi_shim {
+            initialize_reactor(&mut module);
+        }
 
         // Perform thread transform for wasm32-unknown-unknown with atomics.
         let thread_count = transforms::threads::run(&mut module)
             .context("failed to prepare module for threading")?;
 
         // Perform wait transform for WASI or if explicitly requested.
-        if self.wasi || self.wait {
+        let wait = wasi || self.wait;
+        if wait {
             log::debug!("transformating atomics.wait into spinning on the main thread");
-            transforms::wait::run(&mut module, PLACEHOLDER_MODULE)
-                .context("wait transform failed")?;
+            transforms::wait::run(
+                &mut module,
+                PLACEHOLDER_MODULE,
+                self.max_spin_ns,
+                self.spin_timeout,
+                self.spin_telemetry,
+            )
+            .context("wait transform failed")?;
         }
 
         // Check that reset_state is only used with --target module, web, or node
@@ -511,9 +590,14 @@ impl Bindgen {
             .customs
             .delete_typed::<wit::NonstandardWitSection>()
             .unwrap();
-        let mut cx = js::Context::new(&mut module, self, &adapters, &aux)?;
+        let mut cx = js::Context::new(&mut module, self, &adapters, &aux, wait)?;
         cx.generate()?;
         let (js, ts, start) = cx.finalize(stem)?;
+        let manifest = if self.manifest {
+            Some(cx.manifest()?)
+        } else {
+            None
+        };
         let generated = Generated {
             snippets: aux.snippets.clone(),
             local_modules: aux.local_modules.clone(),
@@ -523,6 +607,9 @@ impl Bindgen {
             js,
             ts,
             start,
+            js_source_map: cx.js_source_map.take(),
+            start_source_map: cx.start_source_map.take(),
+            manifest,
             wasi: self.wasi,
         };
 
@@ -703,6 +790,10 @@ impl Output {
         self.generated.start.as_ref()
     }
 
+    pub fn manifest(&self) -> Option<&str> {
+        self.generated.manifest.as_deref()
+    }
+
     pub fn snippets(&self) -> &BTreeMap<String, Vec<String>> {
         &self.generated.snippets
     }
@@ -790,12 +881,27 @@ impl Output {
                 .with_context(|| format!("failed to write `{}`", path.as_ref().display()))
         }
 
+        // Source maps are written next to the JS they're for, which links to
+        // them with a `sourceMappingURL` comment.
+        fn write_js(path: &Path, js: &str, source_map: Option<&String>) -> Result<(), Error> {
+            let mut js = reset_indentation(js);
+            if let Some(source_map) = source_map {
+                let mut map_path = path.as_os_str().to_owned();
+                map_path.push(".map");
+                let map_path = PathBuf::from(map_path);
+                write(&map_path, source_map)?;
+                let map_name = map_path.file_name().unwrap().to_string_lossy();
+                js.push_str(&format!("//# sourceMappingURL={map_name}\n"));
+            }
+            write(path, js)
+        }
+
         let js_path = out_dir.join(&self.stem).with_extension(extension);
-        write(&js_path, reset_indentation(&gen.js))?;
+        write_js(&js_path, &gen.js, gen.js_source_map.as_ref())?;
 
         if let Some(start) = &gen.start {
             let js_path = out_dir.join(wasm_name).with_extension(extension);
-            write(&js_path, reset_indentation(start))?;
+            write_js(&js_path, start, gen.start_source_map.as_ref())?;
         }
 
         if gen.typescript {
@@ -804,6 +910,11 @@ impl Output {
                 .with_context(|| format!("failed to write `{}`", ts_path.display()))?;
         }
 
+        if let Some(manifest) = &gen.manifest {
+            let manifest_path = out_dir.join(format!("{}.manifest.json", self.stem));
+            write(&manifest_path, manifest)?;
+        }
+
         if gen.typescript {
             let ts_path = wasm_path.with_extension("wasm.d.ts");
             let ts = wasm2es6js::typescript(&self.module)?;
@@ -815,7 +926,7 @@ impl Output {
             let Some(wwrr_dir) = &self.wwrr_dir else {
                 bail!(
                     "WWRR directory (command line option or WWRR_DIR environment variable) \
-                     must be specified for a WASI module"
+                     must be specified for a WASI module unless the built-in WASI shim is used"
                 )
             };
 
@@ -928,7 +1039,22 @@ where
     pairs.into_iter()
 }
 
+/// Moves the call to `_initialize` of a WASI reactor module into the start
+/// function.
+fn initialize_reactor(module: &mut Module) {
+    let Some(export) = module.exports.iter().find(|e| e.name == "_initialize") else {
+        return;
+    };
+    let (id, walrus::ExportItem::Function(initialize)) = (export.id(), export.item) else {
+        return;
+    };
+    module.exports.delete(id);
+    wasm_conventions::get_or_insert_start_builder(module)
+        .func_body()
+        .call(initialize);
+}
+
 /// Checks whether the import is from the WASI API.
-fn is_wasi_import(import: &Import) -> bool {
+pub fn is_wasi_import(import: &Import) -> bool {
     import.module == "wasi_snapshot_preview1" || import.module == "wasi"
 }
@@ -0,0 +1,312 @@
+//! Generation of a publishable npm package from one or more outputs of
+//! `Bindgen`, each built for a different target.
+
+use crate::{Output, OutputMode, WWRR_FILES, WWRR_TS_FILES};
+use anyhow::{Context, Error};
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// The metadata of the generated `package.json`, typically taken from the
+/// `Cargo.toml` of the crate.
+#[derive(Debug, Clone, Default)]
+pub struct PackageMetadata {
+    pub name: String,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub license: Option<String>,
+    pub repository: Option<String>,
+}
+
+/// An npm package made up of the outputs of `Bindgen` for one or more targets.
+///
+/// Each output is emitted to its own directory of the package, and the
+/// generated `package.json` maps the targets to conditional `exports` entries.
+pub struct Package {
+    metadata: PackageMetadata,
+    targets: Vec<PackageTarget>,
+}
+
+struct PackageTarget {
+    /// The directory of the output relative to the package root, either empty
+    /// or ending with a `/`.
+    dir: String,
+    mode: OutputMode,
+    stem: String,
+    typescript: bool,
+    files: Vec<String>,
+    npm_dependencies: Vec<(String, String)>,
+}
+
+/// The conditions a target is used for, in the order they're listed in
+/// `exports`, which is the order in which they're matched.
+const CONDITIONS: &[&str] = &["node", "deno", "worker", "browser"];
+
+impl Package {
+    pub fn new(metadata: PackageMetadata) -> Package {
+        Package {
+            metadata,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Adds an output which has been emitted to `dir`, relative to the root
+    /// directory of the package.
+    ///
+    /// If multiple outputs claim the same export condition, e.g. both `web`
+    /// and `bundler` for `browser`, the output added first is used.
+    pub fn add_output(&mut self, dir: &str, output: &Output) {
+        let gen = &output.generated;
+        let dir = if dir.is_empty() || dir.ends_with('/') {
+            dir.to_string()
+        } else {
+            format!("{dir}/")
+        };
+        let stem = &output.stem;
+
+        let mut files = vec![format!("{dir}{stem}.js"), format!("{dir}{stem}_bg.wasm")];
+        if gen.js_source_map.is_some() {
+            files.push(format!("{dir}{stem}.js.map"));
+        }
+        if gen.start.is_some() {
+            files.push(format!("{dir}{stem}_bg.js"));
+        }
+        if gen.start_source_map.is_some() {
+            files.push(format!("{dir}{stem}_bg.js.map"));
+        }
+        if gen.typescript {
+            files.push(format!("{dir}{stem}.d.ts"));
+            files.push(format!("{dir}{stem}_bg.wasm.d.ts"));
+        }
+        if gen.manifest.is_some() {
+            files.push(format!("{dir}{stem}.manifest.json"));
+        }
+        // Every crate has an entry in `snippets`, even without inline JS.
+        let has_snippets = gen.snippets.values().any(|list| !list.is_empty());
+        if has_snippets || !gen.local_modules.is_empty() {
+            files.push(format!("{dir}snippets/"));
+        }
+        if gen.wasi {
+            files.extend(WWRR_FILES.iter().map(|file| format!("{dir}{file}")));
+            if gen.typescript {
+                files.extend(WWRR_TS_FILES.iter().map(|file| format!("{dir}{file}")));
+            }
+        }
+        if !dir.is_empty() {
+            files.push(format!("{dir}package.json"));
+        }
+
+        self.targets.push(PackageTarget {
+            dir,
+            mode: gen.mode.clone(),
+            stem: stem.clone(),
+            typescript: gen.typescript,
+            files,
+            npm_dependencies: gen
+                .npm_dependencies
+                .iter()
+                .map(|(name, (_, version))| (name.clone(), version.clone()))
+                .collect(),
+        });
+    }
+
+    /// Writes the `package.json` of the package to `out_dir`, along with one
+    /// in the directory of each output setting whether it's an ES module.
+    pub fn write(&self, out_dir: &Path) -> Result<(), Error> {
+        for target in self.targets.iter().filter(|t| !t.dir.is_empty()) {
+            #[derive(Serialize)]
+            struct NestedPackageJson {
+                #[serde(rename = "type")]
+                ty: &'static str,
+            }
+            let ty = if target.mode.uses_es_modules() {
+                "module"
+            } else {
+                "commonjs"
+            };
+            let json = serde_json::to_string_pretty(&NestedPackageJson { ty })?;
+            write(&out_dir.join(&target.dir).join("package.json"), json)?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.package_json())?;
+        write(&out_dir.join("package.json"), json)
+    }
+
+    fn package_json(&self) -> PackageJson<'_> {
+        let metadata = &self.metadata;
+
+        // Outputs of the `no-modules` target can't be imported, so they're
+        // only listed in `files`.
+        let importable: Vec<_> = self
+            .targets
+            .iter()
+            .filter(|t| !t.mode.no_modules())
+            .collect();
+        let default = importable.first().copied();
+        let node = importable.iter().find(|t| t.mode.nodejs()).copied();
+        let module = importable
+            .iter()
+            .find(|t| t.mode.bundler())
+            .or_else(|| importable.iter().find(|t| t.mode.uses_es_modules()))
+            .copied();
+
+        let exports = default.map(|default| {
+            let mut conditions = Vec::new();
+            if importable.len() > 1 {
+                for condition in CONDITIONS {
+                    let target = importable
+                        .iter()
+                        .find(|t| t.conditions().contains(condition));
+                    if let Some(target) = target {
+                        conditions.push((*condition, target.entry()));
+                    }
+                }
+            }
+            conditions.push(("default", default.entry()));
+            Exports {
+                root: OrderedMap(conditions),
+                rest: "./*",
+            }
+        });
+
+        let ty = match self.targets.as_slice() {
+            [target] if target.mode.uses_es_modules() => Some("module"),
+            _ => None,
+        };
+
+        let mut side_effects = Vec::new();
+        for target in self.targets.iter() {
+            side_effects.push(format!("./{}{}.js", target.dir, target.stem));
+            side_effects.push(format!("./{}snippets/*", target.dir));
+        }
+
+        let dependencies = self
+            .targets
+            .iter()
+            .flat_map(|t| t.npm_dependencies.iter())
+            .map(|(name, version)| (name.as_str(), version.as_str()))
+            .collect();
+
+        PackageJson {
+            name: &metadata.name,
+            version: metadata.version.as_deref(),
+            description: metadata.description.as_deref(),
+            license: metadata.license.as_deref(),
+            repository: metadata
+                .repository
+                .as_deref()
+                .map(|url| Repository { ty: "git", url }),
+            ty,
+            main: node.or(default).map(|t| t.js()),
+            module: module.map(|t| t.js()),
+            types: default.and_then(|t| t.types()),
+            exports,
+            files: self.targets.iter().flat_map(|t| t.files.iter()).collect(),
+            side_effects,
+            dependencies,
+        }
+    }
+}
+
+impl PackageTarget {
+    fn conditions(&self) -> &'static [&'static str] {
+        match self.mode {
+            OutputMode::Node { .. } => &["node"],
+            OutputMode::Deno => &["deno"],
+            OutputMode::Web => &["worker", "browser"],
+            OutputMode::Bundler { .. } | OutputMode::Module => &["browser"],
+            OutputMode::NoModules { .. } => &[],
+        }
+    }
+
+    fn js(&self) -> String {
+        format!("./{}{}.js", self.dir, self.stem)
+    }
+
+    fn types(&self) -> Option<String> {
+        self.typescript
+            .then(|| format!("./{}{}.d.ts", self.dir, self.stem))
+    }
+
+    fn entry(&self) -> ExportEntry {
+        match self.types() {
+            Some(types) => ExportEntry::Typed {
+                types,
+                default: self.js(),
+            },
+            None => ExportEntry::Untyped(self.js()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct PackageJson<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repository: Option<Repository<'a>>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    ty: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    main: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    module: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    types: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exports: Option<Exports>,
+    files: Vec<&'a String>,
+    #[serde(rename = "sideEffects")]
+    side_effects: Vec<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    dependencies: BTreeMap<&'a str, &'a str>,
+}
+
+#[derive(Serialize)]
+struct Repository<'a> {
+    #[serde(rename = "type")]
+    ty: &'static str,
+    url: &'a str,
+}
+
+#[derive(Serialize)]
+struct Exports {
+    #[serde(rename = ".")]
+    root: OrderedMap<ExportEntry>,
+    /// Keeps all other files importable, e.g. the Wasm file for the `web`
+    /// target.
+    #[serde(rename = "./*")]
+    rest: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ExportEntry {
+    Typed { types: String, default: String },
+    Untyped(String),
+}
+
+/// A JSON object whose keys are kept in order, as the order of conditions in
+/// `exports` matters.
+struct OrderedMap<T>(Vec<(&'static str, T)>);
+
+impl<T: Serialize> Serialize for OrderedMap<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in self.0.iter() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+fn write(path: &Path, contents: String) -> Result<(), Error> {
+    fs::write(path, contents).with_context(|| format!("failed to write `{}`", path.display()))
+}
@@ -0,0 +1,9 @@
+(module
+  (memory 1 1 shared)
+  (func $wait (export "wait") (param i32 i64) (result i32)
+    local.get 0
+    local.get 1
+    i64.const -1
+    memory.atomic.wait64
+  )
+)
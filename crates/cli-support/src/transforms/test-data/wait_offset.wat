@@ -0,0 +1,21 @@
+(module
+  (memory 1 1 shared)
+  (func $wait32 (export "wait32") (param i32 i32) (result i32)
+    local.get 0
+    local.get 1
+    i64.const -1
+    memory.atomic.wait32 offset=16
+  )
+  (func $wait64 (export "wait64") (param i32 i64) (result i32)
+    local.get 0
+    local.get 1
+    i64.const -1
+    memory.atomic.wait64 offset=8
+  )
+  (func $wait64_again (export "wait64_again") (param i32 i64) (result i32)
+    local.get 0
+    local.get 1
+    i64.const 1000
+    memory.atomic.wait64 offset=8
+  )
+)
@@ -1,24 +1,35 @@
 //! The `wasm-bindgen` wait transformation.
 //!
-//! This crate provides a transformation to turn the instruction
-//! `i32.atomic.wait` into a function call.
-//! The function checks via a global if blocking
+//! This crate provides a transformation to turn the instructions
+//! `memory.atomic.wait32` and `memory.atomic.wait64` into function calls.
+//! A function is generated for each combination of wait width and
+//! memory offset found in the module.
+//! Each function checks via a global if blocking
 //! is allowed on the current thread. If not, it spins instead of
 //! issuing the wait operation.
 //!
+//! Spinning is limited by a configurable budget, after which the module
+//! traps, throws a JS error or logs a warning. Optionally the total time
+//! spent spinning is recorded in exported globals.
+//!
 
 #![deny(missing_docs, missing_debug_implementations)]
 
+use std::collections::{HashMap, HashSet};
+
 use anyhow::{anyhow, Context, Error, Result};
 
 use walrus::ir::{
-    dfs_pre_order_mut, BinaryOp, Call, Instr, LoadKind, MemArg, UnaryOp, Value, VisitorMut,
+    dfs_in_order, dfs_pre_order_mut, BinaryOp, Call, Instr, LoadKind, MemArg, UnaryOp, Value,
+    Visitor, VisitorMut,
 };
 use walrus::{
-    ConstExpr, ExportItem, FunctionBuilder, FunctionId, GlobalId, InstrLocId, MemoryId, Module,
-    ValType,
+    ConstExpr, ExportItem, FunctionBuilder, FunctionId, GlobalId, InstrLocId, InstrSeqBuilder,
+    LocalId, MemoryId, Module, ValType,
 };
 
+use crate::SpinTimeout;
+
 /// Import name of function returning monotonic clock in nanoseconds.
 pub const CLOCK_NS_IMPORT: &str = "__wbindgen_clock_ns";
 
@@ -34,14 +45,14 @@ pub const WAIT_PROHIBITED_GLOBAL: &str = "wait_prohibited";
 /// Export name of global that sets the maximum spin time.
 pub const MAX_SPIN_NS_GLOBAL: &str = "max_spin_ns";
 
-/// Default maximum spin time.
-const MAX_SPIN_NS: i64 = 10 * 1000 * 1000 * 1000;
+/// Export name of global that accumulates the time spent spinning.
+pub const SPIN_NS_TOTAL_GLOBAL: &str = "spin_ns_total";
 
-/// Supported memory argument.
-const MEM_ARG: MemArg = MemArg {
-    align: 4,
-    offset: 0,
-};
+/// Export name of global that counts the spinning wait operations.
+pub const SPIN_COUNT_GLOBAL: &str = "spin_count";
+
+/// Default maximum spin time.
+pub const DEFAULT_MAX_SPIN_NS: u64 = 10 * 1000 * 1000 * 1000;
 
 /// Adds the `__wbindgen_clock_ns` function import.
 fn add_clock_ns_import(module: &mut Module, placeholder_module: &str) -> FunctionId {
@@ -81,12 +92,13 @@ fn add_wait_prohibited_global(module: &mut Module) -> GlobalId {
 }
 
 /// Adds the maximum spin time global.
-fn add_max_spin_ns_global(module: &mut Module) -> GlobalId {
+fn add_max_spin_ns_global(module: &mut Module, max_spin_ns: u64) -> GlobalId {
+    // The global is compared unsigned, so reinterpreting the bits is fine.
     let global = module.globals.add_local(
         ValType::I64,
         true,
         false,
-        ConstExpr::Value(Value::I64(MAX_SPIN_NS)),
+        ConstExpr::Value(Value::I64(max_spin_ns as i64)),
     );
     module.globals.get_mut(global).name = Some(MAX_SPIN_NS_GLOBAL.into());
     module
@@ -95,24 +107,130 @@ fn add_max_spin_ns_global(module: &mut Module) -> GlobalId {
     global
 }
 
-/// Adds the `__atomic_wait32` function to the module.
-fn add_atomic_wait32_func(
+/// Adds an exported telemetry counter global.
+fn add_telemetry_global(module: &mut Module, name: &str) -> GlobalId {
+    let global =
+        module
+            .globals
+            .add_local(ValType::I64, true, false, ConstExpr::Value(Value::I64(0)));
+    module.globals.get_mut(global).name = Some(name.into());
+    module.exports.add(name, ExportItem::Global(global));
+    global
+}
+
+/// Globals recording the time spent spinning.
+#[derive(Clone, Copy, Debug)]
+struct Telemetry {
+    spin_ns_total: GlobalId,
+    spin_count: GlobalId,
+}
+
+/// Module items used by all spin functions.
+#[derive(Clone, Copy, Debug)]
+struct SpinItems {
+    memory: MemoryId,
+    clock_ns: FunctionId,
+    max_spin_ns: GlobalId,
+    /// Not present if the module traps on timeout.
+    spin_timeout: Option<FunctionId>,
+    atomics_pause: FunctionId,
+    on_timeout: SpinTimeout,
+    telemetry: Option<Telemetry>,
+}
+
+impl SpinItems {
+    /// Adds the time spent spinning since `start_time` to the telemetry
+    /// globals, if enabled.
+    fn record(&self, seq: &mut InstrSeqBuilder, start_time: LocalId) {
+        if let Some(telemetry) = self.telemetry {
+            seq.global_get(telemetry.spin_ns_total)
+                .call(self.clock_ns)
+                .local_get(start_time)
+                .binop(BinaryOp::I64Sub)
+                .binop(BinaryOp::I64Add)
+                .global_set(telemetry.spin_ns_total)
+                .global_get(telemetry.spin_count)
+                .i64_const(1)
+                .binop(BinaryOp::I64Add)
+                .global_set(telemetry.spin_count);
+        }
+    }
+}
+
+/// Kind of a wait instruction that is replaced by a function call.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+struct WaitKind {
+    /// Whether this is `memory.atomic.wait64` instead of `memory.atomic.wait32`.
+    sixty_four: bool,
+    /// The constant offset of the memory argument.
+    offset: u32,
+}
+
+impl WaitKind {
+    /// Type of the expected value.
+    fn ty(&self) -> ValType {
+        if self.sixty_four {
+            ValType::I64
+        } else {
+            ValType::I32
+        }
+    }
+
+    /// Memory argument of the wait and load instructions.
+    fn mem_arg(&self) -> MemArg {
+        MemArg {
+            align: if self.sixty_four { 8 } else { 4 },
+            offset: self.offset,
+        }
+    }
+
+    /// Atomic load of the waited on value.
+    fn load_kind(&self) -> LoadKind {
+        if self.sixty_four {
+            LoadKind::I64 { atomic: true }
+        } else {
+            LoadKind::I32 { atomic: true }
+        }
+    }
+
+    /// Comparison of the waited on value with the expected value.
+    fn ne(&self) -> BinaryOp {
+        if self.sixty_four {
+            BinaryOp::I64Ne
+        } else {
+            BinaryOp::I32Ne
+        }
+    }
+
+    /// Name of a generated function, e.g. `__atomic_wait64_offset_8`.
+    fn func_name(&self, prefix: &str) -> String {
+        let bits = if self.sixty_four { 64 } else { 32 };
+        match self.offset {
+            0 => format!("{prefix}{bits}"),
+            offset => format!("{prefix}{bits}_offset_{offset}"),
+        }
+    }
+}
+
+/// Adds the `__atomic_wait32` or `__atomic_wait64` function to the module.
+fn add_atomic_wait_func(
     module: &mut Module,
     memory: MemoryId,
+    kind: WaitKind,
     wait_prohibited: GlobalId,
-    atomic_spin32: FunctionId,
+    atomic_spin: FunctionId,
 ) -> FunctionId {
     let mut builder = FunctionBuilder::new(
         &mut module.types,
-        &[ValType::I32, ValType::I32, ValType::I64],
+        &[ValType::I32, kind.ty(), ValType::I64],
         &[ValType::I32],
     );
 
-    builder.name("__atomic_wait32".into());
+    builder.name(kind.func_name("__atomic_wait"));
 
     // Parameters.
     let ptr = module.locals.add(ValType::I32);
-    let expected = module.locals.add(ValType::I32);
+    let expected = module.locals.add(kind.ty());
     let timeout = module.locals.add(ValType::I64);
 
     builder.func_body().global_get(wait_prohibited).if_else(
@@ -121,40 +239,33 @@ fn add_atomic_wait32_func(
             then.local_get(ptr)
                 .local_get(expected)
                 .local_get(timeout)
-                .call(atomic_spin32);
+                .call(atomic_spin);
         },
         |else_| {
             else_
                 .local_get(ptr)
                 .local_get(expected)
                 .local_get(timeout)
-                .atomic_wait(memory, MEM_ARG, false);
+                .atomic_wait(memory, kind.mem_arg(), kind.sixty_four);
         },
     );
 
     builder.finish(vec![ptr, expected, timeout], &mut module.funcs)
 }
 
-/// Adds the `__atomic_spin32` function to the module.
-fn add_atomic_spin32_func(
-    module: &mut Module,
-    memory: MemoryId,
-    clock_ns: FunctionId,
-    max_spin_ns: GlobalId,
-    spin_timeout: FunctionId,
-    atomics_pause: FunctionId,
-) -> FunctionId {
+/// Adds the `__atomic_spin32` or `__atomic_spin64` function to the module.
+fn add_atomic_spin_func(module: &mut Module, kind: WaitKind, spin: SpinItems) -> FunctionId {
     let mut builder = FunctionBuilder::new(
         &mut module.types,
-        &[ValType::I32, ValType::I32, ValType::I64],
+        &[ValType::I32, kind.ty(), ValType::I64],
         &[ValType::I32],
     );
 
-    builder.name("__atomic_spin32".into());
+    builder.name(kind.func_name("__atomic_spin"));
 
     // Parameters.
     let ptr = module.locals.add(ValType::I32);
-    let expected = module.locals.add(ValType::I32);
+    let expected = module.locals.add(kind.ty());
     let timeout = module.locals.add(ValType::I64);
 
     // Locals.
@@ -166,9 +277,9 @@ fn add_atomic_spin32_func(
         .func_body()
         // check initial value
         .local_get(ptr)
-        .load(memory, LoadKind::I32 { atomic: true }, MEM_ARG)
+        .load(spin.memory, kind.load_kind(), kind.mem_arg())
         .local_get(expected)
-        .binop(BinaryOp::I32Ne)
+        .binop(kind.ne())
         .if_else(
             None,
             |then| {
@@ -178,10 +289,10 @@ fn add_atomic_spin32_func(
             |_| (),
         )
         // memory == expected, record start time
-        .call(clock_ns)
+        .call(spin.clock_ns)
         .local_set(start_time)
         // cache max_spin_ns in local
-        .global_get(max_spin_ns)
+        .global_get(spin.max_spin_ns)
         .local_set(max_spin_ns_local)
         // spin loop
         .loop_(None, |spin_loop| {
@@ -189,19 +300,20 @@ fn add_atomic_spin32_func(
             spin_loop
                 // check if memory still equals expected
                 .local_get(ptr)
-                .load(memory, LoadKind::I32 { atomic: true }, MEM_ARG)
+                .load(spin.memory, kind.load_kind(), kind.mem_arg())
                 .local_get(expected)
-                .binop(BinaryOp::I32Ne)
+                .binop(kind.ne())
                 .if_else(
                     None,
                     |then| {
                         // value changed, return 1
+                        spin.record(then, start_time);
                         then.i32_const(1).return_();
                     },
                     |_| (),
                 )
                 // get time
-                .call(clock_ns)
+                .call(spin.clock_ns)
                 .local_get(start_time)
                 .binop(BinaryOp::I64Sub)
                 .local_tee(elapsed)
@@ -211,6 +323,7 @@ fn add_atomic_spin32_func(
                     None,
                     |then| {
                         // timeout exceeded, return 2
+                        spin.record(then, start_time);
                         then.i32_const(2).return_();
                     },
                     |_| (),
@@ -231,14 +344,29 @@ fn add_atomic_spin32_func(
                                 None,
                                 |then| {
                                     // global spin timeout exceeded
-                                    then.call(spin_timeout).unreachable();
+                                    match (spin.on_timeout, spin.spin_timeout) {
+                                        (SpinTimeout::Log, Some(spin_timeout)) => {
+                                            // log once and keep spinning
+                                            then.call(spin_timeout)
+                                                .i64_const(0)
+                                                .local_set(max_spin_ns_local);
+                                        }
+                                        (SpinTimeout::Throw, Some(spin_timeout)) => {
+                                            spin.record(then, start_time);
+                                            then.call(spin_timeout).unreachable();
+                                        }
+                                        _ => {
+                                            spin.record(then, start_time);
+                                            then.unreachable();
+                                        }
+                                    }
                                 },
                                 |_| (),
                             );
                     },
                 )
                 // thread pause hint
-                .call(atomics_pause)
+                .call(spin.atomics_pause)
                 // repeat loop
                 .br(id);
         })
@@ -247,42 +375,64 @@ fn add_atomic_spin32_func(
     builder.finish(vec![ptr, expected, timeout], &mut module.funcs)
 }
 
-/// Replaces `atomic.wait32` by the specified function.
-struct ReplaceAtomicWait {
-    wait32_func: FunctionId,
+/// Collects the kinds of all wait instructions.
+struct CollectAtomicWait {
     memory: MemoryId,
+    kinds: HashSet<WaitKind>,
     failed: Option<Error>,
 }
 
-impl VisitorMut for ReplaceAtomicWait {
-    fn visit_instr_mut(&mut self, instr: &mut Instr, instr_loc: &mut InstrLocId) {
+impl<'instr> Visitor<'instr> for CollectAtomicWait {
+    fn visit_instr(&mut self, instr: &'instr Instr, instr_loc: &'instr InstrLocId) {
         if self.failed.is_some() {
             return;
         }
 
-        if let Some(wait) = instr.atomic_wait_mut() {
+        if let Instr::AtomicWait(wait) = instr {
             if wait.memory != self.memory {
                 self.failed = Some(anyhow!(
                     "unsupported wait memory index {} at {}",
                     wait.memory.index(),
                     instr_loc.data()
                 ));
+                return;
             }
 
-            if wait.arg.align != MEM_ARG.align || wait.arg.offset != MEM_ARG.offset {
+            let kind = WaitKind {
+                sixty_four: wait.sixty_four,
+                offset: wait.arg.offset,
+            };
+
+            if wait.arg.align != kind.mem_arg().align {
                 self.failed = Some(anyhow!(
                     "unsupported wait memory argument {:?} at {}",
                     wait.arg,
                     instr_loc.data()
                 ));
+                return;
             }
 
-            if wait.sixty_four {
-                self.failed = Some(anyhow!("unsupported wait64 at {}", instr_loc.data()));
-            }
+            self.kinds.insert(kind);
+        }
+    }
+}
+
+/// Replaces `memory.atomic.wait32` and `memory.atomic.wait64` by the
+/// function generated for their kind.
+struct ReplaceAtomicWait<'a> {
+    wait_funcs: &'a HashMap<WaitKind, FunctionId>,
+}
+
+impl VisitorMut for ReplaceAtomicWait<'_> {
+    fn visit_instr_mut(&mut self, instr: &mut Instr, _instr_loc: &mut InstrLocId) {
+        if let Some(wait) = instr.atomic_wait_mut() {
+            let kind = WaitKind {
+                sixty_four: wait.sixty_four,
+                offset: wait.arg.offset,
+            };
 
             *instr = Instr::Call(Call {
-                func: self.wait32_func,
+                func: self.wait_funcs[&kind],
             });
         }
     }
@@ -290,8 +440,19 @@ impl VisitorMut for ReplaceAtomicWait {
 
 /// Run the transformation.
 ///
+/// Spinning waits exceeding `max_spin_ns` nanoseconds are handled according
+/// to `on_timeout`, a budget of zero disables the limit. If `telemetry` is
+/// enabled, the total time spent spinning and the number of spinning waits
+/// are exported as globals.
+///
 /// See the module-level docs for details on the transformation.
-pub fn run(module: &mut Module, placeholder_module: &str) -> Result<()> {
+pub fn run(
+    module: &mut Module,
+    placeholder_module: &str,
+    max_spin_ns: u64,
+    on_timeout: SpinTimeout,
+    telemetry: bool,
+) -> Result<()> {
     // For now only one memory is supported.
     let memory = module
         .memories
@@ -300,40 +461,229 @@ pub fn run(module: &mut Module, placeholder_module: &str) -> Result<()> {
         .context("module has no memory")?
         .id();
 
+    // Find all kinds of wait instructions in the module.
+    let mut kinds = HashSet::new();
+    for (id, func) in module.funcs.iter_local() {
+        let mut visitor = CollectAtomicWait {
+            memory,
+            kinds: HashSet::new(),
+            failed: None,
+        };
+
+        dfs_in_order(&mut visitor, func, func.entry_block());
+
+        if let Some(err) = visitor.failed {
+            return Err(err).with_context(|| format!("processing function {} failed", id.index()));
+        }
+
+        kinds.extend(visitor.kinds);
+    }
+
     // Add necessary items to module.
-    let clock_ns = add_clock_ns_import(module, placeholder_module);
-    let spin_timeout = add_spin_timeout_import(module, placeholder_module);
-    let atomics_pause = add_atomics_pause_import(module, placeholder_module);
-    let max_spin_ns = add_max_spin_ns_global(module);
-    let spin32_func = add_atomic_spin32_func(
-        module,
+    let spin = SpinItems {
         memory,
-        clock_ns,
-        max_spin_ns,
-        spin_timeout,
-        atomics_pause,
-    );
+        clock_ns: add_clock_ns_import(module, placeholder_module),
+        max_spin_ns: add_max_spin_ns_global(module, max_spin_ns),
+        spin_timeout: match on_timeout {
+            SpinTimeout::Trap => None,
+            SpinTimeout::Throw | SpinTimeout::Log => {
+                Some(add_spin_timeout_import(module, placeholder_module))
+            }
+        },
+        atomics_pause: add_atomics_pause_import(module, placeholder_module),
+        on_timeout,
+        telemetry: telemetry.then(|| Telemetry {
+            spin_ns_total: add_telemetry_global(module, SPIN_NS_TOTAL_GLOBAL),
+            spin_count: add_telemetry_global(module, SPIN_COUNT_GLOBAL),
+        }),
+    };
     let wait_prohibited = add_wait_prohibited_global(module);
-    let wait32_func = add_atomic_wait32_func(module, memory, wait_prohibited, spin32_func);
 
-    // Replace all atomic.wait32 instructions by calls to function.
+    let mut kinds = kinds.into_iter().collect::<Vec<_>>();
+    kinds.sort_by_key(|kind| (kind.sixty_four, kind.offset));
+
+    let mut generated = HashSet::new();
+    let mut wait_funcs = HashMap::new();
+    for kind in kinds {
+        let spin_func = add_atomic_spin_func(module, kind, spin);
+        let wait_func = add_atomic_wait_func(module, memory, kind, wait_prohibited, spin_func);
+        generated.insert(spin_func);
+        generated.insert(wait_func);
+        wait_funcs.insert(kind, wait_func);
+    }
+
+    // Replace all wait instructions by calls to functions.
     for (id, func) in module.funcs.iter_local_mut() {
-        if id == wait32_func || id == spin32_func {
+        if generated.contains(&id) {
             continue;
         }
 
         let mut visitor = ReplaceAtomicWait {
-            wait32_func,
-            memory,
-            failed: None,
+            wait_funcs: &wait_funcs,
         };
 
         dfs_pre_order_mut(&mut visitor, func, func.entry_block());
+    }
 
-        if let Some(err) = visitor.failed {
-            return Err(err).with_context(|| format!("processing function {} failed", id.index()));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use walrus::ModuleConfig;
+
+    fn run_wat(wat: &str) -> Module {
+        run_wat_with(wat, DEFAULT_MAX_SPIN_NS, SpinTimeout::Throw, false)
+    }
+
+    fn run_wat_with(
+        wat: &str,
+        max_spin_ns: u64,
+        on_timeout: SpinTimeout,
+        telemetry: bool,
+    ) -> Module {
+        let wasm = wat::parse_str(wat).unwrap();
+        let mut module = ModuleConfig::new()
+            .generate_producers_section(false)
+            .parse(&wasm)
+            .unwrap();
+
+        run(
+            &mut module,
+            crate::PLACEHOLDER_MODULE,
+            max_spin_ns,
+            on_timeout,
+            telemetry,
+        )
+        .unwrap();
+        walrus::passes::gc::run(&mut module);
+
+        let features = wasmparser::WasmFeatures::default() | wasmparser::WasmFeatures::THREADS;
+        wasmparser::Validator::new_with_features(features)
+            .validate_all(&module.emit_wasm())
+            .unwrap();
+
+        module
+    }
+
+    /// Returns the names of all functions still containing a wait instruction.
+    fn waiting_funcs(module: &Module) -> Vec<String> {
+        struct HasWait(bool);
+
+        impl<'instr> Visitor<'instr> for HasWait {
+            fn visit_instr(&mut self, instr: &'instr Instr, _: &'instr InstrLocId) {
+                self.0 |= matches!(instr, Instr::AtomicWait(_));
+            }
         }
+
+        let mut names = module
+            .funcs
+            .iter_local()
+            .filter(|(_, func)| {
+                let mut visitor = HasWait(false);
+                dfs_in_order(&mut visitor, func, func.entry_block());
+                visitor.0
+            })
+            .map(|(id, _)| module.funcs.get(id).name.clone().unwrap_or_default())
+            .collect::<Vec<_>>();
+        names.sort();
+        names
     }
 
-    Ok(())
+    #[test]
+    fn test_wait32() {
+        let module = run_wat(include_str!("../test-data/wait32.wat"));
+        assert_eq!(waiting_funcs(&module), ["__atomic_wait32"]);
+    }
+
+    #[test]
+    fn test_wait64() {
+        let module = run_wat(include_str!("../test-data/wait64.wat"));
+        assert_eq!(waiting_funcs(&module), ["__atomic_wait64"]);
+        assert!(module
+            .funcs
+            .iter()
+            .any(|func| func.name.as_deref() == Some("__atomic_spin64")));
+    }
+
+    #[test]
+    fn test_wait_offset() {
+        let module = run_wat(include_str!("../test-data/wait_offset.wat"));
+        assert_eq!(
+            waiting_funcs(&module),
+            ["__atomic_wait32_offset_16", "__atomic_wait64_offset_8"]
+        );
+    }
+
+    fn exported_global(module: &Module, name: &str) -> Option<GlobalId> {
+        module
+            .exports
+            .iter()
+            .find(|export| export.name == name)
+            .and_then(|export| match export.item {
+                ExportItem::Global(global) => Some(global),
+                _ => None,
+            })
+    }
+
+    fn has_import(module: &Module, name: &str) -> bool {
+        module.imports.iter().any(|import| import.name == name)
+    }
+
+    #[test]
+    fn test_max_spin_ns() {
+        let module = run_wat_with(
+            include_str!("../test-data/wait32.wat"),
+            500,
+            SpinTimeout::Throw,
+            false,
+        );
+        let global = exported_global(&module, MAX_SPIN_NS_GLOBAL).unwrap();
+        assert!(matches!(
+            module.globals.get(global).kind,
+            walrus::GlobalKind::Local(ConstExpr::Value(Value::I64(500)))
+        ));
+    }
+
+    #[test]
+    fn test_spin_timeout() {
+        let wat = include_str!("../test-data/wait32.wat");
+
+        let module = run_wat_with(wat, DEFAULT_MAX_SPIN_NS, SpinTimeout::Trap, false);
+        assert!(!has_import(&module, SPIN_TIMEOUT_IMPORT));
+
+        for on_timeout in [SpinTimeout::Throw, SpinTimeout::Log] {
+            let module = run_wat_with(wat, DEFAULT_MAX_SPIN_NS, on_timeout, false);
+            assert!(has_import(&module, SPIN_TIMEOUT_IMPORT));
+        }
+    }
+
+    #[test]
+    fn test_telemetry() {
+        let wat = include_str!("../test-data/wait32.wat");
+
+        let module = run_wat(wat);
+        assert!(exported_global(&module, SPIN_NS_TOTAL_GLOBAL).is_none());
+        assert!(exported_global(&module, SPIN_COUNT_GLOBAL).is_none());
+
+        let module = run_wat_with(wat, DEFAULT_MAX_SPIN_NS, SpinTimeout::Throw, true);
+        assert!(exported_global(&module, SPIN_NS_TOTAL_GLOBAL).is_some());
+        assert!(exported_global(&module, SPIN_COUNT_GLOBAL).is_some());
+    }
+
+    #[test]
+    fn test_func_name() {
+        let kind = WaitKind {
+            sixty_four: true,
+            offset: 0,
+        };
+        assert_eq!(kind.func_name("__atomic_spin"), "__atomic_spin64");
+
+        let kind = WaitKind {
+            sixty_four: false,
+            offset: 24,
+        };
+        assert_eq!(kind.func_name("__atomic_wait"), "__atomic_wait32_offset_24");
+    }
 }
@@ -70,6 +70,19 @@ impl InstructionBuilder<'_, '_> {
                     &[AdapterType::I32]
                 )
             }
+            // Tuples are converted from a JS array on the Rust side.
+            Descriptor::Tuple(elements) => {
+                self.instruction(
+                    &[AdapterType::tuple(elements)],
+                    Instruction::I32FromExternrefOwned,
+                    &[AdapterType::I32]
+                )
+            }
+            Descriptor::Map {
+                key,
+                value,
+                as_object,
+            } => self.incoming_map(key, value, *as_object, false)?,
             Descriptor::RustStruct(class) => {
                 self.instruction(
                     &[AdapterType::Struct(class.clone())],
@@ -160,6 +173,7 @@ impl InstructionBuilder<'_, '_> {
             // Can't be passed from JS to Rust yet
             Descriptor::Function(_) |
             Descriptor::Closure(_) |
+            Descriptor::Iterator { .. } |
 
             Descriptor::Result(_) |
             // Always behind a `Ref`
@@ -281,6 +295,20 @@ impl InstructionBuilder<'_, '_> {
                     &[AdapterType::I32],
                 );
             }
+            Descriptor::Tuple(elements) => {
+                self.instruction(
+                    &[AdapterType::tuple(elements).option()],
+                    Instruction::I32FromOptionExternref {
+                        table_and_alloc: None,
+                    },
+                    &[AdapterType::I32],
+                );
+            }
+            Descriptor::Map {
+                key,
+                value,
+                as_object,
+            } => self.incoming_map(key, value, *as_object, true)?,
             Descriptor::I8 => self.in_option_sentinel32(AdapterType::S8),
             Descriptor::U8 => self.in_option_sentinel32(AdapterType::U8),
             Descriptor::I16 => self.in_option_sentinel32(AdapterType::S16),
@@ -393,6 +421,42 @@ impl InstructionBuilder<'_, '_> {
         Ok(())
     }
 
+    /// Maps are converted from a JS `Map` on the Rust side, so plain objects
+    /// are turned into a `Map` before being passed along.
+    fn incoming_map(
+        &mut self,
+        key: &Descriptor,
+        value: &Descriptor,
+        as_object: bool,
+        optional: bool,
+    ) -> Result<(), Error> {
+        let (ty, instr) = if optional {
+            (
+                AdapterType::map(key, value, as_object).option(),
+                Instruction::I32FromOptionExternref {
+                    table_and_alloc: None,
+                },
+            )
+        } else {
+            (
+                AdapterType::map(key, value, as_object),
+                Instruction::I32FromExternrefOwned,
+            )
+        };
+        if as_object {
+            let key = AdapterType::object_key(key)?;
+            self.instruction(
+                &[ty],
+                Instruction::MapFromObject { key, optional },
+                &[AdapterType::Externref],
+            );
+            self.late_instruction(&[AdapterType::Externref], instr, &[AdapterType::I32]);
+        } else {
+            self.instruction(&[ty], instr, &[AdapterType::I32]);
+        }
+        Ok(())
+    }
+
     pub fn get(&mut self, ty: AdapterType) {
         self.input.push(ty);
 
@@ -515,6 +515,8 @@ impl<'a> Context<'a> {
             enums,
             imports,
             structs,
+            traits,
+            errors,
             typescript_custom_sections,
             local_modules,
             inline_js,
@@ -577,6 +579,11 @@ impl<'a> Context<'a> {
                     .extend(ty.vendor_prefixes.iter().map(|s| s.to_string()));
             }
         }
+        // Trait methods are typed from the descriptors of their imports, so
+        // they need to be handled before the imports consume them.
+        for trait_ in traits {
+            self.trait_(trait_, &imports)?;
+        }
         for import in imports {
             self.import(import)?;
         }
@@ -587,6 +594,9 @@ impl<'a> Context<'a> {
         for struct_ in structs {
             self.struct_(struct_)?;
         }
+        for error in errors {
+            self.error(error)?;
+        }
 
         // Collect custom sections to be sorted later when all CGUs encountered
         self.aux
@@ -616,6 +626,9 @@ impl<'a> Context<'a> {
             None => return Ok(()),
             Some(d) => d.unwrap_function(),
         };
+        if export.function.map_as_object {
+            descriptor.map_as_object();
+        }
 
         let Some((export_id, id)) = self.function_exports.get(&wasm_name).copied() else {
             bail!("{wasm_name} symbol is missing, \
@@ -689,6 +702,13 @@ impl<'a> Context<'a> {
                 })
                 .collect::<Vec<_>>(),
         );
+        let throws = match self
+            .descriptors
+            .remove(&wasm_bindgen_shared::throws_function(&wasm_name))
+        {
+            Some(Descriptor::NamedExternref(name)) => Some(name),
+            _ => None,
+        };
         let id = self.export_adapter(export_id, descriptor)?;
         self.aux.export_map.insert(
             id,
@@ -697,6 +717,7 @@ impl<'a> Context<'a> {
                 comments: concatenate_comments(&export.comments),
                 args,
                 asyncness: export.function.asyncness,
+                promising: export.promising,
                 kind,
                 js_namespace: export
                     .js_namespace
@@ -706,6 +727,8 @@ impl<'a> Context<'a> {
                 variadic: export.function.variadic,
                 fn_ret_ty_override: export.function.ret_ty_override.map(String::from),
                 fn_ret_desc: export.function.ret_desc.map(String::from),
+                throws,
+                location: export.function.location.map(source_location),
             },
         );
         Ok(())
@@ -758,6 +781,7 @@ impl<'a> Context<'a> {
             shim,
             catch,
             variadic,
+            suspending,
             method,
             structural,
             function,
@@ -775,12 +799,15 @@ impl<'a> Context<'a> {
                 return Ok(());
             }
         };
-        let descriptor = match self.descriptors.remove(shim) {
+        let mut descriptor = match self.descriptors.remove(shim) {
             None => {
                 return Ok(());
             }
             Some(d) => d.unwrap_function(),
         };
+        if function.map_as_object {
+            descriptor.map_as_object();
+        }
 
         // Perform two functions here. First we're saving off our adapter
         // signature, indicating what we think our import is going to be. Next
@@ -852,7 +879,7 @@ impl<'a> Context<'a> {
             self.aux.imports_with_variadic.insert(id);
         }
 
-        // Note that `catch`/`assert_no_shim` is applied not to the import
+        // Note that `catch`/`suspending`/`assert_no_shim` is applied not to the import
         // itself but to the adapter shim we generated, so fetch that shim id
         // and flag it as catch here. This basically just needs to be kept in
         // sync with `js/mod.rs`.
@@ -866,10 +893,18 @@ impl<'a> Context<'a> {
                 self.find_exn_store();
             }
         }
+        if suspending {
+            self.aux.imports_with_suspending.insert(adapter);
+        }
         if assert_no_shim {
             self.aux.imports_with_assert_no_shim.insert(adapter);
         }
 
+        if let Some(location) = function.location {
+            self.aux
+                .import_locations
+                .insert(adapter, source_location(location));
+        }
         self.aux.import_map.insert(id, aux_import);
 
         Ok(())
@@ -1166,6 +1201,9 @@ impl<'a> Context<'a> {
     }
 
     fn struct_(&mut self, struct_: decode::Struct<'_>) -> Result<(), Error> {
+        if struct_.plain_object {
+            return self.plain_object(struct_);
+        }
         let qualified_name =
             wasm_bindgen_shared::qualified_name(struct_.js_namespace.as_deref(), struct_.name);
         let rust_name = struct_.rust_name;
@@ -1192,6 +1230,7 @@ impl<'a> Context<'a> {
                     debug_name: format!("getter for `{}::{}`", struct_.name, field.name),
                     args: None,
                     asyncness: false,
+                    promising: false,
                     comments: concatenate_comments(&field.comments),
                     kind: AuxExportKind::Method {
                         class: rust_name.to_string(),
@@ -1205,6 +1244,8 @@ impl<'a> Context<'a> {
                     variadic: false,
                     fn_ret_ty_override: None,
                     fn_ret_desc: None,
+                    throws: None,
+                    location: None,
                 },
             );
 
@@ -1227,6 +1268,7 @@ impl<'a> Context<'a> {
                     debug_name: format!("setter for `{}::{}`", struct_.name, field.name),
                     args: None,
                     asyncness: false,
+                    promising: false,
                     comments: concatenate_comments(&field.comments),
                     kind: AuxExportKind::Method {
                         class: rust_name.to_string(),
@@ -1240,6 +1282,8 @@ impl<'a> Context<'a> {
                     variadic: false,
                     fn_ret_ty_override: None,
                     fn_ret_desc: None,
+                    throws: None,
+                    location: None,
                 },
             );
         }
@@ -1255,6 +1299,7 @@ impl<'a> Context<'a> {
                 .as_ref()
                 .map(|ns| ns.iter().map(|s| s.to_string()).collect()),
             private: struct_.private,
+            location: struct_.location.map(source_location),
         };
         self.aux.structs.push(aux);
 
@@ -1277,6 +1322,102 @@ impl<'a> Context<'a> {
         Ok(())
     }
 
+    /// `plain_object` structs are converted to and from plain JS objects on
+    /// the Rust side, so there's no class to generate, only a TypeScript
+    /// interface describing their fields.
+    fn plain_object(&mut self, struct_: decode::Struct<'_>) -> Result<(), Error> {
+        let mut fields = Vec::new();
+        for field in struct_.fields {
+            let getter = wasm_bindgen_shared::struct_field_get(struct_.name, field.name);
+            let descriptor = match self.descriptors.remove(&getter) {
+                None => continue,
+                Some(d) => d,
+            };
+            fields.push(AuxPlainObjectField {
+                name: field.name.to_string(),
+                ty: AdapterType::js_value(&descriptor),
+                comments: concatenate_comments(&field.comments),
+                generate_typescript: field.generate_typescript,
+            });
+        }
+        self.aux.plain_objects.push(AuxPlainObject {
+            name: struct_.name.to_string(),
+            comments: concatenate_comments(&struct_.comments),
+            fields,
+            generate_typescript: struct_.generate_typescript,
+            private: struct_.private,
+        });
+        Ok(())
+    }
+
+    fn error(&mut self, error: decode::ErrorEnum<'_>) -> Result<(), Error> {
+        self.add_aux_import_to_import_map(
+            &wasm_bindgen_shared::error_new_function(error.name),
+            vec![Descriptor::Externref; 3],
+            Descriptor::Externref,
+            AuxImport::NewError(error.name.to_string()),
+        )?;
+        self.aux.errors.push(AuxError {
+            name: error.name.to_string(),
+            variants: error.variants,
+            comments: concatenate_comments(&error.comments),
+            generate_typescript: error.generate_typescript,
+        });
+        Ok(())
+    }
+
+    fn trait_(
+        &mut self,
+        trait_: decode::Trait<'_>,
+        imports: &[decode::Import<'_>],
+    ) -> Result<(), Error> {
+        let mut methods = Vec::new();
+        for method in trait_.methods {
+            let function = imports.iter().find_map(|import| match &import.kind {
+                decode::ImportKind::Function(f) if f.shim == method.shim => Some(&f.function),
+                _ => None,
+            });
+            let (Some(function), Some(descriptor)) = (function, self.descriptors.get(method.shim))
+            else {
+                continue;
+            };
+            let mut descriptor = descriptor.clone().unwrap_function();
+            if function.map_as_object {
+                descriptor.map_as_object();
+            }
+            // The first argument is the JS object the method is called on.
+            let args = function
+                .args
+                .iter()
+                .zip(&descriptor.arguments)
+                .skip(1)
+                .map(|(arg, ty)| AuxTraitArg {
+                    name: arg.name.clone(),
+                    ty: AdapterType::js_value(ty),
+                    ty_override: arg.ty_override.map(String::from),
+                })
+                .collect();
+            let ret = match &descriptor.ret {
+                Descriptor::Unit => None,
+                ret => Some(AdapterType::js_value(ret)),
+            };
+            methods.push(AuxTraitMethod {
+                name: function.name.to_string(),
+                args,
+                ret,
+                ret_ty_override: function.ret_ty_override.map(String::from),
+                comments: concatenate_comments(&method.comments),
+            });
+        }
+        self.aux.traits.push(AuxTrait {
+            name: trait_.name.to_string(),
+            comments: concatenate_comments(&trait_.comments),
+            methods,
+            generate_typescript: trait_.generate_typescript,
+        });
+        Ok(())
+    }
+
     fn add_aux_import_to_import_map(
         &mut self,
         fn_name: &str,
@@ -1913,6 +2054,14 @@ fn concatenate_comments(comments: &[&str]) -> String {
     comments.join("\n")
 }
 
+fn source_location(location: decode::SourceLocation) -> AuxSourceLocation {
+    AuxSourceLocation {
+        file: location.file,
+        line: location.line,
+        column: location.column,
+    }
+}
+
 /// The C struct packing algorithm, in terms of u32.
 struct StructUnpacker {
     next_offset: usize,
@@ -1,5 +1,5 @@
 use crate::intrinsic::Intrinsic;
-use crate::wit::AdapterId;
+use crate::wit::{AdapterId, AdapterType};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::PathBuf;
@@ -41,6 +41,11 @@ pub struct WasmBindgenAux {
     pub imports_with_catch: HashSet<AdapterId>,
     pub imports_with_variadic: HashSet<AdapterId>,
     pub imports_with_assert_no_shim: HashSet<AdapterId>,
+    pub imports_with_suspending: HashSet<AdapterId>,
+
+    /// Locations in Rust code of the imports, keyed by their shim like the
+    /// metadata above, used to generate source maps.
+    pub import_locations: HashMap<AdapterId, AuxSourceLocation>,
 
     /// Auxiliary information to go into JS/TypeScript bindings describing the
     /// exported enums from Rust.
@@ -53,6 +58,18 @@ pub struct WasmBindgenAux {
     /// exported structs from Rust and their fields they've got exported.
     pub structs: Vec<AuxStruct>,
 
+    /// Auxiliary information to go into TypeScript bindings describing the
+    /// exported `plain_object` structs from Rust.
+    pub plain_objects: Vec<AuxPlainObject>,
+
+    /// Auxiliary information to go into TypeScript bindings describing the
+    /// Rust traits which can be implemented by JS objects.
+    pub traits: Vec<AuxTrait>,
+
+    /// Auxiliary information about the `#[wasm_bindgen(error)]` enums of the
+    /// module, each of which is generated as a JS `Error` subclass.
+    pub errors: Vec<AuxError>,
+
     /// Information about various internal functions used to manage the `externref`
     /// table, later used to process JS bindings.
     pub externref_table: Option<walrus::TableId>,
@@ -92,6 +109,9 @@ pub struct AuxExport {
     pub args: Option<Vec<AuxFunctionArgumentData>>,
     /// Whether this is an async function, to configure the TypeScript return value.
     pub asyncness: bool,
+    /// Whether this is wrapped with `WebAssembly.promising` and returns a
+    /// `Promise` to JS.
+    pub promising: bool,
     /// What kind of function this is and where it shows up
     pub kind: AuxExportKind,
     /// The namespace to export the item through, if any
@@ -106,6 +126,21 @@ pub struct AuxExport {
     pub fn_ret_ty_override: Option<String>,
     /// Function's return description
     pub fn_ret_desc: Option<String>,
+    /// The JS name of the error class this function throws, if any
+    pub throws: Option<String>,
+    /// Location of this function in Rust code, if known
+    pub location: Option<AuxSourceLocation>,
+}
+
+/// The location of an item in Rust code, used to generate source maps.
+#[derive(Debug, Clone)]
+pub struct AuxSourceLocation {
+    /// The path to the Rust file, as passed to `rustc`
+    pub file: String,
+    /// The 1-based line of the item
+    pub line: u32,
+    /// The 0-based column of the item
+    pub column: u32,
 }
 
 /// Information about a functions' argument
@@ -250,6 +285,82 @@ pub struct AuxStruct {
     pub private: bool,
     /// The namespace to export the struct through, if any
     pub js_namespace: Option<Vec<String>>,
+    /// Location of this struct in Rust code, if known
+    pub location: Option<AuxSourceLocation>,
+}
+
+#[derive(Debug)]
+pub struct AuxPlainObject {
+    /// The JS name of this struct
+    pub name: String,
+    /// The copied Rust comments to forward to JS
+    pub comments: String,
+    /// The fields of the plain object
+    pub fields: Vec<AuxPlainObjectField>,
+    /// Whether typescript bindings should be generated for this struct.
+    pub generate_typescript: bool,
+    /// Whether to not export the interface from the TypeScript module
+    pub private: bool,
+}
+
+#[derive(Debug)]
+pub struct AuxPlainObjectField {
+    /// The JS name of this field
+    pub name: String,
+    /// The type of the field's value on the JS object
+    pub ty: AdapterType,
+    /// The copied Rust comments to forward to JS
+    pub comments: String,
+    /// Whether typescript bindings should be generated for this field.
+    pub generate_typescript: bool,
+}
+
+#[derive(Debug)]
+pub struct AuxError {
+    /// The JS name of the error class
+    pub name: String,
+    /// The names of the enum's variants, used as the error's `kind`
+    pub variants: Vec<String>,
+    /// The copied Rust comments to forward to JS
+    pub comments: String,
+    /// Whether typescript bindings should be generated for this error class.
+    pub generate_typescript: bool,
+}
+
+#[derive(Debug)]
+pub struct AuxTrait {
+    /// The JS name of the interface
+    pub name: String,
+    /// The copied Rust comments to forward to JS
+    pub comments: String,
+    /// The methods JS objects need to implement
+    pub methods: Vec<AuxTraitMethod>,
+    /// Whether typescript bindings should be generated for this trait.
+    pub generate_typescript: bool,
+}
+
+#[derive(Debug)]
+pub struct AuxTraitMethod {
+    /// The JS name of the method
+    pub name: String,
+    /// The names and types of the arguments JS receives
+    pub args: Vec<AuxTraitArg>,
+    /// The type JS returns, or `None` if nothing is returned
+    pub ret: Option<AdapterType>,
+    /// The TypeScript type of the return value if overridden
+    pub ret_ty_override: Option<String>,
+    /// The copied Rust comments to forward to JS
+    pub comments: String,
+}
+
+#[derive(Debug)]
+pub struct AuxTraitArg {
+    /// The name of the argument
+    pub name: String,
+    /// The type of the argument
+    pub ty: AdapterType,
+    /// The TypeScript type of the argument if overridden
+    pub ty_override: Option<String>,
 }
 
 /// All possible types of imports that can be imported by a Wasm module.
@@ -407,6 +518,11 @@ pub enum AuxImport {
     /// instance of the given exported class. The class name is one that is
     /// exported from the Rust/wasm.
     UnwrapExportedClass(String),
+
+    /// This import is a generated shim which creates an instance of the error
+    /// class generated for a `#[wasm_bindgen(error)]` enum, from its kind,
+    /// message and cause.
+    NewError(String),
 }
 
 /// Values that can be imported verbatim to hook up to an import.
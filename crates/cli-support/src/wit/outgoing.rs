@@ -59,6 +59,31 @@ impl InstructionBuilder<'_, '_> {
                     &[AdapterType::NamedExternref(name.clone())],
                 );
             }
+            // Tuples are converted into a JS array on the Rust side.
+            Descriptor::Tuple(elements) => {
+                self.instruction(
+                    &[AdapterType::I32],
+                    Instruction::ExternrefLoadOwned {
+                        table_and_drop: None,
+                    },
+                    &[AdapterType::tuple(elements)],
+                );
+            }
+            Descriptor::Map {
+                key,
+                value,
+                as_object,
+            } => self.outgoing_map(key, value, *as_object, false)?,
+            // Iterators are wrapped in a JS iterator object on the Rust side.
+            Descriptor::Iterator { item, is_async } => {
+                self.instruction(
+                    &[AdapterType::I32],
+                    Instruction::ExternrefLoadOwned {
+                        table_and_drop: None,
+                    },
+                    &[AdapterType::iterator(item, *is_async)],
+                );
+            }
             Descriptor::I8 => self.outgoing_i32(AdapterType::S8),
             Descriptor::U8 => self.outgoing_i32(AdapterType::U8),
             Descriptor::I16 => self.outgoing_i32(AdapterType::S16),
@@ -323,6 +348,29 @@ impl InstructionBuilder<'_, '_> {
                     &[AdapterType::NamedExternref(name.clone()).option()],
                 );
             }
+            Descriptor::Tuple(elements) => {
+                self.instruction(
+                    &[AdapterType::I32],
+                    Instruction::ExternrefLoadOwned {
+                        table_and_drop: None,
+                    },
+                    &[AdapterType::tuple(elements).option()],
+                );
+            }
+            Descriptor::Map {
+                key,
+                value,
+                as_object,
+            } => self.outgoing_map(key, value, *as_object, true)?,
+            Descriptor::Iterator { item, is_async } => {
+                self.instruction(
+                    &[AdapterType::I32],
+                    Instruction::ExternrefLoadOwned {
+                        table_and_drop: None,
+                    },
+                    &[AdapterType::iterator(item, *is_async).option()],
+                );
+            }
             Descriptor::I8 => self.out_option_sentinel32(AdapterType::S8),
             Descriptor::U8 => self.out_option_sentinel32(AdapterType::U8),
             Descriptor::I16 => self.out_option_sentinel32(AdapterType::S16),
@@ -448,7 +496,10 @@ impl InstructionBuilder<'_, '_> {
             | Descriptor::Option(_)
             | Descriptor::Vector(_)
             | Descriptor::Unit
-            | Descriptor::NonNull => {
+            | Descriptor::NonNull
+            | Descriptor::Tuple(_)
+            | Descriptor::Map { .. }
+            | Descriptor::Iterator { .. } => {
                 // We must throw before reading the Ok type, if there is an error. However, the
                 // structure of ResultAbi is that the Err value + discriminant come last (for
                 // alignment reasons). So the UnwrapResult instruction must come first, but the
@@ -598,6 +649,36 @@ impl InstructionBuilder<'_, '_> {
         Ok(())
     }
 
+    /// Maps are converted into a JS `Map` on the Rust side, which is turned
+    /// into a plain object afterwards if requested.
+    fn outgoing_map(
+        &mut self,
+        key: &Descriptor,
+        value: &Descriptor,
+        as_object: bool,
+        optional: bool,
+    ) -> Result<(), Error> {
+        let mut ty = AdapterType::map(key, value, as_object);
+        if optional {
+            ty = ty.option();
+        }
+        let load = Instruction::ExternrefLoadOwned {
+            table_and_drop: None,
+        };
+        if as_object {
+            AdapterType::object_key(key)?;
+            self.instruction(&[AdapterType::I32], load, &[AdapterType::Externref]);
+            self.late_instruction(
+                &[AdapterType::Externref],
+                Instruction::ObjectFromMap { optional },
+                &[ty],
+            );
+        } else {
+            self.instruction(&[AdapterType::I32], load, &[ty]);
+        }
+        Ok(())
+    }
+
     fn outgoing_string_enum(&mut self, name: &str) {
         self.instruction(
             &[AdapterType::I32],
@@ -1,4 +1,5 @@
-use crate::descriptor::VectorKind;
+use crate::descriptor::{Descriptor, VectorKind};
+use anyhow::{bail, Error};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashSet};
 use walrus::{ExportId, FunctionId, ImportId, RefType, TypedCustomSectionId};
@@ -94,6 +95,21 @@ pub enum AdapterType {
     NamedExternref(String),
     Function,
     NonNull,
+    /// A JS array holding the elements of a Rust tuple.
+    Tuple(Vec<AdapterType>),
+    /// A JS `Map`, or a plain object if `as_object`, holding the entries of a
+    /// Rust map.
+    Map {
+        key: Box<AdapterType>,
+        value: Box<AdapterType>,
+        as_object: bool,
+    },
+    /// A JS iterable iterator, or an async one if `is_async`, yielding the
+    /// items of a Rust iterator or stream.
+    Iterator {
+        item: Box<AdapterType>,
+        is_async: bool,
+    },
 }
 
 /// Describes how a closure's lifetime is managed.
@@ -225,6 +241,13 @@ pub enum Instruction {
         /// module, otherwise it's shoved into the JS shim.
         table_and_alloc: Option<(walrus::TableId, walrus::FunctionId)>,
     },
+    /// Pops a plain JS object, pushes a `Map` of its entries with the keys
+    /// converted back from strings to the `key` type
+    MapFromObject {
+        key: AdapterType,
+        /// Whether `undefined` and `null` are passed through unchanged
+        optional: bool,
+    },
     /// Pops an `externref` from the stack, pushes either a sentinel value if it's
     /// "none" or the integer value of it if it's "some"
     I32FromOptionU32Sentinel,
@@ -309,6 +332,11 @@ pub enum Instruction {
         /// but has to pass them through a retptr.
         table_and_drop: Option<(walrus::TableId, walrus::FunctionId)>,
     },
+    /// pops a `Map`, pushes a plain JS object holding its entries
+    ObjectFromMap {
+        /// Whether `undefined` is passed through unchanged
+        optional: bool,
+    },
     /// pops `i32`, pushes string from that `char`
     StringFromChar,
     /// pops `i32`, pushes an externref for the wrapped rust class
@@ -395,9 +423,11 @@ impl AdapterType {
             AdapterType::F32 => walrus::ValType::F32,
             AdapterType::F64 => walrus::ValType::F64,
             AdapterType::Enum(_) => walrus::ValType::I32,
-            AdapterType::Externref | AdapterType::NamedExternref(_) => {
-                walrus::ValType::Ref(RefType::EXTERNREF)
-            }
+            AdapterType::Externref
+            | AdapterType::NamedExternref(_)
+            | AdapterType::Tuple(_)
+            | AdapterType::Map { .. }
+            | AdapterType::Iterator { .. } => walrus::ValType::Ref(RefType::EXTERNREF),
             _ => return None,
         })
     }
@@ -405,6 +435,89 @@ impl AdapterType {
     pub fn option(self) -> AdapterType {
         AdapterType::Option(Box::new(self))
     }
+
+    /// The type of a Rust tuple, whose elements are converted with
+    /// `JsValue::from` into a JS array.
+    pub fn tuple(elements: &[Descriptor]) -> AdapterType {
+        AdapterType::Tuple(elements.iter().map(AdapterType::js_value).collect())
+    }
+
+    /// The type of a Rust map, whose keys and values are converted with
+    /// `JsValue::from` into a JS `Map`.
+    pub fn map(key: &Descriptor, value: &Descriptor, as_object: bool) -> AdapterType {
+        AdapterType::Map {
+            key: Box::new(AdapterType::js_value(key)),
+            value: Box::new(AdapterType::js_value(value)),
+            as_object,
+        }
+    }
+
+    /// The type of a Rust iterator or stream, whose items are converted with
+    /// `JsValue::from` as they're requested by JS.
+    pub fn iterator(item: &Descriptor, is_async: bool) -> AdapterType {
+        AdapterType::Iterator {
+            item: Box::new(AdapterType::js_value(item)),
+            is_async,
+        }
+    }
+
+    /// The type of the keys of a Rust map represented as a plain JS object,
+    /// which only supports string and numeric keys.
+    pub fn object_key(key: &Descriptor) -> Result<AdapterType, Error> {
+        match key {
+            Descriptor::I8
+            | Descriptor::U8
+            | Descriptor::I16
+            | Descriptor::U16
+            | Descriptor::I32
+            | Descriptor::U32
+            | Descriptor::I64
+            | Descriptor::U64
+            | Descriptor::I128
+            | Descriptor::U128
+            | Descriptor::F32
+            | Descriptor::F64
+            | Descriptor::Char
+            | Descriptor::String
+            | Descriptor::CachedString => Ok(AdapterType::js_value(key)),
+            _ => bail!("`map_as = \"object\"` requires string or numeric map keys, found {key:?}"),
+        }
+    }
+
+    /// The type of a Rust value after being converted with `JsValue::from`.
+    pub fn js_value(descriptor: &Descriptor) -> AdapterType {
+        match descriptor {
+            Descriptor::I8 => AdapterType::S8,
+            Descriptor::U8 | Descriptor::ClampedU8 => AdapterType::U8,
+            Descriptor::I16 => AdapterType::S16,
+            Descriptor::U16 => AdapterType::U16,
+            Descriptor::I32 => AdapterType::S32,
+            Descriptor::U32 => AdapterType::U32,
+            Descriptor::I64 => AdapterType::S64,
+            Descriptor::U64 => AdapterType::U64,
+            Descriptor::I128 => AdapterType::S128,
+            Descriptor::U128 => AdapterType::U128,
+            Descriptor::F32 => AdapterType::F32,
+            Descriptor::F64 => AdapterType::F64,
+            Descriptor::Boolean => AdapterType::Bool,
+            Descriptor::Char | Descriptor::String | Descriptor::CachedString => AdapterType::String,
+            Descriptor::NamedExternref(name) => AdapterType::NamedExternref(name.clone()),
+            Descriptor::Enum { name, .. } => AdapterType::Enum(name.clone()),
+            Descriptor::StringEnum { name, .. } => AdapterType::StringEnum(name.clone()),
+            Descriptor::RustStruct(name) => AdapterType::Struct(name.clone()),
+            Descriptor::NonNull => AdapterType::NonNull,
+            Descriptor::Option(d) => AdapterType::js_value(d).option(),
+            Descriptor::Ref(d) | Descriptor::RefMut(d) => AdapterType::js_value(d),
+            Descriptor::Tuple(elements) => AdapterType::tuple(elements),
+            Descriptor::Map { key, value, .. } => AdapterType::map(key, value, false),
+            Descriptor::Iterator { item, is_async } => AdapterType::iterator(item, *is_async),
+            Descriptor::Vector(_) | Descriptor::Slice(_) => match descriptor.vector_kind() {
+                Some(kind) => AdapterType::Vector(kind),
+                None => AdapterType::Externref,
+            },
+            _ => AdapterType::Externref,
+        }
+    }
 }
 
 impl NonstandardWitSection {
@@ -0,0 +1,11 @@
+use std::{env, process};
+
+fn main() {
+    env_logger::init();
+    let err = match wasm_bindgen_cli::wasm_bindgen_api_check::run_cli_with_args(env::args_os()) {
+        Ok(()) => return,
+        Err(e) => e,
+    };
+    eprintln!("error: {err:?}");
+    process::exit(1);
+}
@@ -1,3 +1,4 @@
 pub mod wasm2es6js;
 pub mod wasm_bindgen;
+pub mod wasm_bindgen_api_check;
 pub mod wasm_bindgen_test_runner;
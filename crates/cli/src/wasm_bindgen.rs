@@ -1,10 +1,12 @@
 use anyhow::{bail, Error};
 use clap::{Parser, ValueEnum};
+use serde::Deserialize;
 use std::ffi::OsString;
 use std::path::PathBuf;
-use wasm_bindgen_cli_support::{Bindgen, EncodeInto};
+use std::process::Command;
+use wasm_bindgen_cli_support::{Bindgen, EncodeInto, Package, PackageMetadata, SpinTimeout};
 
-#[derive(Debug, Clone, ValueEnum)]
+#[derive(Debug, Clone, PartialEq, ValueEnum)]
 #[clap(rename_all = "kebab-case")]
 enum Target {
     Bundler,
@@ -29,11 +31,11 @@ struct Args {
         long,
         value_name = "TARGET",
         value_enum,
-        default_value_t = Target::Bundler,
-        help = "What type of output to generate",
+        default_values_t = [Target::Bundler],
+        help = "What type of output to generate, can be repeated with `--package`",
         group = "target-group"
     )]
-    target: Target,
+    target: Vec<Target>,
     #[arg(long, value_name = "DIR", help = "Output directory")]
     out_dir: PathBuf,
     #[arg(
@@ -99,12 +101,50 @@ struct Args {
                 This is always enabled for WASI targets."
     )]
     wait: bool,
+    #[arg(
+        long,
+        value_name = "MS",
+        help = "Maximum time a wait may spin on the main thread, 0 disables the limit.\n\
+                Defaults to ten seconds."
+    )]
+    max_spin_ms: Option<u64>,
+    #[arg(
+        long,
+        value_name = "MODE",
+        help = "What to do when a wait exceeds the maximum spin time",
+        value_parser = ["trap", "throw", "log"]
+    )]
+    spin_timeout: Option<String>,
+    #[arg(
+        long,
+        help = "Export globals recording the time spent spinning on the main thread"
+    )]
+    spin_telemetry: bool,
     #[arg(
         long,
         help = "Sets the path to the WWRR files for WASI targets.\n\
                 Can also be set using the WWRR_DIR environment variable."
     )]
     wwrr_dir: Option<PathBuf>,
+    #[arg(
+        long,
+        conflicts_with = "wwrr_dir",
+        help = "Provides the WASI imports of WASI targets through a built-in JS shim\n\
+                instead of the WWRR runtime. The shim doesn't support threads."
+    )]
+    wasi_shim: bool,
+    #[arg(
+        long,
+        help = "Writes a JSON manifest of the exports and imports of the bindings\n\
+                to `<out-name>.manifest.json`"
+    )]
+    manifest: bool,
+    #[arg(
+        long,
+        help = "Generates a package.json for publishing the output to npm.\n\
+                With multiple targets, each is generated into its own directory."
+    )]
+    package: bool,
     // The options below are deprecated. They're still parsed for backwards compatibility,
     // but we don't want to show them in `--help` to avoid distracting users.
     #[arg(long, hide = true)]
@@ -148,8 +188,102 @@ where
 }
 
 fn rmain(args: &Args) -> Result<(), Error> {
+    if !args.package {
+        let [target] = args.target.as_slice() else {
+            bail!("multiple targets can only be generated with `--package`");
+        };
+        return bindgen(args, target)?.generate(&args.out_dir);
+    }
+
+    let input_stem = args.input.file_stem().unwrap().to_string_lossy();
+    let mut package = Package::new(package_metadata(&input_stem));
+    for (i, target) in args.target.iter().enumerate() {
+        if args.target[..i].contains(target) {
+            bail!(
+                "target `{}` was specified multiple times",
+                target_name(target)
+            );
+        }
+        let dir = if args.target.len() > 1 {
+            target_name(target)
+        } else {
+            ""
+        };
+        let mut output = bindgen(args, target)?.generate_output()?;
+        output.emit(args.out_dir.join(dir))?;
+        package.add_output(dir, &output);
+    }
+    package.write(&args.out_dir)
+}
+
+fn target_name(target: &Target) -> &'static str {
+    match target {
+        Target::Bundler => "bundler",
+        Target::Web => "web",
+        Target::Nodejs => "nodejs",
+        Target::NoModules => "no-modules",
+        Target::Deno => "deno",
+        Target::ExperimentalNodejsModule => "experimental-nodejs-module",
+        Target::Module => "module",
+    }
+}
+
+/// Takes the metadata of the npm package from the Cargo package in the current
+/// directory which the Wasm file was built from, if any.
+fn package_metadata(stem: &str) -> PackageMetadata {
+    #[derive(Deserialize)]
+    struct Metadata {
+        packages: Vec<CargoPackage>,
+    }
+    #[derive(Deserialize)]
+    struct CargoPackage {
+        name: String,
+        version: String,
+        description: Option<String>,
+        license: Option<String>,
+        repository: Option<String>,
+        targets: Vec<CargoTarget>,
+    }
+    #[derive(Deserialize)]
+    struct CargoTarget {
+        name: String,
+    }
+
+    let package = Command::new("cargo")
+        .args(["metadata", "--format-version", "1", "--no-deps"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| serde_json::from_slice::<Metadata>(&output.stdout).ok())
+        .and_then(|metadata| {
+            metadata.packages.into_iter().find(|package| {
+                package
+                    .targets
+                    .iter()
+                    .any(|target| target.name.replace('-', "_") == stem)
+            })
+        });
+    match package {
+        Some(package) => PackageMetadata {
+            name: package.name,
+            version: Some(package.version),
+            description: package.description,
+            license: package.license,
+            repository: package.repository,
+        },
+        None => {
+            log::warn!("no Cargo package found for `{stem}`, package.json will lack its metadata");
+            PackageMetadata {
+                name: stem.to_string(),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+fn bindgen(args: &Args, target: &Target) -> Result<Bindgen, Error> {
     let mut b = Bindgen::new();
-    match &args.target {
+    match target {
         Target::Bundler => b.bundler(true)?,
         Target::Web => b.web(true)?,
         Target::NoModules => b.no_modules(true)?,
@@ -176,7 +310,10 @@ fn rmain(args: &Args) -> Result<(), Error> {
         .split_linked_modules(args.split_linked_modules)
         .reference_types(args.reference_types)
         .reset_state_function(args.generate_reset_state)
-        .wait(args.wait);
+        .wait(args.wait)
+        .spin_telemetry(args.spin_telemetry)
+        .wasi_shim(args.wasi_shim)
+        .manifest(args.manifest);
 
     if let Some(ref name) = args.no_modules_global {
         b.no_modules_global(name)?;
@@ -196,9 +333,24 @@ fn rmain(args: &Args) -> Result<(), Error> {
         b.encode_into(mode);
     }
 
+    if let Some(ms) = args.max_spin_ms {
+        b.max_spin_ns(ms.saturating_mul(1000 * 1000));
+    }
+
+    if let Some(mode) = &args.spin_timeout {
+        let mode = match mode.as_str() {
+            "trap" => SpinTimeout::Trap,
+            "throw" => SpinTimeout::Throw,
+            "log" => SpinTimeout::Log,
+            // clap guarantees
+            _ => unreachable!(),
+        };
+        b.spin_timeout(mode);
+    }
+
     if let Some(wwrr_dir) = &args.wwrr_dir {
         b.wwrr_dir(wwrr_dir);
     }
 
-    b.generate(&args.out_dir)
+    Ok(b)
 }
@@ -0,0 +1,424 @@
+use anyhow::{bail, Context, Error};
+use clap::Parser;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use wasm_bindgen_cli_support::Bindgen;
+
+/// The version of the manifest format written by `wasm-bindgen --manifest`
+/// that this tool understands.
+const MANIFEST_VERSION: u32 = 1;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "wasm-bindgen-api-check",
+    version,
+    about = "Compares the JS API of two builds of a wasm-bindgen library",
+    long_about = None,
+    after_help = "Both builds can either be Wasm files as passed to `wasm-bindgen` or manifests\n\
+                  written with `wasm-bindgen --manifest`. Exits with an error if any\n\
+                  breaking change is found."
+)]
+struct Args {
+    #[arg(help = "The previous build of the library")]
+    old: PathBuf,
+    #[arg(help = "The new build of the library")]
+    new: PathBuf,
+}
+
+pub fn run_cli_with_args<I, T>(args: I) -> anyhow::Result<()>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    let args = match Args::try_parse_from(args) {
+        Ok(a) => a,
+        Err(e) => match e.kind() {
+            // Passing --version and --help should not result in a failure.
+            clap::error::ErrorKind::DisplayHelp | clap::error::ErrorKind::DisplayVersion => {
+                print!("{e}");
+                return Ok(());
+            }
+            _ => bail!(e),
+        },
+    };
+    rmain(&args)
+}
+
+fn rmain(args: &Args) -> Result<(), Error> {
+    let old = load(&args.old)?;
+    let new = load(&args.new)?;
+
+    let changes = compare(&old, &new);
+    for change in changes.iter() {
+        let label = match change.kind {
+            ChangeKind::Breaking => "breaking",
+            ChangeKind::Compatible => "compatible",
+        };
+        println!("{label}: {}", change.description);
+    }
+
+    let breaking = changes
+        .iter()
+        .filter(|c| matches!(c.kind, ChangeKind::Breaking))
+        .count();
+    if breaking > 0 {
+        bail!("found {breaking} breaking change(s) to the JS API");
+    }
+    if changes.is_empty() {
+        println!("no changes to the JS API");
+    }
+    Ok(())
+}
+
+/// The parts of the manifest relevant to the JS API of a library.
+#[derive(Deserialize)]
+struct Manifest {
+    version: u32,
+    exports: Vec<Export>,
+}
+
+#[derive(Deserialize)]
+struct Export {
+    name: String,
+    kind: String,
+    #[serde(default)]
+    class: Option<String>,
+    #[serde(default)]
+    namespace: Option<Vec<String>>,
+    #[serde(default, rename = "static")]
+    is_static: bool,
+    #[serde(default)]
+    args: Vec<Argument>,
+    #[serde(default)]
+    returns: Option<String>,
+    #[serde(default)]
+    throws: Option<String>,
+    #[serde(default)]
+    variants: Vec<Variant>,
+    #[serde(default)]
+    fields: Vec<Argument>,
+    #[serde(default)]
+    methods: Vec<TraitMethod>,
+}
+
+#[derive(Deserialize)]
+struct Argument {
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+    optional: bool,
+}
+
+#[derive(Deserialize)]
+struct TraitMethod {
+    name: String,
+    args: Vec<Argument>,
+    returns: String,
+}
+
+#[derive(Deserialize)]
+struct Variant {
+    name: String,
+    #[serde(default)]
+    value: Option<i64>,
+}
+
+fn load(path: &Path) -> Result<Manifest, Error> {
+    let json = if path.extension().is_some_and(|ext| ext == "json") {
+        fs::read_to_string(path).with_context(|| format!("failed to read `{}`", path.display()))?
+    } else {
+        let output = Bindgen::new()
+            .input_path(path)
+            .manifest(true)
+            .generate_output()
+            .with_context(|| format!("failed to generate bindings for `{}`", path.display()))?;
+        output.manifest().unwrap().to_string()
+    };
+    let manifest: Manifest = serde_json::from_str(&json)
+        .with_context(|| format!("failed to parse the manifest of `{}`", path.display()))?;
+    if manifest.version != MANIFEST_VERSION {
+        bail!(
+            "unsupported manifest version {} in `{}`, expected {MANIFEST_VERSION}",
+            manifest.version,
+            path.display()
+        );
+    }
+    Ok(manifest)
+}
+
+enum ChangeKind {
+    /// Code using the old API may stop working with the new one.
+    Breaking,
+    /// Code using the old API keeps working with the new one, e.g. because
+    /// an item was added.
+    Compatible,
+}
+
+struct Change {
+    kind: ChangeKind,
+    description: String,
+}
+
+fn compare(old: &Manifest, new: &Manifest) -> Vec<Change> {
+    // Getters and setters share their name, so exports are identified by
+    // their kind as well.
+    fn by_key(manifest: &Manifest) -> BTreeMap<(Option<&str>, String, &str), &Export> {
+        manifest
+            .exports
+            .iter()
+            .map(|e| ((e.class.as_deref(), path(e), e.kind.as_str()), e))
+            .collect()
+    }
+    let old_exports = by_key(old);
+    let new_exports = by_key(new);
+
+    let mut changes = Vec::new();
+    let mut push = |kind, description| changes.push(Change { kind, description });
+    for (key, old) in old_exports.iter() {
+        match new_exports.get(key) {
+            Some(new) => compare_export(old, new, &mut push),
+            None => push(ChangeKind::Breaking, format!("removed {}", describe(old))),
+        }
+    }
+    for (key, new) in new_exports.iter() {
+        if !old_exports.contains_key(key) {
+            push(ChangeKind::Compatible, format!("added {}", describe(new)));
+        }
+    }
+    changes
+}
+
+fn compare_export(old: &Export, new: &Export, push: &mut impl FnMut(ChangeKind, String)) {
+    let item = describe(new);
+
+    if old.is_static != new.is_static {
+        let now = if new.is_static {
+            "static"
+        } else {
+            "not static"
+        };
+        push(ChangeKind::Breaking, format!("{item} is now {now}"));
+    }
+
+    for i in 0..old.args.len().max(new.args.len()) {
+        match (old.args.get(i), new.args.get(i)) {
+            (Some(old), Some(new)) => {
+                if old.ty != new.ty {
+                    push(
+                        ChangeKind::Breaking,
+                        format!(
+                            "argument `{}` of {item} changed type from `{}` to `{}`",
+                            new.name, old.ty, new.ty
+                        ),
+                    );
+                }
+                if old.optional && !new.optional {
+                    push(
+                        ChangeKind::Breaking,
+                        format!("argument `{}` of {item} is now required", new.name),
+                    );
+                } else if !old.optional && new.optional {
+                    push(
+                        ChangeKind::Compatible,
+                        format!("argument `{}` of {item} is now optional", new.name),
+                    );
+                }
+            }
+            (Some(old), None) => push(
+                ChangeKind::Breaking,
+                format!("removed argument `{}` of {item}", old.name),
+            ),
+            (None, Some(new)) if new.optional => push(
+                ChangeKind::Compatible,
+                format!("added optional argument `{}` to {item}", new.name),
+            ),
+            (None, Some(new)) => push(
+                ChangeKind::Breaking,
+                format!("added required argument `{}` to {item}", new.name),
+            ),
+            (None, None) => unreachable!(),
+        }
+    }
+
+    if old.returns != new.returns {
+        push(
+            ChangeKind::Breaking,
+            format!(
+                "return type of {item} changed from `{}` to `{}`",
+                old.returns.as_deref().unwrap_or("void"),
+                new.returns.as_deref().unwrap_or("void"),
+            ),
+        );
+    }
+
+    // The thrown error is only documented, so code handling the old error
+    // keeps working but may want to handle the new one.
+    if old.throws != new.throws {
+        let description = match (&old.throws, &new.throws) {
+            (Some(old), Some(new)) => format!("{item} now throws `{new}` instead of `{old}`"),
+            (None, Some(new)) => format!("{item} now throws `{new}`"),
+            (Some(old), None) => format!("{item} no longer throws `{old}`"),
+            (None, None) => unreachable!(),
+        };
+        push(ChangeKind::Compatible, description);
+    }
+
+    for old_variant in old.variants.iter() {
+        match new.variants.iter().find(|v| v.name == old_variant.name) {
+            Some(new_variant) if new_variant.value != old_variant.value => push(
+                ChangeKind::Breaking,
+                format!(
+                    "variant `{}` of {item} changed value from {} to {}",
+                    old_variant.name,
+                    display_value(old_variant.value),
+                    display_value(new_variant.value),
+                ),
+            ),
+            Some(_) => {}
+            None => push(
+                ChangeKind::Breaking,
+                format!("removed variant `{}` of {item}", old_variant.name),
+            ),
+        }
+    }
+    for new_variant in new.variants.iter() {
+        if !old.variants.iter().any(|v| v.name == new_variant.name) {
+            push(
+                ChangeKind::Compatible,
+                format!("added variant `{}` to {item}", new_variant.name),
+            );
+        }
+    }
+
+    // Plain objects are both passed to and returned from Rust, so JS has to
+    // provide every required field and may read any of them.
+    for old_field in old.fields.iter() {
+        let Some(new_field) = new.fields.iter().find(|f| f.name == old_field.name) else {
+            push(
+                ChangeKind::Breaking,
+                format!("removed field `{}` of {item}", old_field.name),
+            );
+            continue;
+        };
+        // The type of optional fields includes `undefined`, so it always
+        // changes along with whether the field is optional.
+        if old_field.optional != new_field.optional {
+            let now = if new_field.optional {
+                "optional"
+            } else {
+                "required"
+            };
+            push(
+                ChangeKind::Breaking,
+                format!("field `{}` of {item} is now {now}", new_field.name),
+            );
+        } else if old_field.ty != new_field.ty {
+            push(
+                ChangeKind::Breaking,
+                format!(
+                    "field `{}` of {item} changed type from `{}` to `{}`",
+                    new_field.name, old_field.ty, new_field.ty
+                ),
+            );
+        }
+    }
+    for new_field in new.fields.iter() {
+        if old.fields.iter().any(|f| f.name == new_field.name) {
+            continue;
+        }
+        if new_field.optional {
+            push(
+                ChangeKind::Compatible,
+                format!("added optional field `{}` to {item}", new_field.name),
+            );
+        } else {
+            push(
+                ChangeKind::Breaking,
+                format!("added required field `{}` to {item}", new_field.name),
+            );
+        }
+    }
+
+    // Traits are implemented by JS objects, so every method they have to
+    // provide, or that Rust calls differently, breaks them.
+    for old_method in old.methods.iter() {
+        let Some(new_method) = new.methods.iter().find(|m| m.name == old_method.name) else {
+            push(
+                ChangeKind::Breaking,
+                format!("removed method `{}` of {item}", old_method.name),
+            );
+            continue;
+        };
+        let old_signature = signature(old_method);
+        let new_signature = signature(new_method);
+        if old_signature != new_signature {
+            push(
+                ChangeKind::Breaking,
+                format!(
+                    "method `{}` of {item} changed from `{old_signature}` to `{new_signature}`",
+                    new_method.name
+                ),
+            );
+        }
+    }
+    for new_method in new.methods.iter() {
+        if !old.methods.iter().any(|m| m.name == new_method.name) {
+            push(
+                ChangeKind::Breaking,
+                format!("added method `{}` to {item}", new_method.name),
+            );
+        }
+    }
+}
+
+/// The TypeScript signature of a trait method, such as
+/// `(a: number, b?: string) => void`.
+fn signature(method: &TraitMethod) -> String {
+    let args = method
+        .args
+        .iter()
+        .map(|arg| {
+            let optional = if arg.optional { "?" } else { "" };
+            format!("{}{optional}: {}", arg.name, arg.ty)
+        })
+        .collect::<Vec<_>>();
+    format!("({}) => {}", args.join(", "), method.returns)
+}
+
+/// The path of an export as seen from JS, including its namespace.
+fn path(export: &Export) -> String {
+    let mut path = String::new();
+    for part in export.namespace.iter().flatten() {
+        path.push_str(part);
+        path.push('.');
+    }
+    path.push_str(&export.name);
+    path
+}
+
+fn describe(export: &Export) -> String {
+    let path = path(export);
+    let (class, member) = match &export.class {
+        Some(class) => (class.as_str(), format!("{class}.{}", export.name)),
+        None => ("", path.clone()),
+    };
+    match export.kind.as_str() {
+        "constructor" => format!("constructor of class `{class}`"),
+        "method" if export.is_static => format!("static method `{member}`"),
+        "getter" | "setter" if export.is_static => {
+            format!("static {} `{member}`", export.kind)
+        }
+        "class" => format!("class `{path}`"),
+        "error" => format!("error class `{path}`"),
+        "string_enum" => format!("string enum `{path}`"),
+        "plain_object" => format!("plain object `{path}`"),
+        kind => format!("{kind} `{member}`"),
+    }
+}
+
+fn display_value(value: Option<i64>) -> String {
+    value.map_or_else(|| "none".to_string(), |v| v.to_string())
+}
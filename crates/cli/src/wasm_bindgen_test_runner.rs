@@ -17,10 +17,13 @@ use clap::ValueEnum;
 use std::env;
 use std::ffi::OsString;
 use std::fs;
-use std::path::PathBuf;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::thread;
-use wasm_bindgen_cli_support::Bindgen;
+use wasm_bindgen_cli_support::{is_wasi_import, Bindgen};
 
+mod bun;
 mod deno;
 mod headless;
 mod node;
@@ -59,10 +62,27 @@ struct Cli {
     #[arg(
         long,
         value_enum,
-        value_name = "terse",
+        value_name = "pretty|terse|json|junit",
+        default_value_t = FormatSetting::Pretty,
+        hide_default_value = true,
         help = "Configure formatting of output"
     )]
-    format: Option<FormatSetting>,
+    format: FormatSetting,
+    #[arg(
+        long,
+        value_name = "N",
+        default_value = "1",
+        help = "Number of threads used for running tests in parallel"
+    )]
+    test_threads: NonZeroUsize,
+    #[arg(
+        long,
+        value_name = "i/n",
+        help = "Only run the i-th of n equally sized shards of the tests"
+    )]
+    shard: Option<Shard>,
+    #[arg(long, help = "Run each test in a fresh instance of the Wasm module")]
+    isolate: bool,
     #[arg(
         index = 2,
         value_name = "FILTER",
@@ -76,17 +96,132 @@ impl Cli {
     fn get_args(&self, tests: &Tests) -> String {
         let include_ignored = self.include_ignored;
         let filtered = tests.filtered;
+        let format = match self.format {
+            FormatSetting::Pretty | FormatSetting::Terse => "pretty",
+            FormatSetting::Json => "json",
+            FormatSetting::Junit => "junit",
+        };
 
         format!(
             r#"
             // Forward runtime arguments.
             cx.include_ignored({include_ignored:?});
             cx.filtered_count({filtered});
+            cx.format({format:?});
         "#
         )
     }
 }
 
+/// A JS function running `tests` split across `threads` test threads, and
+/// aggregating their results in `cx`.
+///
+/// `spawn(tests, report)` has to start a thread running `tests` with a
+/// `Context` forwarding its output to `report`, and return a promise resolving
+/// once the thread is done.
+const RUN_PARALLEL: &str = r#"
+async function run_parallel(cx, tests, threads, spawn) {
+    const chunks = Array.from({ length: Math.min(threads, tests.length) }, () => []);
+    tests.forEach((test, i) => chunks[i % chunks.length].push(test));
+
+    cx.start(tests.length);
+    await Promise.all(chunks.map(chunk =>
+        spawn(chunk, line => cx.report(line)).catch(e => cx.report_error(e))
+    ));
+    return cx.finish();
+}
+"#;
+
+/// A JS function running the tests named `tests` like `cx.run`, except that
+/// the tests in the set `isolated` each run in a fresh instance of the Wasm
+/// module, aggregating their results in `cx`.
+///
+/// `main` is the instance of `cx` and `instantiate()` has to create another
+/// one, both given as the JS bindings and the Wasm exports of the instance.
+/// Each instance gets a new `Context` set up with `configure(cx)`, and
+/// `set_console(bindings)` has to route captured console output to it.
+///
+/// A test aborting its instance, e.g. with `std::process::abort()`, makes the
+/// callbacks of the instance throw outside of any test, which would otherwise
+/// crash the runner or leave the tests of the instance pending forever. These
+/// errors fail the tests of the running instance instead.
+const RUN_TESTS: &str = r#"
+function on_uncaught_error(handler) {
+    if (typeof process !== 'undefined' && typeof process.on === 'function') {
+        process.on('uncaughtException', handler);
+        process.on('unhandledRejection', handler);
+    } else {
+        addEventListener('error', e => {
+            e.preventDefault();
+            handler(e.error);
+        });
+        addEventListener('unhandledrejection', e => {
+            e.preventDefault();
+            handler(e.reason);
+        });
+    }
+}
+
+async function run_tests(cx, tests, main, instantiate) {
+    if (!tests.some(test => isolated.has(test))) {
+        return await cx.run(tests.map(test => main[1][test]));
+    }
+
+    let abort;
+    on_uncaught_error(e => abort ? abort(e) : cx.report_error(e));
+
+    const run = async ([bindings, exports], tests) => {
+        const instance_cx = new bindings.WasmBindgenTestContext(false, line => cx.report(line));
+        configure(instance_cx);
+        set_console(bindings);
+        try {
+            await Promise.race([
+                instance_cx.run(tests.map(test => exports[test])),
+                new Promise((_, reject) => abort = reject),
+            ]);
+        } finally {
+            abort = undefined;
+            set_console(main[0]);
+        }
+    };
+
+    cx.start(tests.length);
+    const shared = tests.filter(test => !isolated.has(test));
+    if (shared.length > 0) {
+        await run(main, shared).catch(e => cx.report_error(e));
+    }
+    for (const test of tests.filter(test => isolated.has(test))) {
+        await instantiate()
+            .then(instance => run(instance, [test]))
+            .catch(e => cx.report_error(e));
+    }
+    return cx.finish();
+}
+"#;
+
+/// A shard of the tests to run, passed with `--shard i/n`.
+#[derive(Debug, Clone, Copy)]
+struct Shard {
+    /// The 1-based index of the shard.
+    index: usize,
+    count: usize,
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("expected `i/n` with 1 <= i <= n, found `{s}`");
+        let (index, count) = s.split_once('/').ok_or_else(err)?;
+        let index = index.trim().parse().map_err(|_| err())?;
+        let count = count.trim().parse().map_err(|_| err())?;
+        if index == 0 || index > count {
+            return Err(err());
+        }
+        Ok(Shard { index, count })
+    }
+}
+
 struct Tests {
     tests: Vec<Test>,
     filtered: usize,
@@ -99,6 +234,18 @@ impl Tests {
             filtered: 0,
         }
     }
+
+    /// Declares the JS set `isolated` of the tests to run in a fresh instance
+    /// of the Wasm module, see `RUN_TESTS`.
+    fn isolated(&self) -> String {
+        let isolated = self
+            .tests
+            .iter()
+            .filter(|test| test.isolated)
+            .map(|test| format!("'{}'", test.export))
+            .collect::<Vec<_>>();
+        format!("const isolated = new Set([{}]);", isolated.join(", "))
+    }
 }
 
 struct Test {
@@ -107,6 +254,7 @@ struct Test {
     // symbol name
     export: String,
     ignored: bool,
+    isolated: bool,
 }
 
 pub fn run_cli_with_args<I, T>(args: I) -> anyhow::Result<()>
@@ -128,7 +276,9 @@ where
     rmain(cli)
 }
 
-fn rmain(cli: Cli) -> anyhow::Result<()> {
+fn rmain(mut cli: Cli) -> anyhow::Result<()> {
+    let shell = shell::Shell::new(cli.format.is_machine_readable());
+
     // Collect all tests that the test harness is supposed to run. We assume
     // that any exported function with the prefix `__wbg_test` is a test we need
     // to execute.
@@ -159,6 +309,7 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
             name: name.into(),
             export: export.name.clone(),
             ignored: modifiers.contains('$'),
+            isolated: modifiers.contains('!') || cli.isolate,
         };
 
         if let Some(filter) = &cli.filter {
@@ -194,6 +345,21 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
         }
     }
 
+    // Every shard sees the same tests, so they agree on how to split them as
+    // long as the order is the same.
+    if let Some(shard) = cli.shard {
+        tests.tests.sort_by(|a, b| a.name.cmp(&b.name));
+        let count = tests.tests.len();
+        tests.tests = tests
+            .tests
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| i % shard.count == shard.index - 1)
+            .map(|(_, test)| test)
+            .collect();
+        tests.filtered += count - tests.tests.len();
+    }
+
     if cli.list {
         for test in tests.tests {
             if cli.bench {
@@ -211,10 +377,10 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
     // Support a WASM_BINDGEN_KEEP_TEST_BUILD=1 env var for debugging test files
     let tmpdir_path = if env::var("WASM_BINDGEN_KEEP_TEST_BUILD").is_ok() {
         let path = tmpdir.keep();
-        println!(
+        shell.info(&format!(
             "Retaining temporary build output folder: {}",
             path.to_string_lossy()
-        );
+        ));
         path
     } else {
         tmpdir.path().to_path_buf()
@@ -226,7 +392,7 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
     // `wasm-bindgen-test` runtime support isn't linked in, so just bail out
     // early saying everything is ok.
     if tests.tests.is_empty() {
-        println!("no tests to run!");
+        shell.info("no tests to run!");
         return Ok(());
     }
 
@@ -235,6 +401,10 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
     // `wasm_bindgen_test_configure` macro, which emits a custom section for us
     // to read later on.
 
+    let coverage = wasm
+        .customs
+        .remove_raw("__wasm_bindgen_test_coverage")
+        .is_some();
     let custom_section = wasm.customs.remove_raw("__wasm_bindgen_test_unstable");
     let no_modules = std::env::var("WASM_BINDGEN_USE_NO_MODULE").is_ok();
     let test_mode = match custom_section {
@@ -243,12 +413,14 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
         Some(section) if section.data.contains(&0x03) => TestMode::SharedWorker { no_modules },
         Some(section) if section.data.contains(&0x04) => TestMode::ServiceWorker { no_modules },
         Some(section) if section.data.contains(&0x05) => TestMode::Node { no_modules },
+        Some(section) if section.data.contains(&0x06) => TestMode::Bun,
         Some(_) => bail!("invalid __wasm_bingen_test_unstable value"),
         None => {
             let mut modes = Vec::new();
             let mut add_mode =
                 |mode: TestMode| std::env::var(mode.env()).is_ok().then(|| modes.push(mode));
             add_mode(TestMode::Deno);
+            add_mode(TestMode::Bun);
             add_mode(TestMode::Browser { no_modules });
             add_mode(TestMode::DedicatedWorker { no_modules });
             add_mode(TestMode::SharedWorker { no_modules });
@@ -272,21 +444,97 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
         }
     };
 
+    // Without the WWRR runtime, WASI imports are provided by the built-in shim
+    // and the module can be loaded like any other. With it, WASI modules can
+    // only be loaded through the asynchronous `--target web` output.
+    let wasi_shim = env::var_os("WWRR_DIR").is_none();
+    // The shim can't spawn threads, so `wasm32-wasip1-threads` binaries would
+    // only fail once they spawn one.
+    if wasi_shim
+        && wasm
+            .imports
+            .iter()
+            .any(|import| import.module == "wasi" && import.name == "thread-spawn")
+    {
+        bail!(
+            "this WASI test binary spawns threads, which the built-in WASI shim \
+             doesn't support; set `WWRR_DIR` to the directory of the WWRR runtime \
+             to run it"
+        )
+    }
+    let wasi = !wasi_shim && wasm.imports.iter().any(is_wasi_import);
+    if wasi {
+        match test_mode {
+            TestMode::Node { .. }
+            | TestMode::Browser { no_modules: false }
+            | TestMode::DedicatedWorker { no_modules: false } => {}
+            _ => bail!(
+                "WASI test binaries using the WWRR runtime can only be executed in \
+                 Node.js, a browser or a dedicated worker, and not with \
+                 `WASM_BINDGEN_USE_NO_MODULE`"
+            ),
+        }
+    }
+
+    // Benchmarks measure the performance of one test at a time, shared and
+    // service workers can only be instantiated once per page, and coverage is
+    // only dumped by the instance of the main thread.
+    if cli.test_threads.get() > 1 && !cli.bench {
+        if let TestMode::SharedWorker { .. } | TestMode::ServiceWorker { .. } = test_mode {
+            shell.info(
+                "`--test-threads` is not supported in shared or service workers, \
+                 running tests on a single thread",
+            );
+            cli.test_threads = NonZeroUsize::MIN;
+        } else if coverage {
+            shell.info(
+                "`--test-threads` is not supported when collecting coverage, \
+                 running tests on a single thread",
+            );
+            cli.test_threads = NonZeroUsize::MIN;
+        }
+    } else {
+        cli.test_threads = NonZeroUsize::MIN;
+    }
+
     let headless = env::var("NO_HEADLESS").is_err();
     let debug = env::var("WASM_BINDGEN_NO_DEBUG").is_err();
 
+    // Isolated tests need another copy of the JS bindings, which can only be
+    // imported again from ES modules or CommonJS, and dynamic imports aren't
+    // allowed in service workers.
+    let isolate = tests.tests.iter().any(|test| test.isolated);
+    let isolation_supported = !cli.bench
+        && !wasi
+        && match test_mode {
+            TestMode::Node { .. } | TestMode::Deno | TestMode::Bun => true,
+            TestMode::ServiceWorker { .. } => false,
+            _ => !test_mode.no_modules(),
+        };
+    if isolate && !isolation_supported {
+        if !cli.bench {
+            shell.info(
+                "isolated tests are not supported in service workers, with WASI \
+                 or with `WASM_BINDGEN_USE_NO_MODULE`, running them in a shared instance",
+            );
+        }
+        for test in tests.tests.iter_mut() {
+            test.isolated = false;
+        }
+    }
+
     // Gracefully handle requests to execute only node or only web tests.
     let node = matches!(test_mode, TestMode::Node { .. });
 
     if env::var_os("WASM_BINDGEN_TEST_ONLY_NODE").is_some() && !node {
-        println!(
+        shell.info(
             "this test suite is only configured to run in a browser, \
-             but we're only testing node.js tests so skipping"
+             but we're only testing node.js tests so skipping",
         );
         return Ok(());
     }
     if env::var_os("WASM_BINDGEN_TEST_ONLY_WEB").is_some() && node {
-        println!(
+        shell.info(
             "\
     This test suite is only configured to run in node.js, but we're only running
     browser tests so skipping. If you'd like to run the tests in a browser
@@ -296,7 +544,7 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
 
     You'll likely want to put that in a `#[cfg(test)]` module or at the top of an
     integration test.\
-    "
+    ",
         );
         return Ok(());
     }
@@ -314,20 +562,21 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
             let timeout = timeout
                 .parse()
                 .expect("Could not parse 'WASM_BINDGEN_TEST_TIMEOUT'");
-            println!("Set timeout to {timeout} seconds...");
+            shell.info(&format!("Set timeout to {timeout} seconds..."));
             timeout
         })
         .unwrap_or(20);
 
-    let shell = shell::Shell::new();
-
     // Make the generated bindings available for the tests to execute against.
     shell.status("Executing bindgen...");
     let mut b = Bindgen::new();
     match test_mode {
+        _ if wasi => b.web(true)?,
         TestMode::Node { no_modules: true } => b.nodejs(true)?,
         TestMode::Node { no_modules: false } => b.nodejs_module(true)?,
         TestMode::Deno => b.deno(true)?,
+        // Bun loads the Node.js ES module output as is.
+        TestMode::Bun => b.nodejs_module(true)?,
         TestMode::Browser { .. }
         | TestMode::DedicatedWorker { .. }
         | TestMode::SharedWorker { .. }
@@ -340,6 +589,18 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
         }
     };
 
+    b.wasi_shim(wasi_shim);
+    // Instead of loading another copy of the JS bindings for each isolated
+    // test, the copy is reset where supported. The `wasm` exports of the new
+    // instance are only exposed in debug mode.
+    if isolate && isolation_supported && debug {
+        let reset_state = match test_mode {
+            TestMode::Node { no_modules } => no_modules,
+            TestMode::Deno | TestMode::Bun => false,
+            _ => true,
+        };
+        b.reset_state_function(reset_state);
+    }
     if std::env::var("WASM_BINDGEN_SPLIT_LINKED_MODULES").is_ok() {
         b.split_linked_modules(true);
     }
@@ -373,15 +634,40 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
         .context("executing `wasm-bindgen` over the Wasm file")?;
     shell.clear();
 
+    let format = cli.format;
     match test_mode {
-        TestMode::Node { no_modules } => {
-            node::execute(module, &tmpdir_path, cli, tests, !no_modules, benchmark)?
-        }
+        TestMode::Node { no_modules } => node::execute(
+            module,
+            &tmpdir_path,
+            cli,
+            tests,
+            !no_modules,
+            wasi,
+            benchmark,
+        )?,
         TestMode::Deno => deno::execute(module, &tmpdir_path, cli, tests)?,
+        TestMode::Bun => bun::execute(module, &tmpdir_path, cli, tests)?,
         TestMode::Browser { .. }
         | TestMode::DedicatedWorker { .. }
         | TestMode::SharedWorker { .. }
         | TestMode::ServiceWorker { .. } => {
+            // Where headless browser tests save a screenshot, the console output and
+            // the HTML of the page on failure, such as
+            // `target/wbg_test_artifacts/mycrate-1a2b3c4d`.
+            let artifacts = if let Some(path) = env::var_os("WASM_BINDGEN_TEST_ARTIFACTS_DIR") {
+                PathBuf::from(path)
+            } else if let Some(path) = env::var_os("CARGO_TARGET_DIR") {
+                PathBuf::from(path).join("wbg_test_artifacts")
+            } else if let Some(path) = target_dir(&cli.file) {
+                path.join("wbg_test_artifacts")
+            } else {
+                env::current_dir()
+                    .context("Failed to get current dir")?
+                    .join("target")
+                    .join("wbg_test_artifacts")
+            }
+            .join(cli.file.file_stem().unwrap_or_default());
+
             let srv = server::spawn(
                 &if let Ok(address) = std::env::var("WASM_BINDGEN_TEST_ADDRESS") {
                     address.parse().unwrap()
@@ -416,16 +702,36 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
             }
 
             thread::spawn(|| srv.run());
-            headless::run(&addr, &shell, driver_timeout, browser_timeout)?;
+            headless::run(
+                &addr,
+                &shell,
+                format,
+                driver_timeout,
+                browser_timeout,
+                &artifacts,
+            )?;
         }
     }
     Ok(())
 }
 
+/// The target directory Cargo built the test binary `file` in, such as
+/// `target` for `target/wasm32-unknown-unknown/debug/deps/mycrate-1a2b3c4d.wasm`.
+fn target_dir(file: &Path) -> Option<PathBuf> {
+    let triple = file.parent()?.ancestors().find(|dir| {
+        dir.file_name().is_some_and(|name| {
+            let name = name.to_string_lossy();
+            name.starts_with("wasm32-") || name.starts_with("wasm64-")
+        })
+    })?;
+    triple.parent().map(Path::to_path_buf)
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum TestMode {
     Node { no_modules: bool },
     Deno,
+    Bun,
     Browser { no_modules: bool },
     DedicatedWorker { no_modules: bool },
     SharedWorker { no_modules: bool },
@@ -442,7 +748,7 @@ impl TestMode {
 
     fn no_modules(self) -> bool {
         match self {
-            Self::Deno => true,
+            Self::Deno | Self::Bun => true,
             Self::Browser { no_modules }
             | Self::Node { no_modules }
             | Self::DedicatedWorker { no_modules }
@@ -455,6 +761,7 @@ impl TestMode {
         match self {
             TestMode::Node { .. } => "WASM_BINDGEN_USE_NODE_EXPERIMENTAL",
             TestMode::Deno => "WASM_BINDGEN_USE_DENO",
+            TestMode::Bun => "WASM_BINDGEN_USE_BUN",
             TestMode::Browser { .. } => "WASM_BINDGEN_USE_BROWSER",
             TestMode::DedicatedWorker { .. } => "WASM_BINDGEN_USE_DEDICATED_WORKER",
             TestMode::SharedWorker { .. } => "WASM_BINDGEN_USE_SHARED_WORKER",
@@ -464,8 +771,56 @@ impl TestMode {
 }
 
 /// Possible values for the `--format` option.
-#[derive(Debug, Clone, Copy, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 enum FormatSetting {
+    /// Print verbose output
+    Pretty,
     /// Display one character per test
     Terse,
+    /// Output a libtest-compatible JSON event stream
+    Json,
+    /// Output a JUnit XML report
+    Junit,
+}
+
+impl FormatSetting {
+    /// Whether the output is meant to be parsed by other tools. Status messages
+    /// of the runner are then written to stderr to keep stdout parseable.
+    fn is_machine_readable(self) -> bool {
+        matches!(self, Self::Json | Self::Junit)
+    }
+
+    /// Whether a line of the test harness output belongs to the test results,
+    /// as opposed to other output of the page like loading messages.
+    fn is_result_line(self, line: &str) -> bool {
+        match self {
+            Self::Pretty | Self::Terse => true,
+            Self::Json => line.starts_with('{'),
+            Self::Junit => line.starts_with("<?xml"),
+        }
+    }
+
+    /// Looks for the end of the test suite in the output of the test harness.
+    /// Returns whether all tests passed, or `None` if the test suite didn't
+    /// finish yet.
+    fn result(self, output: &str) -> Option<bool> {
+        match self {
+            Self::Pretty | Self::Terse => output
+                .contains("test result: ")
+                .then(|| output.contains("test result: ok")),
+            Self::Json => output
+                .lines()
+                .filter(|line| line.contains(r#""type":"suite""#))
+                .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+                .filter(|event| event["type"] == "suite")
+                .find_map(|event| match event["event"].as_str() {
+                    Some("ok") => Some(true),
+                    Some("failed") => Some(false),
+                    _ => None,
+                }),
+            Self::Junit => output
+                .contains("</testsuites>")
+                .then(|| !output.contains("<failure")),
+        }
+    }
 }
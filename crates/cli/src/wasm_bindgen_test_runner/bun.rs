@@ -0,0 +1,31 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{bail, Context, Error};
+
+use super::deno::module_script;
+use super::{Cli, Tests};
+
+pub fn execute(module: &str, tmpdir: &Path, cli: Cli, tests: Tests) -> Result<(), Error> {
+    // Bun workers always run ES modules, so they need no options.
+    let js_to_execute = module_script(
+        module,
+        &cli,
+        &tests,
+        "!Bun.isMainThread",
+        "{}",
+        "process.exit(1)",
+    );
+
+    let js_path = tmpdir.join("run.mjs");
+    fs::write(&js_path, js_to_execute).context("failed to write JS file")?;
+
+    let status = Command::new("bun").arg("run").arg(&js_path).status()?;
+
+    if !status.success() {
+        bail!("Bun failed with exit_code {}", status.code().unwrap_or(1))
+    }
+
+    Ok(())
+}
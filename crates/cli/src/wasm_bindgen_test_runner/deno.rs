@@ -5,33 +5,19 @@ use std::process::Command;
 use anyhow::{bail, Context, Error};
 
 use super::Tests;
-use super::{node::shared_setup, Cli};
+use super::{
+    node::{isolated_import, shared_setup},
+    Cli, RUN_PARALLEL, RUN_TESTS,
+};
 
 pub fn execute(module: &str, tmpdir: &Path, cli: Cli, tests: Tests) -> Result<(), Error> {
-    let mut js_to_execute = format!(
-        r#"import * as wasm from "./{module}.js";
-
-        const nocapture = {nocapture};
-        {shared_setup}
-
-        globalThis.__wbg_test_invoke = f => f();
-
-        {args}
-
-        const tests = [];
-    "#,
-        shared_setup = shared_setup(cli.bench),
-        nocapture = cli.nocapture || cli.bench,
-        args = cli.get_args(&tests),
-    );
-
-    for test in tests.tests {
-        js_to_execute.push_str(&format!("tests.push('{}')\n", test.export));
-    }
-
-    js_to_execute.push_str(
-        r#"const ok = await cx.run(tests.map(n => wasm.__wasm[n]));
-if (!ok) Deno.exit(1);"#,
+    let js_to_execute = module_script(
+        module,
+        &cli,
+        &tests,
+        r#"typeof WorkerGlobalScope !== "undefined" && self instanceof WorkerGlobalScope"#,
+        r#"{ type: "module" }"#,
+        "Deno.exit(1)",
     );
 
     let js_path = tmpdir.join("run.js");
@@ -70,3 +56,93 @@ if (!ok) Deno.exit(1);"#,
 
     Ok(())
 }
+
+/// Generates the ES module running `tests`, shared with runtimes similar to
+/// Deno.
+///
+/// The module spawns itself as a worker for `--test-threads`, so it needs an
+/// `is_worker` expression telling the two apart, the `worker_options` passed
+/// to `new Worker`, and the `exit` call failing the process.
+pub fn module_script(
+    module: &str,
+    cli: &Cli,
+    tests: &Tests,
+    is_worker: &str,
+    worker_options: &str,
+    exit: &str,
+) -> String {
+    let mut js_to_execute = format!(
+        r#"import * as wasm from "./{module}.js";
+
+        // Whether this is a test thread spawned by the main thread below.
+        const is_worker = {is_worker};
+
+        const nocapture = {nocapture};
+        {shared_setup}
+
+        globalThis.__wbg_test_invoke = f => f();
+
+        function configure(cx) {{
+            {args}
+        }}
+        configure(cx);
+
+        {isolated}
+        {instantiate}
+        {RUN_TESTS}
+        {RUN_PARALLEL}
+
+        // Runs `tests` in a worker, which reports back every line of output
+        // and `null` once it's done.
+        function spawn(tests, report) {{
+            return new Promise((resolve, reject) => {{
+                const worker = new Worker(import.meta.url, {worker_options});
+                worker.onmessage = e => {{
+                    if (e.data === null) {{
+                        worker.terminate();
+                        resolve();
+                    }} else {{
+                        report(e.data);
+                    }}
+                }};
+                worker.onerror = e => {{
+                    e.preventDefault();
+                    reject(e.error ?? new Error(e.message));
+                }};
+                worker.postMessage(tests);
+            }});
+        }}
+
+        const tests = [];
+    "#,
+        shared_setup = shared_setup(
+            cli.bench,
+            "is_worker ? line => self.postMessage(line) : undefined"
+        ),
+        nocapture = cli.nocapture || cli.bench,
+        args = cli.get_args(tests),
+        isolated = tests.isolated(),
+        instantiate = isolated_import(module),
+    );
+
+    for test in tests.tests.iter() {
+        js_to_execute.push_str(&format!("tests.push('{}')\n", test.export));
+    }
+
+    js_to_execute.push_str(&format!(
+        r#"if (is_worker) {{
+    self.onmessage = async e => {{
+        await run_tests(cx, e.data, [wasm, wasm.__wasm], instantiate);
+        self.postMessage(null);
+    }};
+}} else {{
+    const ok = {threads} > 1
+        ? await run_parallel(cx, tests, {threads}, spawn)
+        : await run_tests(cx, tests, [wasm, wasm.__wasm], instantiate);
+    if (!ok) {exit};
+}}"#,
+        threads = cli.test_threads,
+    ));
+
+    js_to_execute
+}
@@ -1,11 +1,13 @@
 use super::shell::Shell;
+use super::FormatSetting;
 use anyhow::{bail, Context, Error};
+use base64::{prelude::BASE64_STANDARD, Engine as _};
 use log::{debug, warn};
 use rouille::url::Url;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value as Json};
 use std::env;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{self, Cursor, ErrorKind, Read, Write};
 use std::net::{SocketAddr, TcpListener, TcpStream};
 use std::path::{Path, PathBuf};
@@ -57,13 +59,120 @@ pub struct LegacyNewSessionParameters {
 /// binary, controlling it, running tests, scraping output, displaying output,
 /// etc. It will return `Ok` if all tests finish successfully, and otherwise it
 /// will return an error if some tests failed.
+///
+/// With a machine-readable `format`, only the test results are written to
+/// stdout once the tests finished, everything else goes to stderr.
+///
+/// If tests fail, a screenshot, the console output and the HTML of the page
+/// are written to a directory named after each failed test in `artifacts`,
+/// captured before the next test starts, see [`save_artifacts`]. Tests running
+/// in a worker can't be waited for, so the page is only captured once the
+/// whole suite finished or timed out, directly in `artifacts`.
+///
+/// The browsers listed in `WASM_BINDGEN_TEST_BROWSERS`, such as
+/// `chrome,firefox`, each run the tests in turn against the same server. The
+/// machine-readable reports of several browsers are merged into one, see
+/// [`merge_reports`].
 pub fn run(
     server: &SocketAddr,
     shell: &Shell,
+    format: FormatSetting,
     driver_timeout: u64,
     test_timeout: u64,
+    artifacts: &Path,
+) -> Result<(), Error> {
+    let browsers = env::var("WASM_BINDGEN_TEST_BROWSERS").unwrap_or_default();
+    let browsers = browsers
+        .split(',')
+        .map(str::trim)
+        .filter(|browser| !browser.is_empty())
+        .collect::<Vec<_>>();
+    let drivers = browsers
+        .into_iter()
+        .map(Driver::find_browser)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if drivers.is_empty() {
+        let driver = Driver::find()?;
+        let mut report = Vec::new();
+        let result = run_browser(
+            driver,
+            server,
+            shell,
+            format,
+            driver_timeout,
+            test_timeout,
+            artifacts,
+            &mut report,
+        );
+        for line in report {
+            println!("{line}");
+        }
+        return result;
+    }
+
+    let mut results = Vec::new();
+    let mut reports = Vec::new();
+    for driver in drivers {
+        let browser = driver.browser();
+        let artifacts = artifacts.join(browser.to_ascii_lowercase());
+        let mut report = Vec::new();
+        let result = run_browser(
+            driver,
+            server,
+            shell,
+            format,
+            driver_timeout,
+            test_timeout,
+            &artifacts,
+            &mut report,
+        );
+        results.push((browser, result));
+        reports.push((browser, report));
+    }
+    if format.is_machine_readable() {
+        if let [(_, report)] = reports.as_slice() {
+            for line in report {
+                println!("{line}");
+            }
+        } else {
+            println!("{}", merge_reports(format, &reports));
+        }
+    }
+
+    let mut failed = Vec::new();
+    shell.info("browser results:");
+    for (browser, result) in results {
+        match result {
+            Ok(()) => shell.info(&format!("    {browser}: ok")),
+            Err(e) => {
+                shell.info(&format!("    {browser}: FAILED ({e:#})"));
+                failed.push(browser);
+            }
+        }
+    }
+    if !failed.is_empty() {
+        bail!("some tests failed in {}", failed.join(", "))
+    }
+
+    Ok(())
+}
+
+/// Runs the tests in the browser controlled by `driver`, see [`run`].
+///
+/// With a machine-readable `format`, the lines of the test results are added
+/// to `report` instead of being printed.
+#[allow(clippy::too_many_arguments)]
+fn run_browser(
+    driver: Driver,
+    server: &SocketAddr,
+    shell: &Shell,
+    format: FormatSetting,
+    driver_timeout: u64,
+    test_timeout: u64,
+    artifacts: &Path,
+    report: &mut Vec<String>,
 ) -> Result<(), Error> {
-    let driver = Driver::find()?;
     let mut drop_log: Box<dyn FnMut()> = Box::new(|| ());
     let driver_url = match driver.location() {
         Locate::Remote(url) => Ok(url.clone()),
@@ -92,7 +201,7 @@ pub fn run(
                             bail!("driver failed to start")
                         }
 
-                        println!("Failed to start driver, trying again ...");
+                        shell.info("Failed to start driver, trying again ...");
 
                         thread::sleep(Duration::from_millis(100));
                         break;
@@ -114,27 +223,27 @@ pub fn run(
             Url::parse(&format!("http://{driver_addr}")).map_err(Error::from)
         }
     }?;
-    println!(
+    shell.info(&format!(
         "Running headless tests in {} on `{}`",
         driver.browser(),
         driver_url.as_str(),
-    );
+    ));
 
     let mut client = Client {
         agent: Agent::new_with_defaults(),
         driver_url,
         session: None,
     };
-    println!("Try find `webdriver.json` for configure browser's capabilities:");
+    shell.info("Try find `webdriver.json` for configure browser's capabilities:");
     let capabilities: Capabilities = match File::open(
         std::env::var("WASM_BINDGEN_TEST_WEBDRIVER_JSON").unwrap_or("webdriver.json".to_string()),
     ) {
         Ok(file) => {
-            println!("Ok");
+            shell.info("Ok");
             serde_json::from_reader(file)
         }
         Err(_) => {
-            println!("Not found");
+            shell.info("Not found");
             Ok(Capabilities::new())
         }
     }?;
@@ -180,14 +289,35 @@ pub fn run(
     shell.status("Waiting for test to finish...");
     let start = Instant::now();
     let max = Duration::new(test_timeout, 0);
-    let no_stream_scrape = env::var_os("WASM_BINDGEN_TEST_NO_STREAM").is_some();
+    // Machine-readable output is filtered once complete, so it isn't streamed.
+    let no_stream_scrape =
+        env::var_os("WASM_BINDGEN_TEST_NO_STREAM").is_some() || format.is_machine_readable();
     let mut shell_cleared = false;
     let mut output_buf = String::new();
     let mut output_offset = 0usize;
+    // Only Chromium-based browsers let WebDriver read their log.
+    let browser_log = matches!(driver, Driver::Chrome(_) | Driver::Edge(_));
+    let mut artifact_paths = Vec::new();
     while start.elapsed() < max {
+        // A failed test waits for us to snapshot the page it left behind
+        // before the next test starts, see `index-headless.html`.
+        if let Some(name) = client.failed_test(&id)? {
+            let console = client.console_output(&id)?;
+            let dir = artifacts.join(artifacts_dir_name(&name));
+            artifact_paths.extend(save_artifacts(
+                &mut client,
+                &id,
+                &dir,
+                &console,
+                browser_log,
+                shell,
+            ));
+            client.resume_tests(&id)?;
+        }
+
         if no_stream_scrape {
             let output = client.text_content(&id, "#output", 0)?;
-            if output.chunk.contains("test result: ") {
+            if format.result(&output.chunk).is_some() {
                 output_buf = output.chunk;
                 output_offset = output.next_offset;
                 break;
@@ -208,7 +338,7 @@ pub fn run(
                 output_buf.push_str(&new_output);
             }
 
-            if output_buf.contains("test result: ") {
+            if format.result(&output_buf).is_some() {
                 break;
             }
         }
@@ -221,7 +351,7 @@ pub fn run(
     // Tests have now finished or have timed out. At this point we need to check
     // what happened. In streaming mode output was already printed in real-time.
     // In no-stream mode, emit the buffered output now.
-    if no_stream_scrape && !output_buf.is_empty() {
+    if no_stream_scrape && !format.is_machine_readable() && !output_buf.is_empty() {
         io::stdout().lock().write_all(output_buf.as_bytes())?;
     }
 
@@ -231,35 +361,56 @@ pub fn run(
         output.chunk
     };
     if !remaining_output.is_empty() {
-        io::stdout().lock().write_all(remaining_output.as_bytes())?;
+        if !format.is_machine_readable() {
+            io::stdout().lock().write_all(remaining_output.as_bytes())?;
+        }
         output_buf.push_str(&remaining_output);
     }
 
-    if output_buf.contains("test result: ") {
+    if format.is_machine_readable() {
+        // The JUnit report spans several lines if the output of a test does.
+        let mut in_report = false;
+        for line in output_buf.lines() {
+            let is_result = in_report || format.is_result_line(line);
+            in_report =
+                is_result && format == FormatSetting::Junit && !line.ends_with("</testsuites>");
+            if is_result {
+                report.push(line.to_string());
+            } else {
+                shell.info(line);
+            }
+        }
+    }
+
+    let result = format.result(&output_buf);
+    if result.is_some() {
         // If the tests harness finished (either successfully or unsuccessfully)
         // then in theory all the info needed to debug the failure is in its own
         // output, so we shouldn't need the driver logs to get printed.
         drop_log();
     } else {
-        println!("Failed to detect test as having been run. It might have timed out.");
+        shell.info("Failed to detect test as having been run. It might have timed out.");
     }
 
-    if !output_buf.contains("test result: ok") {
-        // Read console output incrementally to avoid exceeding WebDriver response limits.
-        let mut has_output = false;
-        let mut offset = 0;
-        loop {
-            let output = client.text_content(&id, "#console_output", offset)?;
-            let chunk = output.chunk;
-            if chunk.is_empty() {
-                break;
-            }
-            if !has_output {
-                println!("console output:");
-                has_output = true;
+    if result != Some(true) {
+        let console = client.console_output(&id)?;
+        if !console.is_empty() {
+            shell.info("console output:");
+            shell.info(tab(&console).trim_end_matches('\n'));
+        }
+
+        // Without a snapshot of a failed test, such as when the tests timed
+        // out or ran in a worker, the page as the tests left it is the best
+        // we have.
+        if artifact_paths.is_empty() {
+            artifact_paths =
+                save_artifacts(&mut client, &id, artifacts, &console, browser_log, shell);
+        }
+        if !artifact_paths.is_empty() {
+            shell.info("failure artifacts:");
+            for path in artifact_paths {
+                shell.info(&format!("    {}", path.display()));
             }
-            io::stdout().lock().write_all(tab(&chunk).as_bytes())?;
-            offset = output.next_offset;
         }
 
         bail!("some tests failed")
@@ -268,6 +419,125 @@ pub fn run(
     Ok(())
 }
 
+/// Merges the machine-readable `reports` of several browsers into one, adding
+/// the browser to the name of each test, such as `chrome::tests::it_works`.
+///
+/// The JSON event streams of the browsers follow each other, like those of
+/// several test binaries. The JUnit reports become one with a test suite per
+/// browser.
+fn merge_reports(format: FormatSetting, reports: &[(&str, Vec<String>)]) -> String {
+    let mut merged = Vec::new();
+    for (id, (browser, report)) in reports.iter().enumerate() {
+        let browser = browser.to_ascii_lowercase();
+        if format == FormatSetting::Junit {
+            let xml = report.join("\n");
+            let Some(start) = xml.find("<testsuites>") else {
+                continue;
+            };
+            let end = xml.rfind("</testsuites>").unwrap_or(xml.len());
+            merged.push(
+                xml[start + "<testsuites>".len()..end]
+                    .replace(
+                        r#"<testsuite name="test" package="test" id="0""#,
+                        &format!(r#"<testsuite name="{browser}" package="test" id="{id}""#),
+                    )
+                    .replace(
+                        r#"<testcase classname=""#,
+                        &format!(r#"<testcase classname="{browser}::"#),
+                    ),
+            );
+            continue;
+        }
+
+        for line in report {
+            let mut event: Json = match serde_json::from_str(line) {
+                Ok(event) => event,
+                Err(_) => {
+                    merged.push(line.clone());
+                    continue;
+                }
+            };
+            if let Some(name) = event["name"].as_str() {
+                event["name"] = format!("{browser}::{name}").into();
+            }
+            merged.push(event.to_string());
+        }
+    }
+
+    if format == FormatSetting::Junit {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?><testsuites>{}</testsuites>"#,
+            merged.concat()
+        )
+    } else {
+        merged.join("\n")
+    }
+}
+
+/// Writes a screenshot, the console output and the HTML of the page of the
+/// session `id` to `dir`, and returns the paths of the written files.
+///
+/// `console` is what the page passed to the `console` methods the test harness
+/// wraps, saved as `page-console.log`. WebDriver has no portable way to read
+/// the browser's own log, with e.g. network errors, so it's only saved as
+/// `browser.log` if `browser_log` says the browser supports it.
+///
+/// Failing to capture one of them is reported but doesn't prevent capturing the
+/// others, as they are only meant to help debugging the test failure.
+fn save_artifacts(
+    client: &mut Client,
+    id: &str,
+    dir: &Path,
+    console: &str,
+    browser_log: bool,
+    shell: &Shell,
+) -> Vec<PathBuf> {
+    if let Err(e) = fs::create_dir_all(dir) {
+        shell.info(&format!(
+            "failed to create artifacts directory {}: {e}",
+            dir.display()
+        ));
+        return Vec::new();
+    }
+
+    let mut paths = Vec::new();
+    let mut save = |name: &str, data: Result<Vec<u8>, Error>| {
+        let path = dir.join(name);
+        match data.and_then(|data| Ok(fs::write(&path, data)?)) {
+            Ok(()) => paths.push(path),
+            Err(e) => shell.info(&format!("failed to save {}: {e:#}", path.display())),
+        }
+    };
+    save("screenshot.png", client.screenshot(id));
+    save("page-console.log", Ok(console.as_bytes().to_vec()));
+    if browser_log {
+        save(
+            "browser.log",
+            client.browser_log(id).map(String::into_bytes),
+        );
+    }
+    save(
+        "document.html",
+        client.outer_html(id).map(String::into_bytes),
+    );
+    paths
+}
+
+/// The name of the directory with the artifacts of the failed test `name`,
+/// such as `tests-it_works` for `tests::it_works`.
+fn artifacts_dir_name(name: &str) -> String {
+    name.replace("::", "-")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
 #[derive(Copy, Clone, Debug)]
 enum StyleMode {
     DisplayNone,
@@ -295,6 +565,17 @@ enum Locate {
     Remote(Url),
 }
 
+/// A WebDriver binary and the constructor of its [`Driver`].
+type DriverBinary = (&'static str, fn(Locate) -> Driver);
+
+/// The supported WebDriver binaries, in order of preference.
+const DRIVERS: [DriverBinary; 4] = [
+    ("geckodriver", Driver::Gecko),
+    ("safaridriver", Driver::Safari),
+    ("chromedriver", Driver::Chrome),
+    ("msedgedriver", Driver::Edge),
+];
+
 impl Driver {
     /// Attempts to find an appropriate remote WebDriver server or server binary
     /// to execute tests with.
@@ -308,6 +589,70 @@ impl Driver {
     /// which is configured through env vars like `GECKODRIVER_ARGS` to support
     /// extra arguments to the driver's invocation.
     fn find() -> Result<Driver, Error> {
+        if let Some(driver) = Driver::locate(&DRIVERS)? {
+            return Ok(driver);
+        }
+
+        // TODO: download an appropriate driver? How to know which one to
+        //       download?
+
+        bail!(
+            "\
+failed to find a suitable WebDriver binary or remote running WebDriver to drive
+headless testing; to configure the location of the webdriver binary you can use
+environment variables like `GECKODRIVER=/path/to/geckodriver` or make sure that
+the binary is in `PATH`; to configure the address of remote webdriver you can
+use environment variables like `GECKODRIVER_REMOTE=http://remote.host/`
+
+This crate currently supports `geckodriver`, `chromedriver`, `safaridriver`, and
+`msedgedriver`, although more driver support may be added! You can download these at:
+
+    * geckodriver - https://github.com/mozilla/geckodriver/releases
+    * chromedriver - https://chromedriver.chromium.org/downloads
+    * msedgedriver - https://developer.microsoft.com/en-us/microsoft-edge/tools/webdriver/
+    * safaridriver - should be preinstalled on OSX
+
+If you would prefer to not use headless testing and would instead like to do
+interactive testing in a web browser then you can specify `NO_HEADLESS=1` as
+an environment variable. When rerun the tests will start a server that you can
+visit in a web browser, and headless testing should not be used.
+
+If you're still having difficulty resolving this error, please feel free to open
+an issue against wasm-bindgen/wasm-bindgen!
+    "
+        )
+    }
+
+    /// Finds the WebDriver of a browser named in `WASM_BINDGEN_TEST_BROWSERS`,
+    /// with the same heuristics as [`Driver::find`].
+    fn find_browser(browser: &str) -> Result<Driver, Error> {
+        let name = match browser.to_ascii_lowercase().as_str() {
+            "firefox" => "geckodriver",
+            "safari" => "safaridriver",
+            "chrome" => "chromedriver",
+            "edge" => "msedgedriver",
+            _ => bail!(
+                "unknown browser `{browser}` in `WASM_BINDGEN_TEST_BROWSERS`, \
+                 expected `firefox`, `chrome`, `safari` or `edge`"
+            ),
+        };
+        let drivers = DRIVERS
+            .iter()
+            .filter(|(driver, _)| *driver == name)
+            .copied()
+            .collect::<Vec<_>>();
+        Driver::locate(&drivers)?.with_context(|| {
+            format!(
+                "failed to find `{name}` to run tests in {browser}; configure it with \
+                 `{}` or `{}_REMOTE`, or make sure that it is in `PATH`",
+                name.to_uppercase(),
+                name.to_uppercase(),
+            )
+        })
+    }
+
+    /// Looks for one of `drivers`, see [`Driver::find`].
+    fn locate(drivers: &[DriverBinary]) -> Result<Option<Driver>, Error> {
         let env_args = |name: &str| {
             let var = env::var(format!("{}_ARGS", name.to_uppercase())).unwrap_or_default();
 
@@ -315,13 +660,6 @@ impl Driver {
                 .unwrap_or_else(|| var.split_whitespace().map(|s| s.to_string()).collect())
         };
 
-        let drivers = [
-            ("geckodriver", Driver::Gecko as fn(Locate) -> Driver),
-            ("safaridriver", Driver::Safari as fn(Locate) -> Driver),
-            ("chromedriver", Driver::Chrome as fn(Locate) -> Driver),
-            ("msedgedriver", Driver::Edge as fn(Locate) -> Driver),
-        ];
-
         // First up, if env vars like GECKODRIVER_REMOTE are present, use those
         // to allow forcing usage of a particular remote driver.
         for (driver, ctor) in drivers.iter() {
@@ -330,7 +668,7 @@ impl Driver {
                 Ok(var) => Url::parse(&var).context(format!("failed to parse `{env}`"))?,
                 Err(_) => continue,
             };
-            return Ok(ctor(Locate::Remote(url)));
+            return Ok(Some(ctor(Locate::Remote(url))));
         }
 
         // Next, if env vars like GECKODRIVER are present, use those to
@@ -341,7 +679,7 @@ impl Driver {
                 Some(path) => path,
                 None => continue,
             };
-            return Ok(ctor(Locate::Local((path.into(), env_args(driver)))));
+            return Ok(Some(ctor(Locate::Local((path.into(), env_args(driver))))));
         }
 
         // Next, check PATH. If we can find any supported driver, use that by
@@ -356,40 +694,13 @@ impl Driver {
                 Some(p) => p,
                 None => continue,
             };
-            return Ok(ctor(Locate::Local((driver.into(), env_args(driver)))));
+            return Ok(Some(ctor(Locate::Local((driver.into(), env_args(driver))))));
         }
 
-        // TODO: download an appropriate driver? How to know which one to
-        //       download?
-
-        bail!(
-            "\
-failed to find a suitable WebDriver binary or remote running WebDriver to drive
-headless testing; to configure the location of the webdriver binary you can use
-environment variables like `GECKODRIVER=/path/to/geckodriver` or make sure that
-the binary is in `PATH`; to configure the address of remote webdriver you can
-use environment variables like `GECKODRIVER_REMOTE=http://remote.host/`
-
-This crate currently supports `geckodriver`, `chromedriver`, `safaridriver`, and
-`msedgedriver`, although more driver support may be added! You can download these at:
-
-    * geckodriver - https://github.com/mozilla/geckodriver/releases
-    * chromedriver - https://chromedriver.chromium.org/downloads
-    * msedgedriver - https://developer.microsoft.com/en-us/microsoft-edge/tools/webdriver/
-    * safaridriver - should be preinstalled on OSX
-
-If you would prefer to not use headless testing and would instead like to do
-interactive testing in a web browser then you can specify `NO_HEADLESS=1` as
-an environment variable. When rerun the tests will start a server that you can
-visit in a web browser, and headless testing should not be used.
-
-If you're still having difficulty resolving this error, please feel free to open
-an issue against wasm-bindgen/wasm-bindgen!
-    "
-        )
+        Ok(None)
     }
 
-    fn browser(&self) -> &str {
+    fn browser(&self) -> &'static str {
         match self {
             Driver::Gecko(_) => "Firefox",
             Driver::Safari(_) => "Safari",
@@ -509,6 +820,9 @@ impl Client {
                         Json::String("disable-dev-shm-usage".to_string()),
                         Json::String("no-sandbox".to_string()),
                     ]);
+                // Lets `browser_log` read what the browser logged.
+                cap.entry("goog:loggingPrefs".to_string())
+                    .or_insert_with(|| json!({ "browser": "ALL" }));
                 let request = LegacyNewSessionParameters {
                     desired: cap,
                     required: Capabilities::new(),
@@ -538,6 +852,9 @@ impl Client {
                         Json::String("disable-dev-shm-usage".to_string()),
                         Json::String("no-sandbox".to_string()),
                     ]);
+                // Lets `browser_log` read what the browser logged.
+                cap.entry("ms:loggingPrefs".to_string())
+                    .or_insert_with(|| json!({ "browser": "ALL" }));
                 let request = LegacyNewSessionParameters {
                     desired: cap,
                     required: Capabilities::new(),
@@ -620,6 +937,102 @@ impl Client {
         }
     }
 
+    /// Reads the console output of the page, incrementally to avoid exceeding
+    /// WebDriver response limits.
+    fn console_output(&mut self, id: &str) -> Result<String, Error> {
+        let mut console = String::new();
+        let mut offset = 0;
+        loop {
+            let output = self.text_content(id, "#console_output", offset)?;
+            if output.chunk.is_empty() {
+                return Ok(console);
+            }
+            console.push_str(&output.chunk);
+            offset = output.next_offset;
+        }
+    }
+
+    /// Returns the name of the failed test waiting for a snapshot of the page,
+    /// if any, see `index-headless.html`.
+    fn failed_test(&mut self, id: &str) -> Result<Option<String>, Error> {
+        let value = self.execute(
+            id,
+            "const failures = self.__wbg_test_failures; \
+             return failures && failures.length ? failures[0].name : null;",
+        )?;
+        Ok(value.as_str().map(str::to_string))
+    }
+
+    /// Lets the tests go on after the snapshot of [`Client::failed_test`].
+    fn resume_tests(&mut self, id: &str) -> Result<(), Error> {
+        self.execute(id, "self.__wbg_test_failures.shift().done();")?;
+        Ok(())
+    }
+
+    fn execute(&mut self, id: &str, script: &str) -> Result<Json, Error> {
+        #[derive(Serialize)]
+        struct Request<'a> {
+            script: &'a str,
+            args: [(); 0],
+        }
+        #[derive(Deserialize)]
+        struct Response {
+            value: Json,
+        }
+        let request = Request { script, args: [] };
+        let x: Response = self.post(&format!("/session/{id}/execute/sync"), &request)?;
+        Ok(x.value)
+    }
+
+    fn screenshot(&mut self, id: &str) -> Result<Vec<u8>, Error> {
+        #[derive(Deserialize)]
+        struct Response {
+            value: String,
+        }
+        let x: Response = self.get(&format!("/session/{id}/screenshot"))?;
+        BASE64_STANDARD
+            .decode(x.value)
+            .context("failed to decode screenshot")
+    }
+
+    fn outer_html(&mut self, id: &str) -> Result<String, Error> {
+        #[derive(Serialize)]
+        struct Request {
+            script: &'static str,
+            args: [(); 0],
+        }
+        #[derive(Deserialize)]
+        struct Response {
+            value: String,
+        }
+        let request = Request {
+            script: "return document.documentElement.outerHTML;",
+            args: [],
+        };
+        let x: Response = self.post(&format!("/session/{id}/execute/sync"), &request)?;
+        Ok(x.value)
+    }
+
+    /// Reads what the browser logged since the last call, which only
+    /// Chromium-based browsers support.
+    fn browser_log(&mut self, id: &str) -> Result<String, Error> {
+        #[derive(Deserialize)]
+        struct Response {
+            value: Vec<Entry>,
+        }
+        #[derive(Deserialize)]
+        struct Entry {
+            level: String,
+            message: String,
+        }
+        let request = json!({ "type": "browser" });
+        let x: Response = self.post(&format!("/session/{id}/se/log"), &request)?;
+        Ok(x.value
+            .into_iter()
+            .map(|entry| format!("{} {}\n", entry.level, entry.message))
+            .collect())
+    }
+
     fn session_browser_name(&mut self, id: &str) -> Option<String> {
         let value: serde_json::Value = match self.get(&format!("/session/{id}")) {
             Ok(value) => value,
@@ -800,15 +1213,21 @@ impl Drop for BackgroundChild<'_> {
         }
 
         self.shell.clear();
-        println!("driver status: {status}");
+        self.shell.info(&format!("driver status: {status}"));
 
         let stdout = self.stdout.take().unwrap().join().unwrap().unwrap();
         if !stdout.is_empty() {
-            println!("driver stdout:\n{}", tab(&String::from_utf8_lossy(&stdout)));
+            self.shell.info(&format!(
+                "driver stdout:\n{}",
+                tab(&String::from_utf8_lossy(&stdout))
+            ));
         }
         let stderr = self.stderr.take().unwrap().join().unwrap().unwrap();
         if !stderr.is_empty() {
-            println!("driver stderr:\n{}", tab(&String::from_utf8_lossy(&stderr)));
+            self.shell.info(&format!(
+                "driver stderr:\n{}",
+                tab(&String::from_utf8_lossy(&stderr))
+            ));
         }
     }
 }
@@ -8,9 +8,11 @@ use anyhow::{Context, Error};
 
 use super::Cli;
 use super::Tests;
+use super::{RUN_PARALLEL, RUN_TESTS};
 
-// depends on the variable 'wasm' and initializes te WasmBindgenTestContext cx
-pub fn shared_setup(is_bench: bool) -> String {
+// depends on the variable 'wasm' and initializes te WasmBindgenTestContext cx,
+// forwarding its output to the JS expression `forward` if it isn't undefined
+pub fn shared_setup(is_bench: bool, forward: &str) -> String {
     format!(
         r#"
 const handlers = {{}};
@@ -39,12 +41,51 @@ wrap("info");
 wrap("warn");
 wrap("error");
 
-const cx = new wasm.WasmBindgenTestContext({is_bench});
-handlers.on_console_debug = wasm.__wbgtest_console_debug;
-handlers.on_console_log = wasm.__wbgtest_console_log;
-handlers.on_console_info = wasm.__wbgtest_console_info;
-handlers.on_console_warn = wasm.__wbgtest_console_warn;
-handlers.on_console_error = wasm.__wbgtest_console_error;
+// route captured output to the instance of the running tests
+function set_console(bindings) {{
+    handlers.on_console_debug = bindings.__wbgtest_console_debug;
+    handlers.on_console_log = bindings.__wbgtest_console_log;
+    handlers.on_console_info = bindings.__wbgtest_console_info;
+    handlers.on_console_warn = bindings.__wbgtest_console_warn;
+    handlers.on_console_error = bindings.__wbgtest_console_error;
+}}
+
+const cx = new wasm.WasmBindgenTestContext({is_bench}, {forward});
+set_console(wasm);
+"#
+    )
+}
+
+// Creates another instance of the Wasm module for an isolated test, by
+// importing another copy of the JS bindings.
+pub fn isolated_import(module: &str) -> String {
+    format!(
+        r#"
+let isolates = 0;
+async function instantiate() {{
+    const isolate = await import(`./{module}.js?isolate=${{++isolates}}`);
+    return [isolate, isolate.__wasm];
+}}
+"#
+    )
+}
+
+// WASI modules are instantiated asynchronously by the WWRR runtime, which loads
+// both its own and the tested Wasm module with `fetch()`. Node.js can't fetch
+// `file:` URLs, so they are read from disk instead.
+fn wasi_init(module: &str) -> String {
+    format!(
+        r#"
+const __wbg_fetch = globalThis.fetch;
+globalThis.fetch = async (input, init) => {{
+    const url = new URL(input instanceof Request ? input.url : String(input), import.meta.url);
+    if (url.protocol !== 'file:')
+        return __wbg_fetch(input, init);
+    const headers = url.pathname.endsWith('.wasm') ? {{ 'Content-Type': 'application/wasm' }} : {{}};
+    return new Response(await fs.readFile(url), {{ headers }});
+}};
+
+const __wbg_wasi_exports = await wasm.default(new URL('./{module}_bg.wasm', import.meta.url).href);
 "#
     )
 }
@@ -55,8 +96,12 @@ pub fn execute(
     cli: Cli,
     tests: Tests,
     module_format: bool,
+    wasi: bool,
     benchmark: PathBuf,
 ) -> Result<(), Error> {
+    // WASI modules are always generated as ES modules.
+    let module_format = module_format || wasi;
+
     let coverage_env = if let Ok(env) = env::var("LLVM_PROFILE_FILE") {
         &format!("\"{env}\"")
     } else {
@@ -72,15 +117,44 @@ pub fn execute(
         r#"
         {exit};
         {fs};
+        {worker_threads};
         {wasm};
+        {wasi_init}
 
         const nocapture = {nocapture};
         {shared_setup}
 
         globalThis.__wbg_test_invoke = f => f();
 
-        async function main(tests) {{
+        function configure(cx) {{
             {args}
+        }}
+
+        {isolated}
+        {instantiate}
+        {RUN_TESTS}
+        {RUN_PARALLEL}
+
+        // Runs `tests` in a worker thread, which reports back every line of
+        // output and `null` once it's done.
+        function spawn(tests, report) {{
+            return new Promise((resolve, reject) => {{
+                const worker = new Worker({script}, {{ workerData: tests }});
+                worker.on('message', line => {{
+                    if (line === null) {{
+                        worker.terminate();
+                        resolve();
+                    }} else {{
+                        report(line);
+                    }}
+                }});
+                worker.on('error', reject);
+                worker.on('exit', code => reject(new Error(`test thread exited with code ${{code}}`)));
+            }});
+        }}
+
+        async function main(tests) {{
+            configure(cx);
 
             if ({is_bench}) {{
                 try {{
@@ -91,7 +165,14 @@ pub fn execute(
                 }}
             }}
 
-            const ok = await cx.run(tests.map(n => wasm.__wasm[n]));
+            const ok = isMainThread && {threads} > 1
+                ? await run_parallel(cx, tests, {threads}, spawn)
+                : await run_tests(cx, tests, [wasm, {exports}], instantiate);
+
+            if (!isMainThread) {{
+                parentPort.postMessage(null);
+                return;
+            }}
 
             const coverage = wasm.__wbgtest_cov_dump();
             if (coverage !== undefined) {{
@@ -111,7 +192,10 @@ pub fn execute(
 
         const tests = [];
     "#,
-        shared_setup = shared_setup(cli.bench),
+        shared_setup = shared_setup(
+            cli.bench,
+            "isMainThread ? undefined : line => parentPort.postMessage(line)"
+        ),
         wasm = if !module_format {
             format!(r"const wasm = require('./{module}.js')")
         } else {
@@ -127,6 +211,49 @@ pub fn execute(
         } else {
             r"import fs from 'node:fs/promises'".to_string()
         },
+        worker_threads = if !module_format {
+            r"const { Worker, isMainThread, parentPort, workerData } = require('node:worker_threads')"
+        } else {
+            r"import { Worker, isMainThread, parentPort, workerData } from 'node:worker_threads'"
+        },
+        script = if !module_format {
+            "__filename"
+        } else {
+            "new URL(import.meta.url)"
+        },
+        threads = cli.test_threads,
+        isolated = tests.isolated(),
+        instantiate = if !module_format {
+            format!(
+                r"
+        // Creates another instance for an isolated test, resetting the
+        // previous one if supported.
+        let isolate;
+        async function instantiate() {{
+            if (isolate && isolate.__wbg_reset_state) {{
+                isolate.__wbg_reset_state();
+            }} else {{
+                const path = require.resolve('./{module}.js');
+                delete require.cache[path];
+                isolate = require(path);
+            }}
+            return [isolate, isolate.__wasm];
+        }}
+                "
+            )
+        } else {
+            isolated_import(module)
+        },
+        wasi_init = if wasi {
+            wasi_init(module)
+        } else {
+            String::new()
+        },
+        exports = if wasi {
+            "__wbg_wasi_exports"
+        } else {
+            "wasm.__wasm"
+        },
         is_bench = cli.bench,
         nocapture = cli.nocapture || cli.bench,
         args = cli.get_args(&tests),
@@ -140,16 +267,22 @@ pub fn execute(
         js_to_execute.push_str(&format!("tests.push('{}')\n", test.export));
     }
     // And as a final addendum, exit with a nonzero code if any tests fail.
+    // Test threads are instead terminated by the main thread once they're
+    // done, and their errors are reported by it.
     js_to_execute.push_str(
         "
-        main(tests)
-            .then(() => {
-                exit(0);
-            })
-            .catch(e => {
-                console.error(e);
-                exit(1);
-            });
+        if (isMainThread) {
+            main(tests)
+                .then(() => {
+                    exit(0);
+                })
+                .catch(e => {
+                    console.error(e);
+                    exit(1);
+                });
+        } else {
+            main(workerData);
+        }
     ",
     );
 
@@ -7,7 +7,7 @@ use std::{env, fs, process};
 use anyhow::{anyhow, Context, Error};
 use rouille::{Request, Response, Server};
 
-use super::{Cli, TestMode, Tests};
+use super::{Cli, TestMode, Tests, RUN_PARALLEL, RUN_TESTS};
 
 pub(crate) fn spawn(
     addr: &SocketAddr,
@@ -75,12 +75,8 @@ pub(crate) fn spawn(
     let wbg_import_script = if test_mode.no_modules() {
         format!(
             r#"
+            let bindings = wasm_bindgen;
             let Context = wasm_bindgen.WasmBindgenTestContext;
-            let __wbgtest_console_debug = wasm_bindgen.__wbgtest_console_debug;
-            let __wbgtest_console_log = wasm_bindgen.__wbgtest_console_log;
-            let __wbgtest_console_info = wasm_bindgen.__wbgtest_console_info;
-            let __wbgtest_console_warn = wasm_bindgen.__wbgtest_console_warn;
-            let __wbgtest_console_error = wasm_bindgen.__wbgtest_console_error;
             {cov_import}
             {bench_import}
             let init = wasm_bindgen;
@@ -89,13 +85,9 @@ pub(crate) fn spawn(
     } else {
         format!(
             r#"
+            import * as bindings from './{module}';
             import {{
                 WasmBindgenTestContext as Context,
-                __wbgtest_console_debug,
-                __wbgtest_console_log,
-                __wbgtest_console_info,
-                __wbgtest_console_warn,
-                __wbgtest_console_error,
                 {cov_import}
                 {bench_import}
                 default as init,
@@ -107,6 +99,42 @@ pub(crate) fn spawn(
     let nocapture = cli.nocapture || cli.bench;
     let is_bench = cli.bench;
     let args = cli.get_args(&tests);
+    let threads = cli.test_threads.get();
+
+    // Support for running tests on the page or in a worker, see `RUN_TESTS`.
+    let run_tests = format!(
+        r#"
+        // Route captured output to the instance of the running tests.
+        function set_console(bindings) {{
+            globalThis.on_console_debug = bindings.__wbgtest_console_debug;
+            globalThis.on_console_log = bindings.__wbgtest_console_log;
+            globalThis.on_console_info = bindings.__wbgtest_console_info;
+            globalThis.on_console_warn = bindings.__wbgtest_console_warn;
+            globalThis.on_console_error = bindings.__wbgtest_console_error;
+        }}
+
+        function configure(cx) {{
+            {args}
+        }}
+
+        // Creates another instance for an isolated test, resetting the
+        // previous one if supported.
+        let isolate;
+        let isolates = 0;
+        async function instantiate() {{
+            if (isolate && isolate.__wbg_reset_state) {{
+                isolate.__wbg_reset_state();
+                return [isolate, isolate.__wasm];
+            }}
+            isolate = await import(`./{module}.js?isolate=${{++isolates}}`);
+            return [isolate, await isolate.default('./{module}_bg.wasm')];
+        }}
+
+        {isolated}
+        {RUN_TESTS}
+        "#,
+        isolated = tests.isolated(),
+    );
 
     if test_mode.is_worker() {
         let mut worker_script = if test_mode.no_modules() {
@@ -116,6 +144,7 @@ pub(crate) fn spawn(
         };
 
         worker_script.push_str(&wbg_import_script);
+        worker_script.push_str(&run_tests);
 
         match test_mode {
             TestMode::DedicatedWorker { .. } => worker_script.push_str("const port = self\n"),
@@ -166,26 +195,21 @@ pub(crate) fn spawn(
             async function run_in_worker(tests) {{
                 const wasm = await init("./{module}_bg.wasm");
                 const t = self;
-                const cx = new Context({is_bench});
-
-                self.on_console_debug = __wbgtest_console_debug;
-                self.on_console_log = __wbgtest_console_log;
-                self.on_console_info = __wbgtest_console_info;
-                self.on_console_warn = __wbgtest_console_warn;
-                self.on_console_error = __wbgtest_console_error;
-
-                {args}
+                const cx = new Context({is_bench}, {forward});
+                set_console(bindings);
+                configure(cx);
 
                 if ({is_bench}) {{
                     {import_bench}
                 }}
 
-                await cx.run(tests.map(s => wasm[s]));
+                await run_tests(cx, tests, [bindings, wasm], instantiate);
                 {cov_dump}
 
                 if ({is_bench}) {{
                     {dump_bench}
                 }}
+                {done}
             }}
 
             port.onmessage = function(e) {{
@@ -193,6 +217,16 @@ pub(crate) fn spawn(
                 run_in_worker(tests);
             }}
             "#,
+            forward = if threads > 1 {
+                r#"line => port.postMessage(["__wbgtest_report", line])"#
+            } else {
+                "undefined"
+            },
+            done = if threads > 1 {
+                r#"port.postMessage(["__wbgtest_done"]);"#
+            } else {
+                ""
+            },
         ));
 
         if matches!(
@@ -210,113 +244,171 @@ pub(crate) fn spawn(
         let worker_js_path = tmpdir.join(name);
         fs::write(worker_js_path, worker_script).context("failed to write JS file")?;
 
-        js_to_execute.push_str(&format!(
-            r#"
-            // Now that we've gotten to the point where JS is executing, update our
-            // status text as at this point we should be asynchronously fetching the
-            // Wasm module.
-            document.getElementById('output').textContent = "Loading Wasm module...\n";
-            {}
-
-            port.addEventListener("message", function(e) {{
-                // Checking the whether the message is from wasm_bindgen_test
-                if(
-                    e.data &&
-                    Array.isArray(e.data) &&
-                    e.data[0] &&
-                    typeof e.data[0] == "string" &&
-                    e.data[0].slice(0,10)=="__wbgtest_"
-                ) {{
-                    const method = e.data[0].slice(10);
-                    const args = e.data.slice(1);
-
-                    if (
-                        method == "log" || method == "error" ||
-                        method == "warn" || method == "info" ||
-                        method == "debug"
-                    ) {{
-                        // In non-headless mode, forward worker console output to the main
-                        // page's console so it appears in DevTools.
-                        if (!{headless}) {{
-                            console[method].apply(console, args[0]);
-                        }}
-                    }} else if (method == "output_append") {{
-                        const el = document.getElementById("output");
-                        el.textContent += args[0];
-                    }}
+        if threads > 1 {
+            // Only dedicated workers can be spawned more than once, see
+            // `rmain`. The results of all workers are aggregated here.
+            js_to_execute.push_str(&wbg_import_script);
+            js_to_execute.push_str(&format!(
+                r#"
+                document.getElementById('output').textContent = "Loading Wasm module...\n";
+
+                {RUN_PARALLEL}
+
+                // Runs `tests` in a worker, which reports back every line of
+                // output and `__wbgtest_done` once it's done.
+                function spawn(tests, report) {{
+                    return new Promise((resolve, reject) => {{
+                        const port = new Worker('worker.js', {{type: '{module_type}'}});
+                        port.onerror = function(e) {{
+                            reject(new Error('Worker error: ' + e.message));
+                        }};
+                        port.addEventListener("message", function(e) {{
+                            if (!Array.isArray(e.data)) {{
+                                return;
+                            }}
+                            const [method, ...args] = e.data;
+                            if (method == "__wbgtest_report") {{
+                                report(args[0]);
+                            }} else if (method == "__wbgtest_done") {{
+                                port.terminate();
+                                resolve();
+                            }} else if (method == "__wbgtest_output_append") {{
+                                document.getElementById("output").textContent += args[0];
+                            }} else if (!{headless} && typeof method == "string" && method.startsWith("__wbgtest_")) {{
+                                console[method.slice(10)].apply(console, args[0]);
+                            }}
+                        }});
+                        port.postMessage(tests);
+                    }});
                 }}
-            }});
 
-            async function main(test) {{
-                port.postMessage(test)
-            }}
+                async function main(tests) {{
+                    await init('./{module}_bg.wasm');
+                    const cx = new Context(false);
 
-            const tests = [];
-            "#,
-            {
-                let module = if test_mode.no_modules() {
+                    {args}
+
+                    await run_parallel(cx, tests, {threads}, spawn);
+                }}
+
+                const tests = [];
+                "#,
+                module_type = if test_mode.no_modules() {
                     "classic"
                 } else {
                     "module"
-                };
-
-                match test_mode {
-                    TestMode::DedicatedWorker { .. } => {
-                        format!(
-                            r#"const port = new Worker('worker.js', {{type: '{module}'}});
-                            port.onerror = function(e) {{
-                                console.error('Worker error:', e.message, e.filename, e.lineno);
-                                document.getElementById('output').textContent += '\nWorker error: ' + e.message;
-                            }};
-                            "#
-                        )
-                    }
-                    TestMode::SharedWorker { .. } => {
-                        format!(
-                            r#"
-                            const worker = new SharedWorker("worker.js?random=" + crypto.randomUUID(), {{type: "{module}"}});
-                            worker.onerror = function(e) {{
-                                console.error('Worker error:', e.message, e.filename, e.lineno);
-                                document.getElementById('output').textContent += '\nWorker error: ' + e.message;
-                            }};
-                            const port = worker.port;
-                            port.start();
-                            "#
-                        )
-                    }
-                    TestMode::ServiceWorker { .. } => {
-                        format!(
-                            r#"
-                            const url = "service.js?random=" + crypto.randomUUID();
-                            const registration = await navigator.serviceWorker.register(url, {{type: "{module}"}});
-                            if (registration.installing) {{
-                                registration.installing.onerror = function(e) {{
-                                    console.error('ServiceWorker error:', e.message);
-                                    document.getElementById('output').textContent += '\nServiceWorker error: ' + e.message;
-                                }};
+                },
+            ));
+        } else {
+            js_to_execute.push_str(&format!(
+                r#"
+                // Now that we've gotten to the point where JS is executing, update our
+                // status text as at this point we should be asynchronously fetching the
+                // Wasm module.
+                document.getElementById('output').textContent = "Loading Wasm module...\n";
+                {}
+
+                port.addEventListener("message", function(e) {{
+                    // Checking the whether the message is from wasm_bindgen_test
+                    if(
+                        e.data &&
+                        Array.isArray(e.data) &&
+                        e.data[0] &&
+                        typeof e.data[0] == "string" &&
+                        e.data[0].slice(0,10)=="__wbgtest_"
+                    ) {{
+                        const method = e.data[0].slice(10);
+                        const args = e.data.slice(1);
+
+                        if (
+                            method == "log" || method == "error" ||
+                            method == "warn" || method == "info" ||
+                            method == "debug"
+                        ) {{
+                            // In non-headless mode, forward worker console output to the main
+                            // page's console so it appears in DevTools.
+                            if (!{headless}) {{
+                                console[method].apply(console, args[0]);
                             }}
-                            await new Promise((resolve) => {{
-                                navigator.serviceWorker.addEventListener('controllerchange', () => {{
-                                    const expected_script_url = new URL(url, location.origin + location.pathname).href;
-                                    if (navigator.serviceWorker.controller.scriptURL != expected_script_url) {{
-                                        throw "`wasm-bindgen-test-runner` does not support running multiple service worker tests at the same time"
-                                    }}
-                                    resolve();
+                        }} else if (method == "output_append") {{
+                            const el = document.getElementById("output");
+                            el.textContent += args[0];
+                        }}
+                    }}
+                }});
+
+                async function main(test) {{
+                    port.postMessage(test)
+                }}
+
+                const tests = [];
+                "#,
+                {
+                    let module = if test_mode.no_modules() {
+                        "classic"
+                    } else {
+                        "module"
+                    };
+
+                    match test_mode {
+                        TestMode::DedicatedWorker { .. } => {
+                            format!(
+                                r#"const port = new Worker('worker.js', {{type: '{module}'}});
+                                port.onerror = function(e) {{
+                                    console.error('Worker error:', e.message, e.filename, e.lineno);
+                                    document.getElementById('output').textContent += '\nWorker error: ' + e.message;
+                                }};
+                                "#
+                            )
+                        }
+                        TestMode::SharedWorker { .. } => {
+                            format!(
+                                r#"
+                                const worker = new SharedWorker("worker.js?random=" + crypto.randomUUID(), {{type: "{module}"}});
+                                worker.onerror = function(e) {{
+                                    console.error('Worker error:', e.message, e.filename, e.lineno);
+                                    document.getElementById('output').textContent += '\nWorker error: ' + e.message;
+                                }};
+                                const port = worker.port;
+                                port.start();
+                                "#
+                            )
+                        }
+                        TestMode::ServiceWorker { .. } => {
+                            format!(
+                                r#"
+                                const url = "service.js?random=" + crypto.randomUUID();
+                                const registration = await navigator.serviceWorker.register(url, {{type: "{module}"}});
+                                if (registration.installing) {{
+                                    registration.installing.onerror = function(e) {{
+                                        console.error('ServiceWorker error:', e.message);
+                                        document.getElementById('output').textContent += '\nServiceWorker error: ' + e.message;
+                                    }};
+                                }}
+                                await new Promise((resolve) => {{
+                                    navigator.serviceWorker.addEventListener('controllerchange', () => {{
+                                        const expected_script_url = new URL(url, location.origin + location.pathname).href;
+                                        if (navigator.serviceWorker.controller.scriptURL != expected_script_url) {{
+                                            throw "`wasm-bindgen-test-runner` does not support running multiple service worker tests at the same time"
+                                        }}
+                                        resolve();
+                                    }});
                                 }});
-                            }});
-                            const channel = new MessageChannel();
-                            navigator.serviceWorker.controller.postMessage(undefined, [channel.port2]);
-                            const port = channel.port1;
-                            port.start();
-                            "#
-                        )
+                                const channel = new MessageChannel();
+                                navigator.serviceWorker.controller.postMessage(undefined, [channel.port2]);
+                                const port = channel.port1;
+                                port.start();
+                                "#
+                            )
+                        }
+                        _ => unreachable!(),
                     }
-                    _ => unreachable!(),
                 }
-            }
-        ));
+            ));
+        }
     } else {
         js_to_execute.push_str(&wbg_import_script);
+        js_to_execute.push_str(&run_tests);
 
         js_to_execute.push_str(&format!(
             r#"
@@ -325,23 +417,45 @@ pub(crate) fn spawn(
             // Wasm module.
             document.getElementById('output').textContent = "Loading Wasm module...\n";
 
+            // Set on the iframe of this page if it's a test thread of another
+            // page, see `spawn`.
+            const thread = window.frameElement && window.frameElement.__wbg_test_thread;
+
+            {RUN_PARALLEL}
+
+            // Runs `tests` in an iframe of this page, which reports back every
+            // line of output.
+            function spawn(tests, report) {{
+                return new Promise((resolve, reject) => {{
+                    const frame = document.createElement("iframe");
+                    frame.__wbg_test_thread = {{ tests, report, resolve, reject }};
+                    frame.style.cssText = "position:absolute;width:800px;height:600px;visibility:hidden";
+                    frame.src = location.pathname;
+                    document.body.appendChild(frame);
+                }});
+            }}
+
             async function main(test) {{
                 const wasm = await init('./{module}_bg.wasm');
 
-                const cx = new Context({is_bench});
-                window.on_console_debug = __wbgtest_console_debug;
-                window.on_console_log = __wbgtest_console_log;
-                window.on_console_info = __wbgtest_console_info;
-                window.on_console_warn = __wbgtest_console_warn;
-                window.on_console_error = __wbgtest_console_error;
-
-                {args}
+                const cx = new Context({is_bench}, thread ? thread.report : undefined);
+                set_console(bindings);
+                configure(cx);
 
                 if ({is_bench}) {{
                     {import_bench}
                 }}
 
-                await cx.run(test.map(s => wasm[s]));
+                if (thread) {{
+                    await run_tests(cx, test, [bindings, wasm], instantiate);
+                    return;
+                }}
+
+                if ({threads} > 1) {{
+                    await run_parallel(cx, test, {threads}, spawn);
+                }} else {{
+                    await run_tests(cx, test, [bindings, wasm], instantiate);
+                }}
                 {cov_dump}
 
                 if ({is_bench}) {{
@@ -356,7 +470,13 @@ pub(crate) fn spawn(
     for test in tests.tests {
         js_to_execute.push_str(&format!("tests.push('{}');\n", test.export));
     }
-    js_to_execute.push_str("main(tests);\n");
+    if test_mode.is_worker() {
+        js_to_execute.push_str("main(tests);\n");
+    } else {
+        js_to_execute.push_str(
+            "if (thread) {\n    main(thread.tests).then(thread.resolve, thread.reject);\n} else {\n    main(tests);\n}\n",
+        );
+    }
 
     let js_path = tmpdir.join("run.js");
     fs::write(&js_path, js_to_execute).context("failed to write JS file")?;
@@ -375,7 +495,8 @@ pub(crate) fn spawn(
                 include_str!("index.html")
             };
             let s = s.replace("// {NOCAPTURE}", &format!("const nocapture = {nocapture};"));
-            let s = if !test_mode.is_worker() && test_mode.no_modules() {
+            // Worker tests run on multiple threads are aggregated on the page.
+            let s = if (!test_mode.is_worker() || threads > 1) && test_mode.no_modules() {
                 s.replace(
                     "<!-- {IMPORT_SCRIPTS} -->",
                     &format!("<script src='{module}.js'></script>\n<script src='run.js'></script>"),
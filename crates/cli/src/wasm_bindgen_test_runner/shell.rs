@@ -4,17 +4,29 @@ use std::io::{self, IsTerminal, Write};
 
 pub struct Shell {
     is_tty: bool,
+    machine_readable: bool,
 }
 
 impl Shell {
-    pub fn new() -> Shell {
+    /// With `machine_readable` output, stdout is reserved for test results.
+    pub fn new(machine_readable: bool) -> Shell {
         Shell {
             is_tty: io::stdout().is_terminal(),
+            machine_readable,
+        }
+    }
+
+    /// Prints an informational message of the runner.
+    pub fn info(&self, s: &str) {
+        if self.machine_readable {
+            eprintln!("{s}");
+        } else {
+            println!("{s}");
         }
     }
 
     pub fn status(&self, s: &str) {
-        if !self.is_tty {
+        if !self.is_tty || self.machine_readable {
             return;
         }
         let s = if s.len() > WIDTH { &s[..WIDTH] } else { s };
@@ -0,0 +1,19 @@
+/* tslint:disable */
+/* eslint-disable */
+
+export class ParseError extends Error {
+    private constructor();
+    readonly kind: "Empty" | "InvalidDigit";
+}
+
+/**
+ * @param input
+ * @throws {ParseError}
+ */
+export function parse(input: string): number;
+
+/**
+ * @param input
+ * @throws {ParseError}
+ */
+export function parse_async(input: string): Promise<number>;
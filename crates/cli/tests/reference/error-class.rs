@@ -0,0 +1,30 @@
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(error)]
+#[derive(Debug)]
+pub enum ParseError {
+    Empty,
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => f.write_str("empty input"),
+            ParseError::InvalidDigit(c) => write!(f, "invalid digit `{c}`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[wasm_bindgen]
+pub fn parse(input: &str) -> Result<u32, ParseError> {
+    input.parse().map_err(|_| ParseError::Empty)
+}
+
+#[wasm_bindgen]
+pub async fn parse_async(input: String) -> Result<u32, ParseError> {
+    parse(&input)
+}
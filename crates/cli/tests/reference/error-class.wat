@@ -0,0 +1,73 @@
+(module $error_class_reftest.wasm
+  (type (;0;) (func))
+  (type (;1;) (func (result i32)))
+  (type (;2;) (func (param i32)))
+  (type (;3;) (func (param i32 i32)))
+  (type (;4;) (func (param i32 i32) (result i32)))
+  (type (;5;) (func (param i32 i32) (result i32 i32 i32)))
+  (type (;6;) (func (param i32 i32) (result externref)))
+  (type (;7;) (func (param i32 i32 i32 i32) (result i32)))
+  (type (;8;) (func (param i32 i32 externref) (result i32 i32)))
+  (type (;9;) (func (param i32 i32 externref externref)))
+  (type (;10;) (func (param i32 externref)))
+  (type (;11;) (func (param f64) (result externref)))
+  (type (;12;) (func (param externref)))
+  (type (;13;) (func (param externref) (result i32)))
+  (type (;14;) (func (param externref) (result externref)))
+  (type (;15;) (func (param externref i32 i32) (result i32)))
+  (type (;16;) (func (param externref i32 i32 externref)))
+  (type (;17;) (func (param externref externref) (result externref)))
+  (type (;18;) (func (param externref externref externref) (result externref)))
+  (import "./reference_test_bg.js" "__wbg_Error_83742b46f01ce22d" (func (;0;) (type 6)))
+  (import "./reference_test_bg.js" "__wbg___wbindgen_debug_string_5398f5bb970e0daa" (func (;1;) (type 10)))
+  (import "./reference_test_bg.js" "__wbg___wbindgen_is_function_3c846841762788c1" (func (;2;) (type 13)))
+  (import "./reference_test_bg.js" "__wbg___wbindgen_is_undefined_52709e72fb9f179c" (func (;3;) (type 13)))
+  (import "./reference_test_bg.js" "__wbg___wbindgen_throw_6ddd609b62940d55" (func (;4;) (type 3)))
+  (import "./reference_test_bg.js" "__wbg__wbg_cb_unref_6b5b6b8576d35cb1" (func (;5;) (type 12)))
+  (import "./reference_test_bg.js" "__wbg_call_2d781c1f4d5c0ef8" (func (;6;) (type 18)))
+  (import "./reference_test_bg.js" "__wbg_createTask_6eb3a8b6dd2f87c9" (func (;7;) (type 6)))
+  (import "./reference_test_bg.js" "__wbg_new_typed_aaaeaf29cf802876" (func (;8;) (type 6)))
+  (import "./reference_test_bg.js" "__wbg_parseerror_error_new" (func (;9;) (type 18)))
+  (import "./reference_test_bg.js" "__wbg_queueMicrotask_0c399741342fb10f" (func (;10;) (type 14)))
+  (import "./reference_test_bg.js" "__wbg_queueMicrotask_a082d78ce798393e" (func (;11;) (type 12)))
+  (import "./reference_test_bg.js" "__wbg_resolve_ae8d83246e5bcc12" (func (;12;) (type 14)))
+  (import "./reference_test_bg.js" "__wbg_run_78b7b601add6ed6b" (func (;13;) (type 15)))
+  (import "./reference_test_bg.js" "__wbg_set_2a15ba699ce394ce" (func (;14;) (type 16)))
+  (import "./reference_test_bg.js" "__wbg_static_accessor_GLOBAL_8adb955bd33fac2f" (func (;15;) (type 1)))
+  (import "./reference_test_bg.js" "__wbg_static_accessor_GLOBAL_THIS_ad356e0db91c7913" (func (;16;) (type 1)))
+  (import "./reference_test_bg.js" "__wbg_static_accessor_SELF_f207c857566db248" (func (;17;) (type 1)))
+  (import "./reference_test_bg.js" "__wbg_static_accessor_WINDOW_bb9f1ba69d61b386" (func (;18;) (type 1)))
+  (import "./reference_test_bg.js" "__wbg_then_098abe61755d12f6" (func (;19;) (type 17)))
+  (import "./reference_test_bg.js" "__wbindgen_cast_0000000000000000" (func (;20;) (type 6)))
+  (import "./reference_test_bg.js" "__wbindgen_cast_0000000000000001" (func (;21;) (type 11)))
+  (import "./reference_test_bg.js" "__wbindgen_cast_0000000000000002" (func (;22;) (type 6)))
+  (import "./reference_test_bg.js" "__wbindgen_init_externref_table" (func (;23;) (type 0)))
+  (table $__wbindgen_externrefs (;0;) 1024 externref)
+  (memory (;0;) 17)
+  (export "memory" (memory 0))
+  (export "parse" (func $"parse multivalue shim"))
+  (export "parse_async" (func $"parse_async externref shim"))
+  (export "wasm_bindgen__convert__closures_____invoke__h0000000000000005" (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000005 externref shim multivalue shim"))
+  (export "wasm_bindgen__convert__closures_____invoke__h0000000000000003" (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000003 externref shim"))
+  (export "wasm_bindgen__convert__closures_____invoke__h0000000000000004" (func $wasm_bindgen::convert::closures::_::invoke::h0000000000000004))
+  (export "__wbindgen_malloc" (func $__wbindgen_malloc))
+  (export "__wbindgen_realloc" (func $__wbindgen_realloc))
+  (export "__wbindgen_exn_store" (func $__wbindgen_exn_store))
+  (export "__externref_table_alloc" (func $__externref_table_alloc))
+  (export "__wbindgen_externrefs" (table $__wbindgen_externrefs))
+  (export "__wbindgen_destroy_closure" (func $__wbindgen_destroy_closure))
+  (export "__externref_table_dealloc" (func $__externref_table_dealloc))
+  (export "__wbindgen_start" (func 23))
+  (func $"parse multivalue shim" (;24;) (type 5) (param i32 i32) (result i32 i32 i32))
+  (func $"parse_async externref shim" (;25;) (type 6) (param i32 i32) (result externref))
+  (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000005 externref shim multivalue shim" (;26;) (type 8) (param i32 i32 externref) (result i32 i32))
+  (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000003 externref shim" (;27;) (type 9) (param i32 i32 externref externref))
+  (func $wasm_bindgen::convert::closures::_::invoke::h0000000000000004 (;28;) (type 4) (param i32 i32) (result i32))
+  (func $__wbindgen_malloc (;29;) (type 4) (param i32 i32) (result i32))
+  (func $__wbindgen_realloc (;30;) (type 7) (param i32 i32 i32 i32) (result i32))
+  (func $__wbindgen_exn_store (;31;) (type 2) (param i32))
+  (func $__externref_table_alloc (;32;) (type 1) (result i32))
+  (func $__wbindgen_destroy_closure (;33;) (type 3) (param i32 i32))
+  (func $__externref_table_dealloc (;34;) (type 2) (param i32))
+  (@custom "target_features" (after code) "\08+\0bbulk-memory+\0fbulk-memory-opt+\16call-indirect-overlong+\0amultivalue+\0fmutable-globals+\13nontrapping-fptoint+\0freference-types+\08sign-ext")
+)
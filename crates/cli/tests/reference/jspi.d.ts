@@ -0,0 +1,11 @@
+/* tslint:disable */
+/* eslint-disable */
+
+export class Counter {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    tick(): Promise<number>;
+}
+
+export function word_count(url: string): Promise<number>;
@@ -0,0 +1,28 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(suspending, js_name = fetchText)]
+    fn fetch_text(url: &str) -> String;
+
+    #[wasm_bindgen(suspending, catch)]
+    fn sleep(ms: u32) -> Result<(), JsValue>;
+}
+
+#[wasm_bindgen(promising)]
+pub fn word_count(url: &str) -> usize {
+    fetch_text(url).split_whitespace().count()
+}
+
+#[wasm_bindgen]
+pub struct Counter(u32);
+
+#[wasm_bindgen]
+impl Counter {
+    #[wasm_bindgen(promising)]
+    pub fn tick(&mut self) -> Result<u32, JsValue> {
+        sleep(10)?;
+        self.0 += 1;
+        Ok(self.0)
+    }
+}
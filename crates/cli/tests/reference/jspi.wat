@@ -0,0 +1,36 @@
+(module $jspi_reftest.wasm
+  (type (;0;) (func))
+  (type (;1;) (func (result i32)))
+  (type (;2;) (func (param i32)))
+  (type (;3;) (func (param i32) (result i32 i32 i32)))
+  (type (;4;) (func (param i32 i32)))
+  (type (;5;) (func (param i32 i32) (result i32)))
+  (type (;6;) (func (param i32 i32 i32)))
+  (type (;7;) (func (param i32 i32 i32 i32) (result i32)))
+  (import "./reference_test_bg.js" "__wbg___wbindgen_throw_6ddd609b62940d55" (func (;0;) (type 4)))
+  (import "./reference_test_bg.js" "__wbg_fetchText_dcb3b381d1071ad7" (func (;1;) (type 6)))
+  (import "./reference_test_bg.js" "__wbg_sleep_8b266e9e6222cc70" (func (;2;) (type 2)))
+  (import "./reference_test_bg.js" "__wbindgen_init_externref_table" (func (;3;) (type 0)))
+  (table $__wbindgen_externrefs (;0;) 1024 externref)
+  (memory (;0;) 17)
+  (export "memory" (memory 0))
+  (export "__wbg_counter_free" (func $__wbg_counter_free))
+  (export "counter_tick" (func $"counter_tick multivalue shim"))
+  (export "word_count" (func $word_count))
+  (export "__wbindgen_malloc" (func $__wbindgen_malloc))
+  (export "__wbindgen_realloc" (func $__wbindgen_realloc))
+  (export "__wbindgen_exn_store" (func $__wbindgen_exn_store))
+  (export "__externref_table_alloc" (func $__externref_table_alloc))
+  (export "__wbindgen_externrefs" (table $__wbindgen_externrefs))
+  (export "__externref_table_dealloc" (func $__externref_table_dealloc))
+  (export "__wbindgen_start" (func 3))
+  (func $__wbg_counter_free (;4;) (type 4) (param i32 i32))
+  (func $"counter_tick multivalue shim" (;5;) (type 3) (param i32) (result i32 i32 i32))
+  (func $word_count (;6;) (type 5) (param i32 i32) (result i32))
+  (func $__wbindgen_malloc (;7;) (type 5) (param i32 i32) (result i32))
+  (func $__wbindgen_realloc (;8;) (type 7) (param i32 i32 i32 i32) (result i32))
+  (func $__wbindgen_exn_store (;9;) (type 2) (param i32))
+  (func $__externref_table_alloc (;10;) (type 1) (result i32))
+  (func $__externref_table_dealloc (;11;) (type 2) (param i32))
+  (@custom "target_features" (after code) "\08+\0bbulk-memory+\0fbulk-memory-opt+\16call-indirect-overlong+\0amultivalue+\0fmutable-globals+\13nontrapping-fptoint+\0freference-types+\08sign-ext")
+)
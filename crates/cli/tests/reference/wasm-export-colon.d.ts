@@ -14,6 +14,17 @@ export class WasmBindgenTestContext {
      * Handle filter argument.
      */
     filtered_count(filtered: number): void;
+    /**
+     * Finishes a test suite started with `start`, printing the results of
+     * all reported tests.
+     *
+     * Returns `true` if all tests passed.
+     */
+    finish(): boolean;
+    /**
+     * Handle `--format` flag.
+     */
+    format(format: string): void;
     /**
      * Handle `--include-ignored` flag.
      */
@@ -24,8 +35,21 @@ export class WasmBindgenTestContext {
      * A `Context` is the main structure through which test execution is
      * coordinated, and this will collect output and results for all executed
      * tests.
+     *
+     * If `forward` is given, all output is passed to it instead of being
+     * printed, to be aggregated by the `Context` of the main thread with
+     * `report`. This is used by `--test-threads` to run tests in workers.
      */
-    constructor(is_bench: boolean);
+    constructor(is_bench: boolean, forward?: Function | null);
+    /**
+     * Records a line of output forwarded by the context of another thread.
+     */
+    report(line: string): void;
+    /**
+     * Records an error of another thread which prevented some of its tests
+     * from reporting their result, e.g. because it crashed.
+     */
+    report_error(error: any): void;
     /**
      * Executes a list of tests, returning a promise representing their
      * eventual completion.
@@ -38,6 +62,11 @@ export class WasmBindgenTestContext {
      * `false` if at least one test failed.
      */
     run(tests: any[]): Promise<any>;
+    /**
+     * Starts a test suite whose tests are executed by the contexts of other
+     * threads, which forward their output to `report`.
+     */
+    start(test_count: number): void;
 }
 
 /**
@@ -106,13 +135,19 @@ export interface InitOutput {
     readonly __wbgtest_coverage_path: (a: number, b: number, c: number, d: number, e: number, f: bigint) => [number, number];
     readonly __wbgtest_module_signature: () => [number, bigint];
     readonly wasmbindgentestcontext_filtered_count: (a: number, b: number) => void;
+    readonly wasmbindgentestcontext_finish: (a: number) => number;
+    readonly wasmbindgentestcontext_format: (a: number, b: number, c: number) => void;
     readonly wasmbindgentestcontext_include_ignored: (a: number, b: number) => void;
-    readonly wasmbindgentestcontext_new: (a: number) => number;
+    readonly wasmbindgentestcontext_new: (a: number, b: number) => number;
+    readonly wasmbindgentestcontext_report: (a: number, b: number, c: number) => void;
+    readonly wasmbindgentestcontext_report_error: (a: number, b: any) => void;
     readonly wasmbindgentestcontext_run: (a: number, b: number, c: number) => any;
+    readonly wasmbindgentestcontext_start: (a: number, b: number) => void;
     readonly wasm_bindgen__convert__closures_____invoke__h0000000000000004: (a: number, b: number, c: any, d: number, e: any) => void;
-    readonly wasm_bindgen__convert__closures_____invoke__h0000000000000007: (a: number, b: number, c: any) => [number, number];
+    readonly wasm_bindgen__convert__closures_____invoke__h0000000000000008: (a: number, b: number, c: any) => [number, number];
     readonly wasm_bindgen__convert__closures_____invoke__h0000000000000005: (a: number, b: number, c: any, d: any) => void;
-    readonly wasm_bindgen__convert__closures_____invoke__h0000000000000006: (a: number, b: number) => number;
+    readonly wasm_bindgen__convert__closures_____invoke__h0000000000000006: (a: number, b: number, c: any, d: any) => void;
+    readonly wasm_bindgen__convert__closures_____invoke__h0000000000000007: (a: number, b: number) => number;
     readonly wasm_bindgen__convert__closures_____invoke__h0000000000000003: (a: number, b: number) => void;
     readonly __externref_table_alloc: () => number;
     readonly __wbindgen_externrefs: WebAssembly.Table;
@@ -24,6 +24,26 @@ export class WasmBindgenTestContext {
     filtered_count(filtered) {
         wasm.wasmbindgentestcontext_filtered_count(this.__wbg_ptr, filtered);
     }
+    /**
+     * Finishes a test suite started with `start`, printing the results of
+     * all reported tests.
+     *
+     * Returns `true` if all tests passed.
+     * @returns {boolean}
+     */
+    finish() {
+        const ret = wasm.wasmbindgentestcontext_finish(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * Handle `--format` flag.
+     * @param {string} format
+     */
+    format(format) {
+        const ptr0 = passStringToWasm0(format, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.wasmbindgentestcontext_format(this.__wbg_ptr, ptr0, len0);
+    }
     /**
      * Handle `--include-ignored` flag.
      * @param {boolean} include_ignored
@@ -37,14 +57,36 @@ export class WasmBindgenTestContext {
      * A `Context` is the main structure through which test execution is
      * coordinated, and this will collect output and results for all executed
      * tests.
+     *
+     * If `forward` is given, all output is passed to it instead of being
+     * printed, to be aggregated by the `Context` of the main thread with
+     * `report`. This is used by `--test-threads` to run tests in workers.
      * @param {boolean} is_bench
+     * @param {Function | null} [forward]
      */
-    constructor(is_bench) {
-        const ret = wasm.wasmbindgentestcontext_new(is_bench);
+    constructor(is_bench, forward) {
+        const ret = wasm.wasmbindgentestcontext_new(is_bench, isLikeNone(forward) ? 0 : addToExternrefTable0(forward));
         this.__wbg_ptr = ret >>> 0;
         WasmBindgenTestContextFinalization.register(this, this.__wbg_ptr, this);
         return this;
     }
+    /**
+     * Records a line of output forwarded by the context of another thread.
+     * @param {string} line
+     */
+    report(line) {
+        const ptr0 = passStringToWasm0(line, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.wasmbindgentestcontext_report(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * Records an error of another thread which prevented some of its tests
+     * from reporting their result, e.g. because it crashed.
+     * @param {any} error
+     */
+    report_error(error) {
+        wasm.wasmbindgentestcontext_report_error(this.__wbg_ptr, error);
+    }
     /**
      * Executes a list of tests, returning a promise representing their
      * eventual completion.
@@ -64,6 +106,14 @@ export class WasmBindgenTestContext {
         const ret = wasm.wasmbindgentestcontext_run(this.__wbg_ptr, ptr0, len0);
         return ret;
     }
+    /**
+     * Starts a test suite whose tests are executed by the contexts of other
+     * threads, which forward their output to `report`.
+     * @param {number} test_count
+     */
+    start(test_count) {
+        wasm.wasmbindgentestcontext_start(this.__wbg_ptr, test_count);
+    }
 }
 if (Symbol.dispose) WasmBindgenTestContext.prototype[Symbol.dispose] = WasmBindgenTestContext.prototype.free;
 
@@ -185,10 +235,18 @@ export function __wbgtest_module_signature() {
 function __wbg_get_imports() {
     const import0 = {
         __proto__: null,
+        __wbg_Bun_e344f4533b5a0d1e: function(arg0) {
+            const ret = arg0.Bun;
+            return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
+        },
         __wbg_Deno_d54bf9e1c1375dc6: function(arg0) {
             const ret = arg0.Deno;
             return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
         },
+        __wbg_Error_83742b46f01ce22d: function(arg0, arg1) {
+            const ret = Error(getStringFromWasm0(arg0, arg1));
+            return ret;
+        },
         __wbg_String_e7b531075cd5ce86: function(arg0, arg1) {
             const ret = String(arg1);
             const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
@@ -252,6 +310,9 @@ function __wbg_get_imports() {
             const ret = arg0.call(arg1, arg2);
             return ret;
         }, arguments); },
+        __wbg_clearTimeout_6531ff0898b3f36d: function(arg0) {
+            clearTimeout(arg0);
+        },
         __wbg_constructor_a0e17f62c53312ec: function(arg0) {
             const ret = arg0.constructor;
             return ret;
@@ -306,7 +367,7 @@ function __wbg_get_imports() {
             const ret = new Error();
             return ret;
         },
-        __wbg_new_typed_aaaeaf29cf802876: function(arg0, arg1) {
+        __wbg_new_d098e265629cd10f: function(arg0, arg1) {
             try {
                 var state0 = {a: arg0, b: arg1};
                 var cb0 = (arg0, arg1) => {
@@ -324,6 +385,24 @@ function __wbg_get_imports() {
                 state0.a = 0;
             }
         },
+        __wbg_new_typed_aaaeaf29cf802876: function(arg0, arg1) {
+            try {
+                var state0 = {a: arg0, b: arg1};
+                var cb0 = (arg0, arg1) => {
+                    const a = state0.a;
+                    state0.a = 0;
+                    try {
+                        return wasm_bindgen__convert__closures_____invoke__h0000000000000006(a, state0.b, arg0, arg1);
+                    } finally {
+                        state0.a = a;
+                    }
+                };
+                const ret = new Promise(cb0);
+                return ret;
+            } finally {
+                state0.a = 0;
+            }
+        },
         __wbg_now_bededbf0fc26550a: function(arg0) {
             const ret = arg0.now();
             return ret;
@@ -350,7 +429,7 @@ function __wbg_get_imports() {
                     const a = state0.a;
                     state0.a = 0;
                     try {
-                        return wasm_bindgen__convert__closures_____invoke__h0000000000000006(a, state0.b, );
+                        return wasm_bindgen__convert__closures_____invoke__h0000000000000007(a, state0.b, );
                     } finally {
                         state0.a = a;
                     }
@@ -365,6 +444,10 @@ function __wbg_get_imports() {
             const ret = arg0.self;
             return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
         },
+        __wbg_setTimeout_205a9e5dc88beae7: function(arg0, arg1) {
+            const ret = setTimeout(arg0, arg1 >>> 0);
+            return ret;
+        },
         __wbg_set_text_content_53a918c21c5b6d7b: function(arg0, arg1, arg2) {
             arg0.textContent = getStringFromWasm0(arg1, arg2);
         },
@@ -402,6 +485,10 @@ function __wbg_get_imports() {
             const ret = typeof globalThis === 'undefined' ? null : globalThis;
             return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
         },
+        __wbg_static_accessor_ON_FAILURE_96dca11623aafa8f: function() {
+            const ret = typeof __wbg_test_on_failure === 'undefined' ? null : __wbg_test_on_failure;
+            return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
+        },
         __wbg_static_accessor_SELF_f207c857566db248: function() {
             const ret = typeof self === 'undefined' ? null : self;
             return isLikeNone(ret) ? 0 : addToExternrefTable0(ret);
@@ -421,6 +508,10 @@ function __wbg_get_imports() {
             const ret = arg0.then(arg1);
             return ret;
         },
+        __wbg_then_9e335f6dd892bc11: function(arg0, arg1, arg2) {
+            const ret = arg0.then(arg1, arg2);
+            return ret;
+        },
         __wbg_toString_5d57325a72a29da1: function() { return handleError(function (arg0, arg1) {
             const ret = arg1.toString();
             const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
@@ -429,8 +520,8 @@ function __wbg_get_imports() {
             getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
         }, arguments); },
         __wbindgen_cast_0000000000000000: function(arg0, arg1) {
-            // Cast intrinsic for `Closure(Closure { owned: true, function: Function { arguments: [Externref], shim_idx: 8, ret: Result(Unit), inner_ret: Some(Result(Unit)) }, mutable: true }) -> Externref`.
-            const ret = makeMutClosure(arg0, arg1, wasm_bindgen__convert__closures_____invoke__h0000000000000007);
+            // Cast intrinsic for `Closure(Closure { owned: true, function: Function { arguments: [Externref], shim_idx: 9, ret: Result(Unit), inner_ret: Some(Result(Unit)) }, mutable: true }) -> Externref`.
+            const ret = makeMutClosure(arg0, arg1, wasm_bindgen__convert__closures_____invoke__h0000000000000008);
             return ret;
         },
         __wbindgen_cast_0000000000000001: function(arg0) {
@@ -463,13 +554,13 @@ function wasm_bindgen__convert__closures_____invoke__h0000000000000003(arg0, arg
     wasm.wasm_bindgen__convert__closures_____invoke__h0000000000000003(arg0, arg1);
 }
 
-function wasm_bindgen__convert__closures_____invoke__h0000000000000006(arg0, arg1) {
-    const ret = wasm.wasm_bindgen__convert__closures_____invoke__h0000000000000006(arg0, arg1);
+function wasm_bindgen__convert__closures_____invoke__h0000000000000007(arg0, arg1) {
+    const ret = wasm.wasm_bindgen__convert__closures_____invoke__h0000000000000007(arg0, arg1);
     return ret !== 0;
 }
 
-function wasm_bindgen__convert__closures_____invoke__h0000000000000007(arg0, arg1, arg2) {
-    const ret = wasm.wasm_bindgen__convert__closures_____invoke__h0000000000000007(arg0, arg1, arg2);
+function wasm_bindgen__convert__closures_____invoke__h0000000000000008(arg0, arg1, arg2) {
+    const ret = wasm.wasm_bindgen__convert__closures_____invoke__h0000000000000008(arg0, arg1, arg2);
     if (ret[1]) {
         throw takeFromExternrefTable0(ret[0]);
     }
@@ -479,6 +570,10 @@ function wasm_bindgen__convert__closures_____invoke__h0000000000000005(arg0, arg
     wasm.wasm_bindgen__convert__closures_____invoke__h0000000000000005(arg0, arg1, arg2, arg3);
 }
 
+function wasm_bindgen__convert__closures_____invoke__h0000000000000006(arg0, arg1, arg2, arg3) {
+    wasm.wasm_bindgen__convert__closures_____invoke__h0000000000000006(arg0, arg1, arg2, arg3);
+}
+
 function wasm_bindgen__convert__closures_____invoke__h0000000000000004(arg0, arg1, arg2, arg3, arg4) {
     wasm.wasm_bindgen__convert__closures_____invoke__h0000000000000004(arg0, arg1, arg2, arg3, arg4);
 }
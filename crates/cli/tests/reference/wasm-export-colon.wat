@@ -22,58 +22,66 @@
   (type (;20;) (func (param externref) (result i32)))
   (type (;21;) (func (param externref) (result f64)))
   (type (;22;) (func (param externref) (result externref)))
-  (type (;23;) (func (param externref i32 i32)))
-  (type (;24;) (func (param externref i32 i32) (result i32)))
-  (type (;25;) (func (param externref i32 i32) (result externref)))
-  (type (;26;) (func (param externref externref) (result externref)))
-  (type (;27;) (func (param externref externref externref) (result externref)))
-  (import "./reference_test_bg.js" "__wbg_Deno_d54bf9e1c1375dc6" (func (;0;) (type 20)))
-  (import "./reference_test_bg.js" "__wbg_String_e7b531075cd5ce86" (func (;1;) (type 17)))
-  (import "./reference_test_bg.js" "__wbg___wbg_test_invoke_1a559f32546cebec" (func (;2;) (type 7)))
-  (import "./reference_test_bg.js" "__wbg___wbg_test_output_writeln_34f10cba65c3d2e9" (func (;3;) (type 19)))
-  (import "./reference_test_bg.js" "__wbg___wbgtest_og_console_log_0eaefd0ba66f70be" (func (;4;) (type 7)))
-  (import "./reference_test_bg.js" "__wbg___wbindgen_debug_string_5398f5bb970e0daa" (func (;5;) (type 17)))
-  (import "./reference_test_bg.js" "__wbg___wbindgen_is_function_3c846841762788c1" (func (;6;) (type 20)))
-  (import "./reference_test_bg.js" "__wbg___wbindgen_is_undefined_52709e72fb9f179c" (func (;7;) (type 20)))
-  (import "./reference_test_bg.js" "__wbg___wbindgen_string_get_395e606bd0ee4427" (func (;8;) (type 17)))
-  (import "./reference_test_bg.js" "__wbg___wbindgen_throw_6ddd609b62940d55" (func (;9;) (type 7)))
-  (import "./reference_test_bg.js" "__wbg__wbg_cb_unref_6b5b6b8576d35cb1" (func (;10;) (type 19)))
-  (import "./reference_test_bg.js" "__wbg_call_2d781c1f4d5c0ef8" (func (;11;) (type 27)))
-  (import "./reference_test_bg.js" "__wbg_constructor_a0e17f62c53312ec" (func (;12;) (type 22)))
-  (import "./reference_test_bg.js" "__wbg_createTask_6eb3a8b6dd2f87c9" (func (;13;) (type 9)))
-  (import "./reference_test_bg.js" "__wbg_error_7af4a02e10118b81" (func (;14;) (type 7)))
-  (import "./reference_test_bg.js" "__wbg_forEach_a2c08d9c3cc0524c" (func (;15;) (type 23)))
-  (import "./reference_test_bg.js" "__wbg_getElementById_c7ce907969867235" (func (;16;) (type 25)))
-  (import "./reference_test_bg.js" "__wbg_log_ba47e422fd2aea35" (func (;17;) (type 7)))
-  (import "./reference_test_bg.js" "__wbg_message_00d63f20c41713dd" (func (;18;) (type 22)))
-  (import "./reference_test_bg.js" "__wbg_name_a0434ee983d3c204" (func (;19;) (type 17)))
-  (import "./reference_test_bg.js" "__wbg_name_ecf53d5e050a495d" (func (;20;) (type 22)))
-  (import "./reference_test_bg.js" "__wbg_new_3b89a8aabb976b21" (func (;21;) (type 4)))
-  (import "./reference_test_bg.js" "__wbg_new_typed_aaaeaf29cf802876" (func (;22;) (type 9)))
-  (import "./reference_test_bg.js" "__wbg_now_bededbf0fc26550a" (func (;23;) (type 21)))
-  (import "./reference_test_bg.js" "__wbg_performance_b046e73c47617524" (func (;24;) (type 22)))
-  (import "./reference_test_bg.js" "__wbg_queueMicrotask_0c399741342fb10f" (func (;25;) (type 22)))
-  (import "./reference_test_bg.js" "__wbg_queueMicrotask_a082d78ce798393e" (func (;26;) (type 19)))
-  (import "./reference_test_bg.js" "__wbg_resolve_ae8d83246e5bcc12" (func (;27;) (type 22)))
-  (import "./reference_test_bg.js" "__wbg_run_78b7b601add6ed6b" (func (;28;) (type 24)))
-  (import "./reference_test_bg.js" "__wbg_self_81ecf3f64cd5499b" (func (;29;) (type 20)))
-  (import "./reference_test_bg.js" "__wbg_set_text_content_53a918c21c5b6d7b" (func (;30;) (type 23)))
-  (import "./reference_test_bg.js" "__wbg_stack_4937ea091ccee211" (func (;31;) (type 17)))
-  (import "./reference_test_bg.js" "__wbg_stack_7d0113fc30ab59f5" (func (;32;) (type 17)))
-  (import "./reference_test_bg.js" "__wbg_stack_c5219bcda5d316b0" (func (;33;) (type 22)))
-  (import "./reference_test_bg.js" "__wbg_stack_f43e5bf3710666bf" (func (;34;) (type 22)))
-  (import "./reference_test_bg.js" "__wbg_static_accessor_DOCUMENT_5687ccb6ab0b4b55" (func (;35;) (type 4)))
-  (import "./reference_test_bg.js" "__wbg_static_accessor_GLOBAL_8adb955bd33fac2f" (func (;36;) (type 1)))
-  (import "./reference_test_bg.js" "__wbg_static_accessor_GLOBAL_THIS_ad356e0db91c7913" (func (;37;) (type 1)))
-  (import "./reference_test_bg.js" "__wbg_static_accessor_SELF_f207c857566db248" (func (;38;) (type 1)))
-  (import "./reference_test_bg.js" "__wbg_static_accessor_WINDOW_bb9f1ba69d61b386" (func (;39;) (type 1)))
-  (import "./reference_test_bg.js" "__wbg_text_content_d8e042bed66aa8bd" (func (;40;) (type 17)))
-  (import "./reference_test_bg.js" "__wbg_then_098abe61755d12f6" (func (;41;) (type 26)))
-  (import "./reference_test_bg.js" "__wbg_toString_5d57325a72a29da1" (func (;42;) (type 17)))
-  (import "./reference_test_bg.js" "__wbindgen_cast_0000000000000000" (func (;43;) (type 9)))
-  (import "./reference_test_bg.js" "__wbindgen_cast_0000000000000001" (func (;44;) (type 18)))
-  (import "./reference_test_bg.js" "__wbindgen_cast_0000000000000002" (func (;45;) (type 9)))
-  (import "./reference_test_bg.js" "__wbindgen_init_externref_table" (func (;46;) (type 0)))
+  (type (;23;) (func (param externref i32) (result externref)))
+  (type (;24;) (func (param externref i32 i32)))
+  (type (;25;) (func (param externref i32 i32) (result i32)))
+  (type (;26;) (func (param externref i32 i32) (result externref)))
+  (type (;27;) (func (param externref externref) (result externref)))
+  (type (;28;) (func (param externref externref externref) (result externref)))
+  (import "./reference_test_bg.js" "__wbg_Bun_e344f4533b5a0d1e" (func (;0;) (type 20)))
+  (import "./reference_test_bg.js" "__wbg_Deno_d54bf9e1c1375dc6" (func (;1;) (type 20)))
+  (import "./reference_test_bg.js" "__wbg_Error_83742b46f01ce22d" (func (;2;) (type 9)))
+  (import "./reference_test_bg.js" "__wbg_String_e7b531075cd5ce86" (func (;3;) (type 17)))
+  (import "./reference_test_bg.js" "__wbg___wbg_test_invoke_1a559f32546cebec" (func (;4;) (type 7)))
+  (import "./reference_test_bg.js" "__wbg___wbg_test_output_writeln_34f10cba65c3d2e9" (func (;5;) (type 19)))
+  (import "./reference_test_bg.js" "__wbg___wbgtest_og_console_log_0eaefd0ba66f70be" (func (;6;) (type 7)))
+  (import "./reference_test_bg.js" "__wbg___wbindgen_debug_string_5398f5bb970e0daa" (func (;7;) (type 17)))
+  (import "./reference_test_bg.js" "__wbg___wbindgen_is_function_3c846841762788c1" (func (;8;) (type 20)))
+  (import "./reference_test_bg.js" "__wbg___wbindgen_is_undefined_52709e72fb9f179c" (func (;9;) (type 20)))
+  (import "./reference_test_bg.js" "__wbg___wbindgen_string_get_395e606bd0ee4427" (func (;10;) (type 17)))
+  (import "./reference_test_bg.js" "__wbg___wbindgen_throw_6ddd609b62940d55" (func (;11;) (type 7)))
+  (import "./reference_test_bg.js" "__wbg__wbg_cb_unref_6b5b6b8576d35cb1" (func (;12;) (type 19)))
+  (import "./reference_test_bg.js" "__wbg_call_2d781c1f4d5c0ef8" (func (;13;) (type 28)))
+  (import "./reference_test_bg.js" "__wbg_clearTimeout_6531ff0898b3f36d" (func (;14;) (type 19)))
+  (import "./reference_test_bg.js" "__wbg_constructor_a0e17f62c53312ec" (func (;15;) (type 22)))
+  (import "./reference_test_bg.js" "__wbg_createTask_6eb3a8b6dd2f87c9" (func (;16;) (type 9)))
+  (import "./reference_test_bg.js" "__wbg_error_7af4a02e10118b81" (func (;17;) (type 7)))
+  (import "./reference_test_bg.js" "__wbg_forEach_a2c08d9c3cc0524c" (func (;18;) (type 24)))
+  (import "./reference_test_bg.js" "__wbg_getElementById_c7ce907969867235" (func (;19;) (type 26)))
+  (import "./reference_test_bg.js" "__wbg_log_ba47e422fd2aea35" (func (;20;) (type 7)))
+  (import "./reference_test_bg.js" "__wbg_message_00d63f20c41713dd" (func (;21;) (type 22)))
+  (import "./reference_test_bg.js" "__wbg_name_a0434ee983d3c204" (func (;22;) (type 17)))
+  (import "./reference_test_bg.js" "__wbg_name_ecf53d5e050a495d" (func (;23;) (type 22)))
+  (import "./reference_test_bg.js" "__wbg_new_3b89a8aabb976b21" (func (;24;) (type 4)))
+  (import "./reference_test_bg.js" "__wbg_new_d098e265629cd10f" (func (;25;) (type 9)))
+  (import "./reference_test_bg.js" "__wbg_new_typed_aaaeaf29cf802876" (func (;26;) (type 9)))
+  (import "./reference_test_bg.js" "__wbg_now_bededbf0fc26550a" (func (;27;) (type 21)))
+  (import "./reference_test_bg.js" "__wbg_performance_b046e73c47617524" (func (;28;) (type 22)))
+  (import "./reference_test_bg.js" "__wbg_queueMicrotask_0c399741342fb10f" (func (;29;) (type 22)))
+  (import "./reference_test_bg.js" "__wbg_queueMicrotask_a082d78ce798393e" (func (;30;) (type 19)))
+  (import "./reference_test_bg.js" "__wbg_resolve_ae8d83246e5bcc12" (func (;31;) (type 22)))
+  (import "./reference_test_bg.js" "__wbg_run_78b7b601add6ed6b" (func (;32;) (type 25)))
+  (import "./reference_test_bg.js" "__wbg_self_81ecf3f64cd5499b" (func (;33;) (type 20)))
+  (import "./reference_test_bg.js" "__wbg_setTimeout_205a9e5dc88beae7" (func (;34;) (type 23)))
+  (import "./reference_test_bg.js" "__wbg_set_text_content_53a918c21c5b6d7b" (func (;35;) (type 24)))
+  (import "./reference_test_bg.js" "__wbg_stack_4937ea091ccee211" (func (;36;) (type 17)))
+  (import "./reference_test_bg.js" "__wbg_stack_7d0113fc30ab59f5" (func (;37;) (type 17)))
+  (import "./reference_test_bg.js" "__wbg_stack_c5219bcda5d316b0" (func (;38;) (type 22)))
+  (import "./reference_test_bg.js" "__wbg_stack_f43e5bf3710666bf" (func (;39;) (type 22)))
+  (import "./reference_test_bg.js" "__wbg_static_accessor_DOCUMENT_5687ccb6ab0b4b55" (func (;40;) (type 4)))
+  (import "./reference_test_bg.js" "__wbg_static_accessor_GLOBAL_8adb955bd33fac2f" (func (;41;) (type 1)))
+  (import "./reference_test_bg.js" "__wbg_static_accessor_GLOBAL_THIS_ad356e0db91c7913" (func (;42;) (type 1)))
+  (import "./reference_test_bg.js" "__wbg_static_accessor_ON_FAILURE_96dca11623aafa8f" (func (;43;) (type 1)))
+  (import "./reference_test_bg.js" "__wbg_static_accessor_SELF_f207c857566db248" (func (;44;) (type 1)))
+  (import "./reference_test_bg.js" "__wbg_static_accessor_WINDOW_bb9f1ba69d61b386" (func (;45;) (type 1)))
+  (import "./reference_test_bg.js" "__wbg_text_content_d8e042bed66aa8bd" (func (;46;) (type 17)))
+  (import "./reference_test_bg.js" "__wbg_then_098abe61755d12f6" (func (;47;) (type 27)))
+  (import "./reference_test_bg.js" "__wbg_then_9e335f6dd892bc11" (func (;48;) (type 28)))
+  (import "./reference_test_bg.js" "__wbg_toString_5d57325a72a29da1" (func (;49;) (type 17)))
+  (import "./reference_test_bg.js" "__wbindgen_cast_0000000000000000" (func (;50;) (type 9)))
+  (import "./reference_test_bg.js" "__wbindgen_cast_0000000000000001" (func (;51;) (type 18)))
+  (import "./reference_test_bg.js" "__wbindgen_cast_0000000000000002" (func (;52;) (type 9)))
+  (import "./reference_test_bg.js" "__wbindgen_init_externref_table" (func (;53;) (type 0)))
   (table $__wbindgen_externrefs (;0;) 1024 externref)
   (memory (;0;) 18)
   (export "memory" (memory 0))
@@ -90,13 +98,19 @@
   (export "__wbgtest_coverage_path" (func $"__wbgtest_coverage_path multivalue shim"))
   (export "__wbgtest_module_signature" (func $"__wbgtest_module_signature multivalue shim"))
   (export "wasmbindgentestcontext_filtered_count" (func $wasmbindgentestcontext_filtered_count))
+  (export "wasmbindgentestcontext_finish" (func $wasmbindgentestcontext_finish))
+  (export "wasmbindgentestcontext_format" (func $wasmbindgentestcontext_format))
   (export "wasmbindgentestcontext_include_ignored" (func $wasmbindgentestcontext_include_ignored))
   (export "wasmbindgentestcontext_new" (func $wasmbindgentestcontext_new))
+  (export "wasmbindgentestcontext_report" (func $wasmbindgentestcontext_report))
+  (export "wasmbindgentestcontext_report_error" (func $"wasmbindgentestcontext_report_error externref shim"))
   (export "wasmbindgentestcontext_run" (func $"wasmbindgentestcontext_run externref shim"))
+  (export "wasmbindgentestcontext_start" (func $wasmbindgentestcontext_start))
   (export "wasm_bindgen__convert__closures_____invoke__h0000000000000004" (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000004 externref shim"))
-  (export "wasm_bindgen__convert__closures_____invoke__h0000000000000007" (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000007 externref shim multivalue shim"))
+  (export "wasm_bindgen__convert__closures_____invoke__h0000000000000008" (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000008 externref shim multivalue shim"))
   (export "wasm_bindgen__convert__closures_____invoke__h0000000000000005" (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000005 externref shim"))
-  (export "wasm_bindgen__convert__closures_____invoke__h0000000000000006" (func $wasm_bindgen::convert::closures::_::invoke::h0000000000000006))
+  (export "wasm_bindgen__convert__closures_____invoke__h0000000000000006" (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000006 externref shim"))
+  (export "wasm_bindgen__convert__closures_____invoke__h0000000000000007" (func $wasm_bindgen::convert::closures::_::invoke::h0000000000000007))
   (export "wasm_bindgen__convert__closures_____invoke__h0000000000000003" (func $wasm_bindgen::convert::closures::_::invoke::h0000000000000003))
   (export "__externref_table_alloc" (func $__externref_table_alloc))
   (export "__wbindgen_externrefs" (table $__wbindgen_externrefs))
@@ -106,34 +120,40 @@
   (export "__wbindgen_destroy_closure" (func $__wbindgen_destroy_closure))
   (export "__wbindgen_free" (func $__wbindgen_free))
   (export "__externref_table_dealloc" (func $__externref_table_dealloc))
-  (export "__wbindgen_start" (func 46))
-  (func $__wbgt__wasm_export_colon_reftest::colon_test (;47;) (type 5) (param i32))
-  (func $__wbg_wasmbindgentestcontext_free (;48;) (type 7) (param i32 i32))
-  (func $"__wbgbench_dump multivalue shim" (;49;) (type 2) (result i32 i32))
-  (func $__wbgbench_import (;50;) (type 7) (param i32 i32))
-  (func $"__wbgtest_console_debug externref shim" (;51;) (type 19) (param externref))
-  (func $"__wbgtest_console_error externref shim" (;52;) (type 19) (param externref))
-  (func $"__wbgtest_console_info externref shim" (;53;) (type 19) (param externref))
-  (func $"__wbgtest_console_log externref shim" (;54;) (type 19) (param externref))
-  (func $"__wbgtest_console_warn externref shim" (;55;) (type 19) (param externref))
-  (func $"__wbgtest_cov_dump multivalue shim" (;56;) (type 2) (result i32 i32))
-  (func $"__wbgtest_coverage_path multivalue shim" (;57;) (type 13) (param i32 i32 i32 i32 i32 i64) (result i32 i32))
-  (func $"__wbgtest_module_signature multivalue shim" (;58;) (type 3) (result i32 i64))
-  (func $wasmbindgentestcontext_filtered_count (;59;) (type 7) (param i32 i32))
-  (func $wasmbindgentestcontext_include_ignored (;60;) (type 7) (param i32 i32))
-  (func $wasmbindgentestcontext_new (;61;) (type 6) (param i32) (result i32))
-  (func $"wasmbindgentestcontext_run externref shim" (;62;) (type 11) (param i32 i32 i32) (result externref))
-  (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000004 externref shim" (;63;) (type 15) (param i32 i32 externref i32 externref))
-  (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000007 externref shim multivalue shim" (;64;) (type 14) (param i32 i32 externref) (result i32 i32))
-  (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000005 externref shim" (;65;) (type 16) (param i32 i32 externref externref))
-  (func $wasm_bindgen::convert::closures::_::invoke::h0000000000000006 (;66;) (type 8) (param i32 i32) (result i32))
-  (func $wasm_bindgen::convert::closures::_::invoke::h0000000000000003 (;67;) (type 7) (param i32 i32))
-  (func $__externref_table_alloc (;68;) (type 1) (result i32))
-  (func $__wbindgen_malloc (;69;) (type 8) (param i32 i32) (result i32))
-  (func $__wbindgen_realloc (;70;) (type 12) (param i32 i32 i32 i32) (result i32))
-  (func $__wbindgen_exn_store (;71;) (type 5) (param i32))
-  (func $__wbindgen_destroy_closure (;72;) (type 7) (param i32 i32))
-  (func $__wbindgen_free (;73;) (type 10) (param i32 i32 i32))
-  (func $__externref_table_dealloc (;74;) (type 5) (param i32))
+  (export "__wbindgen_start" (func 53))
+  (func $__wbgt__wasm_export_colon_reftest::colon_test (;54;) (type 5) (param i32))
+  (func $__wbg_wasmbindgentestcontext_free (;55;) (type 7) (param i32 i32))
+  (func $"__wbgbench_dump multivalue shim" (;56;) (type 2) (result i32 i32))
+  (func $__wbgbench_import (;57;) (type 7) (param i32 i32))
+  (func $"__wbgtest_console_debug externref shim" (;58;) (type 19) (param externref))
+  (func $"__wbgtest_console_error externref shim" (;59;) (type 19) (param externref))
+  (func $"__wbgtest_console_info externref shim" (;60;) (type 19) (param externref))
+  (func $"__wbgtest_console_log externref shim" (;61;) (type 19) (param externref))
+  (func $"__wbgtest_console_warn externref shim" (;62;) (type 19) (param externref))
+  (func $"__wbgtest_cov_dump multivalue shim" (;63;) (type 2) (result i32 i32))
+  (func $"__wbgtest_coverage_path multivalue shim" (;64;) (type 13) (param i32 i32 i32 i32 i32 i64) (result i32 i32))
+  (func $"__wbgtest_module_signature multivalue shim" (;65;) (type 3) (result i32 i64))
+  (func $wasmbindgentestcontext_filtered_count (;66;) (type 7) (param i32 i32))
+  (func $wasmbindgentestcontext_finish (;67;) (type 6) (param i32) (result i32))
+  (func $wasmbindgentestcontext_format (;68;) (type 10) (param i32 i32 i32))
+  (func $wasmbindgentestcontext_include_ignored (;69;) (type 7) (param i32 i32))
+  (func $wasmbindgentestcontext_new (;70;) (type 8) (param i32 i32) (result i32))
+  (func $wasmbindgentestcontext_report (;71;) (type 10) (param i32 i32 i32))
+  (func $"wasmbindgentestcontext_report_error externref shim" (;72;) (type 17) (param i32 externref))
+  (func $"wasmbindgentestcontext_run externref shim" (;73;) (type 11) (param i32 i32 i32) (result externref))
+  (func $wasmbindgentestcontext_start (;74;) (type 7) (param i32 i32))
+  (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000004 externref shim" (;75;) (type 15) (param i32 i32 externref i32 externref))
+  (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000008 externref shim multivalue shim" (;76;) (type 14) (param i32 i32 externref) (result i32 i32))
+  (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000005 externref shim" (;77;) (type 16) (param i32 i32 externref externref))
+  (func $"wasm_bindgen::convert::closures::_::invoke::h0000000000000006 externref shim" (;78;) (type 16) (param i32 i32 externref externref))
+  (func $wasm_bindgen::convert::closures::_::invoke::h0000000000000007 (;79;) (type 8) (param i32 i32) (result i32))
+  (func $wasm_bindgen::convert::closures::_::invoke::h0000000000000003 (;80;) (type 7) (param i32 i32))
+  (func $__externref_table_alloc (;81;) (type 1) (result i32))
+  (func $__wbindgen_malloc (;82;) (type 8) (param i32 i32) (result i32))
+  (func $__wbindgen_realloc (;83;) (type 12) (param i32 i32 i32 i32) (result i32))
+  (func $__wbindgen_exn_store (;84;) (type 5) (param i32))
+  (func $__wbindgen_destroy_closure (;85;) (type 7) (param i32 i32))
+  (func $__wbindgen_free (;86;) (type 10) (param i32 i32 i32))
+  (func $__externref_table_dealloc (;87;) (type 5) (param i32))
   (@custom "target_features" (after code) "\08+\0bbulk-memory+\0fbulk-memory-opt+\16call-indirect-overlong+\0amultivalue+\0fmutable-globals+\13nontrapping-fptoint+\0freference-types+\08sign-ext")
 )
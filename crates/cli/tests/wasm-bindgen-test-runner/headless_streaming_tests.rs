@@ -1,7 +1,8 @@
 //! Tests for headless browser output streaming behavior.
 //!
 //! These tests verify that console output is handled correctly in headless browser mode,
-//! including proper output streaming and avoiding duplicate messages.
+//! including proper output streaming and avoiding duplicate messages, and that it is saved
+//! along with the rest of the failure artifacts.
 
 use assert_cmd::Command;
 use std::env;
@@ -1875,6 +1876,191 @@ fn test_service_worker_no_carriage_return_in_output() {
     );
 }
 
+/// Test that a screenshot, the console output and the HTML of the page are
+/// saved for each test failing in run_in_browser mode.
+#[test]
+fn test_browser_failure_artifacts() {
+    let Some((driver_env, driver_path)) = find_webdriver() else {
+        eprintln!("Skipping headless test: no webdriver found");
+        return;
+    };
+
+    let mut project = Project::new("test_browser_failure_artifacts");
+    project.file(
+        "src/lib.rs",
+        r#"
+            use wasm_bindgen_test::*;
+
+            wasm_bindgen_test_configure!(run_in_browser);
+
+            #[wasm_bindgen_test]
+            fn test() {
+                console_log!("logged before failing");
+                panic!()
+            }
+
+            #[wasm_bindgen_test]
+            fn passing() {}
+        "#,
+    );
+
+    project.cargo_toml();
+    let artifacts = project.root.join("artifacts");
+    let runner = REPO_ROOT.join("crates").join("cli").join("Cargo.toml");
+    let output = Command::new("cargo")
+        .current_dir(&project.root)
+        .arg("test")
+        .arg("--target")
+        .arg("wasm32-unknown-unknown")
+        .env("CARGO_TARGET_DIR", &*TARGET_DIR)
+        .env(
+            "CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER",
+            format!(
+                "cargo run --manifest-path {} --bin wasm-bindgen-test-runner --",
+                runner.display()
+            ),
+        )
+        .env(driver_env, driver_path)
+        .env("WASM_BINDGEN_TEST_ARTIFACTS_DIR", &artifacts)
+        .output()
+        .expect("failed to execute cargo test");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("failure artifacts:"),
+        "Expected the artifacts to be listed.\nstderr:\n{stderr}",
+    );
+
+    // The artifacts of each test binary go into a directory named after it,
+    // with one directory per failed test named after the test.
+    let artifacts = fs::read_dir(&artifacts)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    let tests = fs::read_dir(&artifacts)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    assert_eq!(tests.len(), 1, "{tests:?}");
+    let artifacts = &tests[0];
+    assert!(
+        artifacts
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .ends_with("-test"),
+        "{}",
+        artifacts.display()
+    );
+    let screenshot = fs::read(artifacts.join("screenshot.png")).unwrap();
+    assert!(screenshot.starts_with(b"\x89PNG"));
+    let console = fs::read_to_string(artifacts.join("page-console.log")).unwrap();
+    assert!(console.contains("logged before failing"), "{console}");
+    let html = fs::read_to_string(artifacts.join("document.html")).unwrap();
+    assert!(html.contains("id=\"console_output\""), "{html}");
+}
+
+/// Test that the JSON and JUnit reports of several browsers are merged, with
+/// the browser in the name of each test.
+#[test]
+fn test_browsers_merged_reports() {
+    let Some((driver_env, driver_path)) = find_webdriver() else {
+        eprintln!("Skipping headless test: no webdriver found");
+        return;
+    };
+    let browser = match driver_env {
+        "CHROMEDRIVER" => "chrome",
+        "GECKODRIVER" => "firefox",
+        _ => "safari",
+    };
+
+    let mut project = Project::new("test_browsers_merged_reports");
+    project.file(
+        "src/lib.rs",
+        r#"
+            use wasm_bindgen_test::*;
+
+            wasm_bindgen_test_configure!(run_in_browser);
+
+            #[wasm_bindgen_test]
+            fn test() {}
+        "#,
+    );
+
+    project.cargo_toml();
+    let runner = REPO_ROOT.join("crates").join("cli").join("Cargo.toml");
+    let run = |format: &str| {
+        let output = Command::new("cargo")
+            .current_dir(&project.root)
+            .arg("test")
+            .arg("--target")
+            .arg("wasm32-unknown-unknown")
+            .arg("--")
+            .arg("--format")
+            .arg(format)
+            .env("CARGO_TARGET_DIR", &*TARGET_DIR)
+            .env(
+                "CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER",
+                format!(
+                    "cargo run --manifest-path {} --bin wasm-bindgen-test-runner --",
+                    runner.display()
+                ),
+            )
+            .env(driver_env, &driver_path)
+            // The same browser twice stands in for two browsers.
+            .env("WASM_BINDGEN_TEST_BROWSERS", format!("{browser},{browser}"))
+            .output()
+            .expect("failed to execute cargo test");
+        assert!(
+            output.status.success(),
+            "stderr:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let stdout = run("json");
+    let events = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    let suites = events
+        .iter()
+        .filter(|event| event["type"] == "suite" && event["event"] == "ok")
+        .count();
+    assert_eq!(suites, 2, "{stdout}");
+    let name = format!("{browser}::test_browsers_merged_reports::test");
+    let passed = events
+        .iter()
+        .filter(|event| event["name"] == name.as_str() && event["event"] == "ok")
+        .count();
+    assert_eq!(passed, 2, "{stdout}");
+
+    let stdout = run("junit");
+    let stdout = stdout.trim();
+    assert!(stdout.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?><testsuites>"#));
+    assert!(stdout.ends_with("</testsuites>"));
+    assert_eq!(stdout.matches("<?xml").count(), 1, "{stdout}");
+    assert!(
+        stdout.contains(&format!(
+            r#"<testsuite name="{browser}" package="test" id="1""#
+        )),
+        "{stdout}"
+    );
+    assert_eq!(
+        stdout
+            .matches(&format!(
+                r#"<testcase classname="{browser}::test_browsers_merged_reports" name="test""#
+            ))
+            .count(),
+        2,
+        "{stdout}"
+    );
+}
+
 // ============================================================================
 // Node.js worker_threads log capture tests
 // ============================================================================
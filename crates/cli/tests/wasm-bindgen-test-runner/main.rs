@@ -33,6 +33,9 @@ struct Project {
     name: String,
     deps: String,
     dev_deps: String,
+    target: String,
+    cargo_args: Vec<String>,
+    envs: Vec<(String, String)>,
 }
 
 impl Project {
@@ -46,9 +49,31 @@ impl Project {
             name,
             deps: "wasm-bindgen = { path = '{root}' }\n".to_owned(),
             dev_deps: "wasm-bindgen-test = { path = '{root}/crates/test' }\n".to_owned(),
+            target: "wasm32-unknown-unknown".to_owned(),
+            cargo_args: Vec::new(),
+            envs: Vec::new(),
         }
     }
 
+    fn target(&mut self, target: &str) -> &mut Project {
+        self.target = target.to_owned();
+        self
+    }
+
+    /// Passes an additional argument to `cargo test`.
+    fn cargo_arg(&mut self, arg: &str) -> &mut Project {
+        self.cargo_args.push(arg.to_owned());
+        self
+    }
+
+    /// Sets an environment variable for the test runner, replacing any
+    /// previous value.
+    fn env(&mut self, key: &str, value: &str) -> &mut Project {
+        self.envs.retain(|(k, _)| k != key);
+        self.envs.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
     fn file(&mut self, name: &str, contents: &str) -> &mut Project {
         let dst = self.root.join(name);
         fs::create_dir_all(dst.parent().unwrap()).unwrap();
@@ -64,17 +89,22 @@ impl Project {
             .current_dir(&self.root)
             .arg("test")
             .arg("--target")
-            .arg("wasm32-unknown-unknown")
+            .arg(&self.target)
+            .args(&self.cargo_args)
             .arg("--")
             .args(args.split_whitespace())
             .env("CARGO_TARGET_DIR", &*TARGET_DIR)
             .env(
-                "CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER",
+                format!(
+                    "CARGO_TARGET_{}_RUNNER",
+                    self.target.to_uppercase().replace('-', "_")
+                ),
                 format!(
                     "cargo run --manifest-path {} --bin wasm-bindgen-test-runner --",
                     runner.display()
                 ),
             )
+            .envs(self.envs.iter().map(|(k, v)| (k, v)))
             .output()?;
         Ok(output)
     }
@@ -137,6 +167,549 @@ fn test_wasm_bindgen_test_runner_list() {
     assert_eq!(lines.next(), None);
 }
 
+#[test]
+fn test_wasm_bindgen_test_runner_wasi() {
+    let mut project = Project::new("test_wasm_bindgen_test_runner_wasi");
+    project.target("wasm32-wasip1").file(
+        "src/lib.rs",
+        r#"
+            #[cfg(test)]
+            mod tests {
+                use wasm_bindgen_test::*;
+
+                #[wasm_bindgen_test]
+                fn test_foo() {
+                    println!("written with fd_write");
+                }
+
+                #[wasm_bindgen_test]
+                fn test_bar() {}
+
+                #[wasm_bindgen_test]
+                #[should_panic(expected = "boom")]
+                fn test_panics() {
+                    panic!("boom");
+                }
+            }
+        "#,
+    );
+
+    let output = project.wasm_bindgen_test("--list").unwrap();
+    let mut lines = output
+        .stdout
+        .lines()
+        .map(|l| l.unwrap())
+        .collect::<Vec<_>>();
+    lines.sort();
+    assert_eq!(
+        lines,
+        [
+            "tests::test_bar: test",
+            "tests::test_foo: test",
+            "tests::test_panics: test",
+        ]
+    );
+
+    let output = project.wasm_bindgen_test("foo").unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("test tests::test_foo ... ok"), "{stdout}");
+    assert!(stdout.contains("1 passed; 0 failed; 0 ignored; 2 filtered out"));
+
+    let output = project.wasm_bindgen_test("").unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("test tests::test_panics ... ok"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("3 passed; 0 failed"));
+}
+
+#[test]
+fn test_wasm_bindgen_test_runner_wasi_threads() {
+    // Spawning threads needs the WWRR runtime, the built-in shim can't.
+    if env::var_os("WWRR_DIR").is_none() {
+        eprintln!("Skipping WASI threads test: `WWRR_DIR` not set");
+        return;
+    }
+
+    for (mode, configure) in [
+        ("node", ""),
+        ("browser", "wasm_bindgen_test_configure!(run_in_browser);"),
+        (
+            "dedicated_worker",
+            "wasm_bindgen_test_configure!(run_in_dedicated_worker);",
+        ),
+    ] {
+        let mut project =
+            Project::new(format!("test_wasm_bindgen_test_runner_wasi_threads_{mode}"));
+        project.target("wasm32-wasip1-threads").file(
+            "src/lib.rs",
+            &format!(
+                r#"
+                    #[cfg(test)]
+                    mod tests {{
+                        use wasm_bindgen_test::*;
+
+                        {configure}
+
+                        #[wasm_bindgen_test]
+                        fn test_spawn() {{
+                            let handle = std::thread::spawn(|| 40 + 2);
+                            assert_eq!(handle.join().unwrap(), 42);
+                            println!("joined thread");
+                        }}
+
+                        #[wasm_bindgen_test]
+                        fn test_other() {{}}
+
+                        #[wasm_bindgen_test]
+                        #[should_panic(expected = "boom")]
+                        fn test_panics() {{
+                            panic!("boom");
+                        }}
+                    }}
+                "#
+            ),
+        );
+
+        let output = project.wasm_bindgen_test("--list").unwrap();
+        let mut lines = output
+            .stdout
+            .lines()
+            .map(|l| l.unwrap())
+            .collect::<Vec<_>>();
+        lines.sort();
+        assert_eq!(
+            lines,
+            [
+                "tests::test_other: test",
+                "tests::test_panics: test",
+                "tests::test_spawn: test",
+            ],
+            "{mode}"
+        );
+
+        let output = project.wasm_bindgen_test("spawn --nocapture").unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "{mode}: {stdout}");
+        assert!(stdout.contains("joined thread"), "{mode}: {stdout}");
+        assert!(stdout.contains("1 passed; 0 failed; 0 ignored; 2 filtered out"));
+
+        let output = project.wasm_bindgen_test("").unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "{mode}: {stdout}");
+        assert!(stdout.contains("test tests::test_panics ... ok"));
+        assert!(stdout.contains("3 passed; 0 failed"), "{mode}: {stdout}");
+    }
+}
+
+const FORMAT_TESTS: &str = r#"
+    #[cfg(test)]
+    mod tests {
+        use wasm_bindgen_test::*;
+
+        #[wasm_bindgen_test]
+        fn test_ok() {
+            console_log!("captured <output>");
+        }
+
+        #[wasm_bindgen_test]
+        fn test_fail() {
+            panic!("failed");
+        }
+
+        #[wasm_bindgen_test]
+        #[ignore = "reason"]
+        fn test_ignored() {}
+    }
+"#;
+
+#[test]
+fn test_wasm_bindgen_test_runner_format_json() {
+    let output = Project::new("test_wasm_bindgen_test_runner_format_json")
+        .file("src/lib.rs", FORMAT_TESTS)
+        .wasm_bindgen_test("--format json")
+        .unwrap();
+    assert!(!output.status.success());
+
+    let events: Vec<serde_json::Value> = output
+        .stdout
+        .lines()
+        .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+        .collect();
+    let event = |name: &str, kind: &str| {
+        events
+            .iter()
+            .find(|event| event["name"] == name && event["event"] == kind)
+            .unwrap_or_else(|| panic!("no `{kind}` event for `{name}` in {events:#?}"))
+    };
+
+    assert_eq!(events[0]["type"], "suite");
+    assert_eq!(events[0]["event"], "started");
+    assert_eq!(events[0]["test_count"], 3);
+    event("tests::test_ok", "started");
+    assert!(event("tests::test_ok", "ok")["stdout"]
+        .as_str()
+        .unwrap()
+        .contains("captured <output>"));
+    assert!(event("tests::test_ok", "ok")["exec_time"].is_f64());
+    assert!(event("tests::test_fail", "failed")["stdout"]
+        .as_str()
+        .unwrap()
+        .contains("JS exception that was thrown"));
+    assert_eq!(event("tests::test_ignored", "ignored")["message"], "reason");
+
+    let suite = events.last().unwrap();
+    assert_eq!(suite["type"], "suite");
+    assert_eq!(suite["event"], "failed");
+    assert_eq!(suite["passed"], 1);
+    assert_eq!(suite["failed"], 1);
+    assert_eq!(suite["ignored"], 1);
+    assert_eq!(suite["filtered_out"], 0);
+}
+
+#[test]
+fn test_wasm_bindgen_test_runner_format_junit() {
+    let output = Project::new("test_wasm_bindgen_test_runner_format_junit")
+        .file("src/lib.rs", FORMAT_TESTS)
+        .wasm_bindgen_test("--format junit")
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stdout = stdout.trim();
+    assert!(stdout.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?><testsuites>"#));
+    assert!(stdout.ends_with("</testsuites>"));
+    assert!(stdout.contains(r#"failures="1" tests="3" skipped="1""#));
+    assert!(stdout.contains(r#"<testcase classname="tests" name="test_ok" time=""#));
+    assert!(stdout.contains("<system-out>log output:\n    captured &lt;output&gt;"));
+    assert!(stdout.contains(r#"<testcase classname="tests" name="test_fail""#));
+    assert!(stdout.contains(r#"<failure type="assert">"#));
+    assert!(stdout.contains(r#"name="test_ignored" time="0"><skipped/></testcase>"#));
+}
+
+#[test]
+fn test_wasm_bindgen_test_runner_timeout() {
+    let output = Project::new("test_wasm_bindgen_test_runner_timeout")
+        .file(
+            "src/lib.rs",
+            r#"
+            #[cfg(test)]
+            mod tests {
+                use wasm_bindgen_test::*;
+
+                #[wasm_bindgen_test(timeout = "100ms")]
+                async fn test_hangs() {
+                    std::future::pending::<()>().await
+                }
+
+                #[wasm_bindgen_test(timeout = "5s")]
+                async fn test_async() {}
+
+                #[wasm_bindgen_test]
+                fn test_sync() {}
+            }
+        "#,
+        )
+        .wasm_bindgen_test("")
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("test tests::test_hangs ... FAIL"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("note: tests::test_hangs did not complete within 100ms"));
+    assert!(stdout.contains("test tests::test_async ... ok"));
+    assert!(stdout.contains("test tests::test_sync ... ok"));
+    assert!(stdout.contains("2 passed; 1 failed"));
+}
+
+#[test]
+fn test_wasm_bindgen_test_runner_test_threads() {
+    let output = Project::new("test_wasm_bindgen_test_runner_test_threads")
+        .file("src/lib.rs", FORMAT_TESTS)
+        .wasm_bindgen_test("--test-threads 2 --format json")
+        .unwrap();
+    assert!(!output.status.success());
+
+    let events: Vec<serde_json::Value> = output
+        .stdout
+        .lines()
+        .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+        .collect();
+    assert_eq!(events[0]["event"], "started");
+    assert_eq!(events[0]["test_count"], 3);
+    assert!(events.iter().any(|event| event["name"] == "tests::test_ok"
+        && event["event"] == "ok"
+        && event["stdout"]
+            .as_str()
+            .unwrap()
+            .contains("captured <output>")));
+
+    let suite = events.last().unwrap();
+    assert_eq!(suite["event"], "failed");
+    assert_eq!(suite["passed"], 1);
+    assert_eq!(suite["failed"], 1);
+    assert_eq!(suite["ignored"], 1);
+}
+
+#[test]
+fn test_wasm_bindgen_test_runner_shard() {
+    let mut project = Project::new("test_wasm_bindgen_test_runner_shard");
+    project.file(
+        "src/lib.rs",
+        r#"
+            #[cfg(test)]
+            mod tests {
+                use wasm_bindgen_test::*;
+
+                #[wasm_bindgen_test]
+                fn test_a() {}
+
+                #[wasm_bindgen_test]
+                fn test_b() {}
+
+                #[wasm_bindgen_test]
+                fn test_c() {}
+            }
+        "#,
+    );
+
+    let mut list = |shard: &str| {
+        let output = project
+            .wasm_bindgen_test(&format!("--list --shard {shard}"))
+            .unwrap();
+        output
+            .stdout
+            .lines()
+            .map(|line| line.unwrap())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(list("1/2"), ["tests::test_a: test", "tests::test_c: test"]);
+    assert_eq!(list("2/2"), ["tests::test_b: test"]);
+
+    let output = project.wasm_bindgen_test("--shard 3/2").unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_wasm_bindgen_test_runner_isolated() {
+    let mut project = Project::new("test_wasm_bindgen_test_runner_isolated");
+    project.file(
+        "src/lib.rs",
+        r#"
+            #[cfg(test)]
+            mod tests {
+                use std::sync::atomic::{AtomicUsize, Ordering};
+                use wasm_bindgen_test::*;
+
+                static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+                #[wasm_bindgen_test(isolated)]
+                fn test_a() {
+                    assert_eq!(RUNS.fetch_add(1, Ordering::SeqCst), 0);
+                }
+
+                #[wasm_bindgen_test(isolated)]
+                fn test_b() {
+                    assert_eq!(RUNS.fetch_add(1, Ordering::SeqCst), 0);
+                }
+
+                #[wasm_bindgen_test]
+                fn test_c() {
+                    RUNS.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+        "#,
+    );
+
+    for args in ["--format json", "--format json --isolate"] {
+        let output = project.wasm_bindgen_test(args).unwrap();
+        assert!(output.status.success());
+
+        let suite: serde_json::Value =
+            serde_json::from_str(&output.stdout.lines().last().unwrap().unwrap()).unwrap();
+        assert_eq!(suite["event"], "ok");
+        assert_eq!(suite["passed"], 3);
+    }
+}
+
+#[test]
+fn test_wasm_bindgen_test_runner_isolated_abort() {
+    let mut project = Project::new("test_wasm_bindgen_test_runner_isolated_abort");
+    // An aborted instance is only marked as terminated with `panic = "unwind"`,
+    // which needs nightly to rebuild `std`.
+    project
+        .env("RUSTUP_TOOLCHAIN", "nightly")
+        .env(
+            "CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUSTFLAGS",
+            "-Cpanic=unwind",
+        )
+        .cargo_arg("-Zbuild-std=std,panic_unwind")
+        .file(
+            "src/lib.rs",
+            r#"
+                #[cfg(test)]
+                mod tests {
+                    use std::sync::atomic::{AtomicUsize, Ordering};
+                    use wasm_bindgen_test::*;
+
+                    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+                    #[wasm_bindgen_test]
+                    fn test_shared() {}
+
+                    #[wasm_bindgen_test(isolated)]
+                    fn test_a() {
+                        assert_eq!(RUNS.fetch_add(1, Ordering::SeqCst), 0);
+                    }
+
+                    #[wasm_bindgen_test(isolated)]
+                    fn test_b_aborts() {
+                        RUNS.fetch_add(1, Ordering::SeqCst);
+                        std::process::abort();
+                    }
+
+                    #[wasm_bindgen_test(isolated)]
+                    fn test_c() {
+                        assert_eq!(RUNS.fetch_add(1, Ordering::SeqCst), 0);
+                    }
+                }
+            "#,
+        );
+
+    let output = project.wasm_bindgen_test("").unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("test tests::test_shared ... ok"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("test tests::test_a ... ok"), "{stdout}");
+    assert!(stdout.contains("test tests::test_c ... ok"), "{stdout}");
+    // The aborted test fails, however the runner attributes the failure.
+    assert!(stdout.contains("3 passed; "), "{stdout}");
+    assert!(!stdout.contains("; 0 failed"), "{stdout}");
+}
+
+#[test]
+fn test_wasm_bindgen_test_runner_bun() {
+    if !std::process::Command::new("bun")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+    {
+        // CI installs Bun, so it must not silently skip the test.
+        assert!(env::var_os("CI").is_none(), "`bun` not found");
+        eprintln!("Skipping Bun test: `bun` not found");
+        return;
+    }
+
+    let mut project = Project::new("test_wasm_bindgen_test_runner_bun");
+    project.file(
+        "src/lib.rs",
+        r#"
+            #[cfg(test)]
+            mod tests {
+                use wasm_bindgen_test::*;
+
+                wasm_bindgen_test_configure!(run_in_bun);
+
+                #[wasm_bindgen_test]
+                fn test_ok() {
+                    assert!(js_sys::eval("typeof Bun").unwrap() == "object");
+                }
+
+                #[wasm_bindgen_test]
+                async fn test_async() {}
+
+                #[wasm_bindgen_test]
+                fn test_fails() {
+                    panic!("failed in Bun");
+                }
+            }
+        "#,
+    );
+    project
+        .dev_deps
+        .push_str("js-sys = { path = '{root}/crates/js-sys' }\n");
+
+    for args in ["", "--test-threads 2"] {
+        let output = project.wasm_bindgen_test(args).unwrap();
+        assert!(!output.status.success());
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("test tests::test_ok ... ok"), "{stdout}");
+        assert!(stdout.contains("test tests::test_async ... ok"));
+        assert!(stdout.contains("test tests::test_fails ... FAIL"));
+        assert!(stdout.contains("failed in Bun"));
+        assert!(stdout.contains("2 passed; 1 failed"));
+    }
+}
+
+#[test]
+fn test_wasm_bindgen_test_runner_browsers() {
+    let mut project = Project::new("test_wasm_bindgen_test_runner_browsers");
+    project.file(
+        "src/lib.rs",
+        r#"
+            #[cfg(test)]
+            mod tests {
+                use wasm_bindgen_test::*;
+
+                wasm_bindgen_test_configure!(run_in_browser);
+
+                #[wasm_bindgen_test]
+                fn test_foo() {}
+            }
+        "#,
+    );
+
+    // Only used once `edge` is requested.
+    project.env("MSEDGEDRIVER_REMOTE", "not a url");
+    let mut run = |browsers: &str, args: &str| {
+        let output = project
+            .env("WASM_BINDGEN_TEST_BROWSERS", browsers)
+            .wasm_bindgen_test(args)
+            .unwrap();
+        assert!(!output.status.success());
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    };
+
+    // Browsers are trimmed, and unknown ones are rejected before any of the
+    // others runs.
+    let stderr = run(" Opera , chrome,", "");
+    assert!(
+        stderr.contains(
+            "unknown browser `Opera` in `WASM_BINDGEN_TEST_BROWSERS`, \
+             expected `firefox`, `chrome`, `safari` or `edge`"
+        ),
+        "{stderr}"
+    );
+
+    // `safaridriver` only exists on macOS.
+    if !cfg!(target_os = "macos") {
+        let stderr = run("safari", "");
+        assert!(
+            stderr.contains(
+                "failed to find `safaridriver` to run tests in safari; configure it with \
+                 `SAFARIDRIVER` or `SAFARIDRIVER_REMOTE`, or make sure that it is in `PATH`"
+            ),
+            "{stderr}"
+        );
+    }
+
+    let stderr = run("EDGE", "");
+    assert!(
+        stderr.contains("failed to parse `MSEDGEDRIVER_REMOTE`"),
+        "{stderr}"
+    );
+}
+
 /// Test that console.log output in dedicated worker mode is not duplicated.
 /// See: https://github.com/wasm-bindgen/wasm-bindgen/pull/4845#issuecomment-3660688206
 #[test]
@@ -48,6 +48,7 @@ struct Project {
     root: PathBuf,
     name: String,
     deps: String,
+    target: String,
     cargo_cmd: Command,
     built: bool,
 }
@@ -62,13 +63,12 @@ impl Project {
         cargo_cmd
             .current_dir(&root)
             .arg("build")
-            .arg("--target")
-            .arg("wasm32-unknown-unknown")
             .env("CARGO_TARGET_DIR", &*TARGET_DIR);
         Project {
             root,
             name,
             deps: "wasm-bindgen = { path = '{root}' }\n".to_owned(),
+            target: "wasm32-unknown-unknown".to_owned(),
             cargo_cmd,
             built: false,
         }
@@ -111,6 +111,11 @@ impl Project {
         Ok(output)
     }
 
+    fn target(&mut self, target: &str) -> &mut Project {
+        self.target = target.to_owned();
+        self
+    }
+
     fn dep(&mut self, line: &str) -> &mut Project {
         self.deps.push_str(line);
         self.deps.push('\n');
@@ -147,13 +152,17 @@ impl Project {
                 );
             }
 
-            self.cargo_cmd.assert().success();
+            self.cargo_cmd
+                .arg("--target")
+                .arg(&self.target)
+                .assert()
+                .success();
 
             self.built = true;
         }
 
         let mut built = TARGET_DIR.to_path_buf();
-        built.push("wasm32-unknown-unknown");
+        built.push(&self.target);
         built.push("debug");
         built.push(&self.name);
         built.set_extension("wasm");
@@ -227,6 +236,444 @@ fn one_export_works() {
         .unwrap();
 }
 
+#[test]
+fn manifest_works() {
+    let out_dir = Project::new("manifest_works")
+        .file(
+            "src/lib.rs",
+            r#"
+                use wasm_bindgen::prelude::*;
+
+                #[wasm_bindgen(module = "foo")]
+                extern "C" {
+                    fn bar(x: u32) -> String;
+                }
+
+                /// Adds one.
+                #[wasm_bindgen]
+                pub fn add_one(x: u32) -> u32 {
+                    x + 1
+                }
+
+                #[wasm_bindgen]
+                pub struct Counter(u32);
+
+                #[wasm_bindgen]
+                impl Counter {
+                    #[wasm_bindgen(constructor)]
+                    pub fn new() -> Counter {
+                        Counter(0)
+                    }
+
+                    #[wasm_bindgen(getter)]
+                    pub fn count(&self) -> u32 {
+                        self.0
+                    }
+                }
+
+                #[wasm_bindgen]
+                pub enum Color {
+                    Red = 1,
+                }
+
+                #[wasm_bindgen]
+                pub fn call_bar() -> String {
+                    bar(1)
+                }
+
+                #[wasm_bindgen]
+                pub enum Mode {
+                    Fast = "fast",
+                }
+
+                /// Configures things.
+                #[wasm_bindgen(plain_object)]
+                pub struct Config {
+                    pub name: String,
+                    pub retries: Option<u32>,
+                }
+
+                #[wasm_bindgen]
+                pub trait Logger {
+                    fn log(&self, msg: &str) -> bool;
+                }
+
+                #[wasm_bindgen]
+                pub fn use_logger(logger: JsLogger, config: Config) -> Mode {
+                    logger.log(&config.name);
+                    Mode::Fast
+                }
+            "#,
+        )
+        .wasm_bindgen("--manifest")
+        .unwrap();
+
+    let manifest = fs::read_to_string(out_dir.join("manifest_works.manifest.json")).unwrap();
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+    assert_eq!(manifest["version"], 1);
+
+    let exports = manifest["exports"].as_array().unwrap();
+    let export = |name: &str| {
+        exports
+            .iter()
+            .find(|e| e["name"] == name)
+            .unwrap_or_else(|| panic!("no export `{name}`"))
+    };
+    let add_one = export("add_one");
+    assert_eq!(add_one["kind"], "function");
+    assert_eq!(add_one["args"][0]["name"], "x");
+    assert_eq!(add_one["args"][0]["type"], "number");
+    assert_eq!(add_one["returns"], "number");
+    assert_eq!(add_one["comments"], " Adds one.");
+    assert_eq!(export("Counter")["kind"], "class");
+    assert_eq!(export("constructor")["class"], "Counter");
+    let count = export("count");
+    assert_eq!(count["kind"], "getter");
+    assert_eq!(count["class"], "Counter");
+    assert_eq!(count["args"].as_array().unwrap().len(), 0);
+    let color = export("Color");
+    assert_eq!(color["kind"], "enum");
+    assert_eq!(color["variants"][0]["name"], "Red");
+    assert_eq!(color["variants"][0]["value"], 1);
+    let mode = export("Mode");
+    assert_eq!(mode["kind"], "string_enum");
+    assert_eq!(mode["variants"][0]["name"], "fast");
+    let config = export("Config");
+    assert_eq!(config["kind"], "plain_object");
+    assert_eq!(config["comments"], " Configures things.");
+    assert_eq!(config["fields"][0]["name"], "name");
+    assert_eq!(config["fields"][0]["type"], "string");
+    assert_eq!(config["fields"][1]["name"], "retries");
+    assert_eq!(config["fields"][1]["optional"], true);
+    let logger = export("Logger");
+    assert_eq!(logger["kind"], "trait");
+    assert_eq!(logger["methods"][0]["name"], "log");
+    assert_eq!(logger["methods"][0]["args"][0]["name"], "msg");
+    assert_eq!(logger["methods"][0]["args"][0]["type"], "string");
+    assert_eq!(logger["methods"][0]["returns"], "boolean");
+
+    let imports = manifest["imports"].as_array().unwrap();
+    let bar = imports.iter().find(|i| i["name"] == "bar").unwrap();
+    assert_eq!(bar["module"], "foo");
+    assert_eq!(bar["kind"], "function");
+    assert_eq!(bar["args"][0]["type"], "number");
+    assert_eq!(bar["returns"], "string");
+}
+
+#[test]
+fn npm_package_works() {
+    let out_dir = Project::new("npm_package_works")
+        .file(
+            "src/lib.rs",
+            r#"
+                use wasm_bindgen::prelude::*;
+                #[wasm_bindgen]
+                pub fn foo() {}
+            "#,
+        )
+        .wasm_bindgen("--package --target nodejs --target web")
+        .unwrap();
+
+    let package = fs::read_to_string(out_dir.join("package.json")).unwrap();
+    let package: serde_json::Value = serde_json::from_str(&package).unwrap();
+    assert_eq!(package["name"], "npm_package_works");
+    assert_eq!(package["main"], "./nodejs/npm_package_works.js");
+    assert_eq!(package["module"], "./web/npm_package_works.js");
+
+    let exports = &package["exports"]["."];
+    assert_eq!(exports["node"]["default"], "./nodejs/npm_package_works.js");
+    assert_eq!(exports["node"]["types"], "./nodejs/npm_package_works.d.ts");
+    assert_eq!(exports["browser"]["default"], "./web/npm_package_works.js");
+    assert_eq!(exports["worker"]["default"], "./web/npm_package_works.js");
+    assert_eq!(
+        exports["default"]["default"],
+        "./nodejs/npm_package_works.js"
+    );
+
+    let files = package["files"].as_array().unwrap();
+    assert!(files.contains(&"web/npm_package_works_bg.wasm".into()));
+    for file in files {
+        let file = file.as_str().unwrap();
+        assert!(out_dir.join(file).exists(), "`{file}` is missing");
+    }
+
+    let nested = fs::read_to_string(out_dir.join("web").join("package.json")).unwrap();
+    assert!(nested.contains(r#""type": "module""#));
+    let nested = fs::read_to_string(out_dir.join("nodejs").join("package.json")).unwrap();
+    assert!(nested.contains(r#""type": "commonjs""#));
+}
+
+#[test]
+fn api_check_works() {
+    let old = Project::new("api_check_old")
+        .file(
+            "src/lib.rs",
+            r#"
+                use wasm_bindgen::prelude::*;
+
+                #[wasm_bindgen]
+                pub fn add(a: u32, b: u32) -> u32 {
+                    a + b
+                }
+
+                #[wasm_bindgen]
+                pub enum Color {
+                    Red,
+                }
+
+                #[wasm_bindgen]
+                pub enum Mode {
+                    Fast = "fast",
+                }
+
+                #[wasm_bindgen(plain_object)]
+                pub struct Config {
+                    pub name: String,
+                    pub verbose: bool,
+                }
+
+                #[wasm_bindgen]
+                pub fn configure(config: Config) -> Mode {
+                    drop(config);
+                    Mode::Fast
+                }
+
+                #[wasm_bindgen(error)]
+                #[derive(Debug)]
+                pub enum ParseError {
+                    Empty,
+                }
+
+                impl std::fmt::Display for ParseError {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        f.write_str("empty input")
+                    }
+                }
+
+                impl std::error::Error for ParseError {}
+
+                #[wasm_bindgen]
+                pub fn parse(input: &str) -> Result<u32, ParseError> {
+                    input.parse().map_err(|_| ParseError::Empty)
+                }
+
+                #[wasm_bindgen]
+                pub trait Logger {
+                    fn log(&self, msg: &str);
+                    fn level(&self) -> u32;
+                }
+
+                #[wasm_bindgen]
+                pub fn use_logger(logger: JsLogger) {
+                    logger.log(&logger.level().to_string());
+                }
+            "#,
+        )
+        .build();
+    let compatible = Project::new("api_check_compatible")
+        .file(
+            "src/lib.rs",
+            r#"
+                use wasm_bindgen::prelude::*;
+
+                #[wasm_bindgen]
+                pub fn add(a: u32, b: u32, c: Option<u32>) -> u32 {
+                    a + b + c.unwrap_or(0)
+                }
+
+                #[wasm_bindgen]
+                pub fn sub(a: u32, b: u32) -> u32 {
+                    a - b
+                }
+
+                #[wasm_bindgen]
+                pub enum Color {
+                    Red,
+                    Green,
+                }
+
+                #[wasm_bindgen]
+                pub enum Mode {
+                    Fast = "fast",
+                    Slow = "slow",
+                }
+
+                #[wasm_bindgen(plain_object)]
+                pub struct Config {
+                    pub name: String,
+                    pub verbose: bool,
+                    pub retries: Option<u32>,
+                }
+
+                #[wasm_bindgen]
+                pub fn configure(config: Config) -> Mode {
+                    drop(config);
+                    Mode::Fast
+                }
+
+                #[wasm_bindgen(error)]
+                #[derive(Debug)]
+                pub enum ParseError {
+                    Empty,
+                }
+
+                impl std::fmt::Display for ParseError {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        f.write_str("empty input")
+                    }
+                }
+
+                impl std::error::Error for ParseError {}
+
+                #[wasm_bindgen(error)]
+                #[derive(Debug)]
+                pub enum FormatError {
+                    Empty,
+                }
+
+                impl std::fmt::Display for FormatError {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        f.write_str("empty input")
+                    }
+                }
+
+                impl std::error::Error for FormatError {}
+
+                #[wasm_bindgen]
+                pub fn parse(input: &str) -> Result<u32, FormatError> {
+                    input.parse().map_err(|_| FormatError::Empty)
+                }
+
+                #[wasm_bindgen]
+                pub trait Logger {
+                    fn log(&self, msg: &str);
+                    fn level(&self) -> u32;
+                }
+
+                #[wasm_bindgen]
+                pub fn use_logger(logger: JsLogger) {
+                    logger.log(&logger.level().to_string());
+                }
+            "#,
+        )
+        .build();
+    let breaking = Project::new("api_check_breaking")
+        .file(
+            "src/lib.rs",
+            r#"
+                use wasm_bindgen::prelude::*;
+
+                #[wasm_bindgen]
+                pub fn add(a: u32, b: &str) -> u32 {
+                    a + b.len() as u32
+                }
+
+                #[wasm_bindgen]
+                pub enum Mode {
+                    Quick = "quick",
+                }
+
+                #[wasm_bindgen(plain_object)]
+                pub struct Config {
+                    pub title: String,
+                    pub verbose: Option<bool>,
+                }
+
+                #[wasm_bindgen]
+                pub fn configure(config: Config) -> Mode {
+                    drop(config);
+                    Mode::Quick
+                }
+
+                #[wasm_bindgen(error)]
+                #[derive(Debug)]
+                pub enum ParseError {
+                    Empty,
+                }
+
+                impl std::fmt::Display for ParseError {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        f.write_str("empty input")
+                    }
+                }
+
+                impl std::error::Error for ParseError {}
+
+                #[wasm_bindgen]
+                pub fn parse(input: &str) -> Result<u32, ParseError> {
+                    input.parse().map_err(|_| ParseError::Empty)
+                }
+
+                #[wasm_bindgen]
+                pub trait Logger {
+                    fn log(&self, msg: u32);
+                    fn flush(&self);
+                }
+
+                #[wasm_bindgen]
+                pub fn use_logger(logger: JsLogger) {
+                    logger.log(0);
+                    logger.flush();
+                }
+            "#,
+        )
+        .build();
+
+    let check = |new: &Path| {
+        wasm_bindgen_cli::wasm_bindgen_api_check::run_cli_with_args([
+            "wasm-bindgen-api-check".as_ref(),
+            old.as_os_str(),
+            new.as_os_str(),
+        ])
+    };
+    check(&old).unwrap();
+    check(&compatible).unwrap();
+    let err = check(&breaking).unwrap_err();
+    assert!(err.to_string().contains("9 breaking change(s)"), "{err}");
+
+    // A trait is implemented by JS objects, so any change to its methods
+    // breaks them, while the thrown error class is only documented.
+    cargo_bin_cmd!("wasm-bindgen-api-check")
+        .arg(&old)
+        .arg(&compatible)
+        .assert()
+        .stdout(str::contains(
+            "compatible: function `parse` now throws `FormatError` instead of `ParseError`\n",
+        ))
+        .success();
+
+    cargo_bin_cmd!("wasm-bindgen-api-check")
+        .arg(&old)
+        .arg(&breaking)
+        .assert()
+        .stdout(str::contains(
+            "breaking: removed variant `fast` of string enum `Mode`\n",
+        ))
+        .stdout(str::contains(
+            "compatible: added variant `quick` to string enum `Mode`\n",
+        ))
+        .stdout(str::contains(
+            "breaking: removed field `name` of plain object `Config`\n",
+        ))
+        .stdout(str::contains(
+            "breaking: added required field `title` to plain object `Config`\n",
+        ))
+        .stdout(str::contains(
+            "breaking: field `verbose` of plain object `Config` is now optional\n",
+        ))
+        .stdout(str::contains(
+            "breaking: method `log` of trait `Logger` changed from \
+             `(msg: string) => void` to `(msg: number) => void`\n",
+        ))
+        .stdout(str::contains(
+            "breaking: removed method `level` of trait `Logger`\n",
+        ))
+        .stdout(str::contains(
+            "breaking: added method `flush` to trait `Logger`\n",
+        ))
+        .failure();
+}
+
 #[test]
 fn bin_crate_works() {
     let out_dir = Project::new("bin_crate_works")
@@ -1133,3 +1580,168 @@ describe('termination with reset state', () => {
         .assert()
         .success();
 }
+
+#[test]
+fn wasi_shim_works() {
+    let out_dir = Project::new("wasi_shim_works")
+        .target("wasm32-wasip1")
+        .file(
+            "src/lib.rs",
+            r#"
+                use std::time::{Instant, SystemTime, UNIX_EPOCH};
+                use wasm_bindgen::prelude::*;
+
+                #[link(wasm_import_module = "wasi_snapshot_preview1")]
+                extern "C" {
+                    fn random_get(buf: *mut u8, len: usize) -> u16;
+                }
+
+                #[wasm_bindgen]
+                pub fn run() {
+                    let args = std::env::args().collect::<Vec<_>>();
+                    println!("args: {args:?}");
+                    println!("FOO={}", std::env::var("FOO").unwrap());
+                    eprintln!("to stderr");
+
+                    let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+                    assert!(since_epoch.as_secs() > 1_600_000_000);
+                    let start = Instant::now();
+                    assert!(start.elapsed().as_secs() < 60);
+
+                    let mut bytes = [0; 32];
+                    assert_eq!(unsafe { random_get(bytes.as_mut_ptr(), bytes.len()) }, 0);
+                    assert!(bytes.iter().any(|&b| b != 0));
+
+                    std::process::exit(3);
+                }
+            "#,
+        )
+        .wasm_bindgen("--target web --wasi-shim")
+        .unwrap();
+
+    fs::write(
+        out_dir.join("run.mjs"),
+        r#"
+import { readFileSync } from 'node:fs';
+import assert from 'node:assert/strict';
+import init, { run, WasiExitError } from './wasi_shim_works.js';
+
+await init({
+    module_or_path: readFileSync(new URL('./wasi_shim_works_bg.wasm', import.meta.url)),
+    wasi: { args: ['prog', 'a b'], env: { FOO: 'bar' } },
+});
+
+assert.throws(run, (e) => {
+    assert(e instanceof WasiExitError, 'proc_exit should throw WasiExitError');
+    assert.strictEqual(e.code, 3);
+    return true;
+});
+"#,
+    )
+    .unwrap();
+
+    Command::new("node")
+        .arg("run.mjs")
+        .current_dir(&out_dir)
+        .assert()
+        .stdout("args: [\"prog\", \"a b\"]\nFOO=bar\n")
+        .stderr("to stderr\n")
+        .success();
+}
+
+#[test]
+fn wasi_shim_configure_works() {
+    let out_dir = Project::new("wasi_shim_configure_works")
+        .target("wasm32-wasip1")
+        .file(
+            "src/lib.rs",
+            r#"
+                use wasm_bindgen::prelude::*;
+
+                #[wasm_bindgen]
+                pub fn run() {
+                    let args = std::env::args().collect::<Vec<_>>();
+                    println!("args: {args:?}");
+                    println!("FOO={}", std::env::var("FOO").unwrap());
+                }
+            "#,
+        )
+        .wasm_bindgen("--target nodejs --wasi-shim")
+        .unwrap();
+
+    fs::write(
+        out_dir.join("run.js"),
+        r#"
+const { configureWasi, run } = require('./wasi_shim_configure_works.js');
+
+configureWasi({ args: ['prog', 'a b'], env: { FOO: 'bar' } });
+run();
+"#,
+    )
+    .unwrap();
+
+    Command::new("node")
+        .arg("run.js")
+        .current_dir(&out_dir)
+        .assert()
+        .stdout("args: [\"prog\", \"a b\"]\nFOO=bar\n")
+        .success();
+}
+
+#[test]
+fn source_map_works() {
+    let out_dir = Project::new("source_map_works")
+        .file(
+            "src/lib.rs",
+            r#"
+                use wasm_bindgen::prelude::*;
+
+                #[wasm_bindgen]
+                pub fn add_one(x: u32) -> u32 {
+                    x + 1
+                }
+            "#,
+        )
+        .file(
+            "Cargo.toml",
+            &format!(
+                "
+                    [package]
+                    name = \"source_map_works\"
+                    authors = []
+                    version = \"1.0.0\"
+                    edition = '2021'
+
+                    [dependencies]
+                    wasm-bindgen = {{ path = '{}', features = ['spans'] }}
+
+                    [lib]
+                    crate-type = ['cdylib']
+
+                    [workspace]
+                ",
+                REPO_ROOT.display(),
+            ),
+        )
+        .wasm_bindgen("--target nodejs")
+        .unwrap();
+
+    let js = fs::read_to_string(out_dir.join("source_map_works.js")).unwrap();
+    assert!(
+        js.ends_with("//# sourceMappingURL=source_map_works.js.map\n"),
+        "missing `sourceMappingURL` comment"
+    );
+
+    let map = fs::read_to_string(out_dir.join("source_map_works.js.map")).unwrap();
+    let map: serde_json::Value = serde_json::from_str(&map).unwrap();
+    assert_eq!(map["version"], 3);
+    let sources = map["sources"].as_array().unwrap();
+    let lib_rs = sources
+        .iter()
+        .map(|source| Path::new(source.as_str().unwrap()))
+        .find(|source| source.ends_with("src/lib.rs"))
+        .unwrap_or_else(|| panic!("no `src/lib.rs` in {sources:?}"));
+    assert!(lib_rs.is_absolute(), "{lib_rs:?} is not absolute");
+    assert!(lib_rs.is_file());
+    assert!(!map["mappings"].as_str().unwrap().is_empty());
+}
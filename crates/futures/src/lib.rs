@@ -68,12 +68,17 @@ pub mod stream;
 pub use js_sys;
 pub use wasm_bindgen;
 
+#[cfg(target_feature = "atomics")]
+pub mod sync;
+
 cfg_if! {
     if #[cfg(all(target_feature = "atomics", target_os = "wasi"))] {
         pub mod thread;
         mod sync_wrapper;
         mod thread_spawn;
+        mod thread_pool;
         pub use thread_spawn::*;
+        pub use thread_pool::*;
     }
 }
 
@@ -1,17 +1,24 @@
-//! Converting JavaScript `AsyncIterator`s to Rust `Stream`s.
+//! Converting between JavaScript `AsyncIterator`s and Rust `Stream`s.
 //!
 //! Analogous to the promise to future conversion, this module allows
 //! turning objects implementing the async iterator protocol into `Stream`s
-//! that produce values that can be awaited from.
+//! that produce values that can be awaited from, and passing `Stream`s to
+//! JavaScript as async iterators with [`JsAsyncIterator`].
 //!
 
-use crate::JsFuture;
-use core::future::Future;
+use crate::{future_to_promise, JsFuture};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::fmt;
+use core::future::{poll_fn, Future};
+use core::panic::AssertUnwindSafe;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::stream::Stream;
-use js_sys::{AsyncIterator, IteratorNext};
-use wasm_bindgen::convert::FromWasmAbi;
+use js_sys::{AsyncIterator, IteratorNext, Promise};
+use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, OptionIntoWasmAbi};
+use wasm_bindgen::describe::{inform, WasmDescribe, ASYNC_ITERATOR};
 use wasm_bindgen::{prelude::*, JsGeneric};
 
 /// A `Stream` that yields values from an underlying `AsyncIterator`.
@@ -79,3 +86,98 @@ impl<T: 'static + JsGeneric + FromWasmAbi + Unpin> Stream for JsStream<T> {
         }
     }
 }
+
+/// A Rust `Stream` which is passed to JavaScript as an async iterable
+/// iterator.
+///
+/// Each item is converted with `JsValue::from` when JavaScript requests it,
+/// for example from a `for await...of` loop, and the generated TypeScript
+/// types it as `AsyncIterableIterator<T>`. The stream is freed once it's
+/// exhausted, closed with `return()` or garbage collected.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures_util::stream;
+/// use wasm_bindgen::prelude::*;
+/// use wasm_bindgen_futures::stream::JsAsyncIterator;
+///
+/// #[wasm_bindgen]
+/// pub fn countdown(from: u32) -> JsAsyncIterator<u32> {
+///     JsAsyncIterator::new(stream::iter((0..=from).rev()))
+/// }
+/// ```
+pub struct JsAsyncIterator<T> {
+    stream: Pin<Box<dyn Stream<Item = T>>>,
+}
+
+impl<T> JsAsyncIterator<T> {
+    /// Wraps a stream to be passed to JavaScript.
+    pub fn new<S>(stream: S) -> Self
+    where
+        S: Stream<Item = T> + 'static,
+    {
+        JsAsyncIterator {
+            stream: Box::pin(stream),
+        }
+    }
+}
+
+impl<T> fmt::Debug for JsAsyncIterator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsAsyncIterator").finish_non_exhaustive()
+    }
+}
+
+impl<T: WasmDescribe> WasmDescribe for JsAsyncIterator<T> {
+    fn describe() {
+        inform(ASYNC_ITERATOR);
+        T::describe();
+    }
+}
+
+impl<T: 'static> From<JsAsyncIterator<T>> for JsValue
+where
+    JsValue: From<T>,
+{
+    fn from(iter: JsAsyncIterator<T>) -> JsValue {
+        let stream = Rc::new(RefCell::new(iter.stream));
+        // The JS glue makes sure only one item is requested at a time, and
+        // passes a sentinel which is resolved with once the stream is done.
+        let next = Closure::<dyn FnMut(JsValue) -> Promise>::own_assert_unwind_safe(
+            move |done: JsValue| {
+                let stream = stream.clone();
+                future_to_promise(AssertUnwindSafe(async move {
+                    let item = poll_fn(|cx| stream.borrow_mut().as_mut().poll_next(cx)).await;
+                    Ok(match item {
+                        Some(item) => JsValue::from(item),
+                        None => done,
+                    })
+                }))
+            },
+        );
+        wasm_bindgen::__rt::make_async_iterator(next.into_js_value())
+    }
+}
+
+impl<T: WasmDescribe + 'static> IntoWasmAbi for JsAsyncIterator<T>
+where
+    JsValue: From<T>,
+{
+    type Abi = u32;
+
+    #[inline]
+    fn into_abi(self) -> u32 {
+        <JsValue as From<Self>>::from(self).into_abi()
+    }
+}
+
+impl<T: WasmDescribe + 'static> OptionIntoWasmAbi for JsAsyncIterator<T>
+where
+    JsValue: From<T>,
+{
+    #[inline]
+    fn none() -> u32 {
+        JsValue::none()
+    }
+}
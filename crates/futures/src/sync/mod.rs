@@ -0,0 +1,148 @@
+//! Asynchronous synchronization primitives for multithreaded WebAssembly.
+//!
+//! Blocking waits with `memory.atomic.wait` are prohibited on the browser
+//! main thread. The primitives in this module never block. Instead, waiting
+//! tasks are suspended and woken up again by the executor, which parks on
+//! `Atomics.waitAsync` (or its polyfill). This allows held threads and the
+//! main thread to coordinate without blocking and without tripping the
+//! spinning fallback of the wait transform.
+//!
+//! Internal state is protected by a spin lock that is only held for a few
+//! instructions and never across an `.await`.
+
+use alloc::collections::VecDeque;
+use core::cell::UnsafeCell;
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::Waker;
+
+mod mutex;
+mod notify;
+
+pub mod mpsc;
+
+pub use mutex::{Mutex, MutexGuard, MutexLock};
+pub use notify::{Notified, Notify};
+
+/// A lock for short critical sections that spins instead of waiting.
+struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// safety: access to the value is synchronized by the lock.
+unsafe impl<T: Send> Send for SpinLock<T> {}
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+impl<T> SpinLock<T> {
+    const fn new(value: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    fn lock(&self) -> SpinLockGuard<'_, T> {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        SpinLockGuard { lock: self }
+    }
+}
+
+struct SpinLockGuard<'a, T> {
+    lock: &'a SpinLock<T>,
+}
+
+impl<T> Deref for SpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for SpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for SpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.locked.store(false, Ordering::Release);
+    }
+}
+
+/// Queue of suspended tasks, woken up in order.
+///
+/// Each waiting future owns a key. Waking removes the waiter from the queue,
+/// so a future whose key is no longer queued has been woken up.
+struct WaitQueue {
+    waiters: VecDeque<(usize, Waker)>,
+    next_key: usize,
+}
+
+impl WaitQueue {
+    const fn new() -> Self {
+        Self {
+            waiters: VecDeque::new(),
+            next_key: 0,
+        }
+    }
+
+    /// Queues a waiter, or updates its waker if it is already queued.
+    fn register(&mut self, key: &mut Option<usize>, waker: &Waker) {
+        if let Some(key) = *key {
+            if let Some((_, queued)) = self.waiters.iter_mut().find(|(k, _)| *k == key) {
+                if !queued.will_wake(waker) {
+                    queued.clone_from(waker);
+                }
+                return;
+            }
+        }
+
+        let new_key = self.next_key;
+        self.next_key = self.next_key.wrapping_add(1);
+        self.waiters.push_back((new_key, waker.clone()));
+        *key = Some(new_key);
+    }
+
+    /// Returns whether the waiter is still queued.
+    fn contains(&self, key: usize) -> bool {
+        self.waiters.iter().any(|(k, _)| *k == key)
+    }
+
+    /// Removes a waiter. Returns `false` if it has already been woken up.
+    fn remove(&mut self, key: usize) -> bool {
+        match self.waiters.iter().position(|(k, _)| *k == key) {
+            Some(index) => {
+                self.waiters.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Wakes up the longest waiting task. Returns `false` if there was none.
+    fn wake_one(&mut self) -> bool {
+        match self.waiters.pop_front() {
+            Some((_, waker)) => {
+                waker.wake();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Wakes up all waiting tasks.
+    fn wake_all(&mut self) {
+        for (_, waker) in self.waiters.drain(..) {
+            waker.wake();
+        }
+    }
+}
@@ -0,0 +1,338 @@
+//! A bounded multi-producer, single-consumer channel.
+
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use super::{SpinLock, WaitQueue};
+
+/// Creates a bounded channel that buffers up to `capacity` messages.
+///
+/// Sending waits asynchronously while the buffer is full, receiving waits
+/// asynchronously while it is empty.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+pub fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "channel capacity must be greater than zero");
+
+    let shared = Arc::new(SpinLock::new(State {
+        buffer: VecDeque::with_capacity(capacity),
+        capacity,
+        senders: 1,
+        receiver: true,
+        send_queue: WaitQueue::new(),
+        recv_queue: WaitQueue::new(),
+    }));
+
+    let sender = Sender {
+        shared: shared.clone(),
+    };
+    let receiver = Receiver { shared };
+    (sender, receiver)
+}
+
+struct State<T> {
+    buffer: VecDeque<T>,
+    capacity: usize,
+    senders: usize,
+    receiver: bool,
+    send_queue: WaitQueue,
+    recv_queue: WaitQueue,
+}
+
+/// The sending half of a channel.
+pub struct Sender<T> {
+    shared: Arc<SpinLock<State<T>>>,
+}
+
+impl<T> Sender<T> {
+    /// Sends a message, waiting asynchronously until there is capacity.
+    ///
+    /// Fails if the receiver has been dropped.
+    pub fn send(&self, value: T) -> SendFut<'_, T> {
+        SendFut {
+            sender: self,
+            value: Some(value),
+            key: None,
+        }
+    }
+
+    /// Sends a message if there is capacity right away.
+    pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        let mut state = self.shared.lock();
+        if !state.receiver {
+            return Err(TrySendError::Closed(value));
+        }
+        if state.buffer.len() == state.capacity {
+            return Err(TrySendError::Full(value));
+        }
+        state.buffer.push_back(value);
+        state.recv_queue.wake_one();
+        Ok(())
+    }
+
+    /// Returns whether the receiver has been dropped.
+    pub fn is_closed(&self) -> bool {
+        !self.shared.lock().receiver
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+        if state.senders == 0 {
+            state.recv_queue.wake_all();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Sender").finish_non_exhaustive()
+    }
+}
+
+/// Future returned by [`Sender::send`].
+pub struct SendFut<'a, T> {
+    sender: &'a Sender<T>,
+    value: Option<T>,
+    key: Option<usize>,
+}
+
+impl<T> Unpin for SendFut<'_, T> {}
+
+impl<T> Future for SendFut<'_, T> {
+    type Output = Result<(), SendError<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let mut state = this.sender.shared.lock();
+        let value = this
+            .value
+            .take()
+            .expect("`SendFut` polled after completion");
+
+        if !state.receiver {
+            this.key = None;
+            return Poll::Ready(Err(SendError(value)));
+        }
+
+        if state.buffer.len() < state.capacity {
+            if let Some(key) = this.key.take() {
+                state.send_queue.remove(key);
+            }
+            state.buffer.push_back(value);
+            state.recv_queue.wake_one();
+            return Poll::Ready(Ok(()));
+        }
+
+        this.value = Some(value);
+        state.send_queue.register(&mut this.key, cx.waker());
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for SendFut<'_, T> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            let mut state = self.sender.shared.lock();
+            // Pass on capacity that this future didn't use.
+            if !state.send_queue.remove(key) {
+                state.send_queue.wake_one();
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for SendFut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SendFut").finish_non_exhaustive()
+    }
+}
+
+/// The receiving half of a channel.
+pub struct Receiver<T> {
+    shared: Arc<SpinLock<State<T>>>,
+}
+
+impl<T> Receiver<T> {
+    /// Receives the next message, waiting asynchronously until one is
+    /// available.
+    ///
+    /// Returns `None` once all senders have been dropped and the buffer is
+    /// empty.
+    pub fn recv(&mut self) -> Recv<'_, T> {
+        Recv {
+            receiver: self,
+            key: None,
+        }
+    }
+
+    /// Receives the next message if one is available right away.
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let mut state = self.shared.lock();
+        match state.buffer.pop_front() {
+            Some(value) => {
+                state.send_queue.wake_one();
+                Ok(value)
+            }
+            None if state.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.receiver = false;
+        state.send_queue.wake_all();
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
+
+/// Future returned by [`Receiver::recv`].
+pub struct Recv<'a, T> {
+    receiver: &'a mut Receiver<T>,
+    key: Option<usize>,
+}
+
+impl<T> Future for Recv<'_, T> {
+    type Output = Option<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let mut state = this.receiver.shared.lock();
+
+        if let Some(value) = state.buffer.pop_front() {
+            if let Some(key) = this.key.take() {
+                state.recv_queue.remove(key);
+            }
+            state.send_queue.wake_one();
+            return Poll::Ready(Some(value));
+        }
+
+        if state.senders == 0 {
+            this.key = None;
+            return Poll::Ready(None);
+        }
+
+        state.recv_queue.register(&mut this.key, cx.waker());
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for Recv<'_, T> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.receiver.shared.lock().recv_queue.remove(key);
+        }
+    }
+}
+
+impl<T> fmt::Debug for Recv<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Recv").finish_non_exhaustive()
+    }
+}
+
+/// Error returned by [`Sender::send`] if the receiver has been dropped.
+///
+/// Contains the message that couldn't be sent.
+#[derive(PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SendError").finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "channel closed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for SendError<T> {}
+
+/// Error returned by [`Sender::try_send`].
+#[derive(PartialEq, Eq)]
+pub enum TrySendError<T> {
+    /// The buffer of the channel is full.
+    Full(T),
+    /// The receiver has been dropped.
+    Closed(T),
+}
+
+impl<T> TrySendError<T> {
+    /// Returns the message that couldn't be sent.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Full(value) | Self::Closed(value) => value,
+        }
+    }
+}
+
+impl<T> fmt::Debug for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Full(_) => write!(f, "Full(..)"),
+            Self::Closed(_) => write!(f, "Closed(..)"),
+        }
+    }
+}
+
+impl<T> fmt::Display for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Full(_) => write!(f, "channel full"),
+            Self::Closed(_) => write!(f, "channel closed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for TrySendError<T> {}
+
+/// Error returned by [`Receiver::try_recv`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// The buffer of the channel is empty.
+    Empty,
+    /// All senders have been dropped and the buffer is empty.
+    Disconnected,
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "channel empty"),
+            Self::Disconnected => write!(f, "channel disconnected"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryRecvError {}
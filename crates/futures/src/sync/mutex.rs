@@ -0,0 +1,164 @@
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::future::Future;
+use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use super::{SpinLock, WaitQueue};
+
+/// An asynchronous mutual exclusion lock.
+///
+/// Unlike [`std::sync::Mutex`], locking never blocks the thread, so it can be
+/// used on the browser main thread. Tasks waiting for the lock are suspended
+/// until it is released.
+pub struct Mutex<T: ?Sized> {
+    state: SpinLock<State>,
+    value: UnsafeCell<T>,
+}
+
+struct State {
+    locked: bool,
+    queue: WaitQueue,
+}
+
+// safety: access to the value is synchronized by the lock.
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+    /// Creates a new unlocked mutex.
+    pub const fn new(value: T) -> Self {
+        Self {
+            state: SpinLock::new(State {
+                locked: false,
+                queue: WaitQueue::new(),
+            }),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consumes the mutex, returning the underlying data.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    /// Acquires the lock, waiting asynchronously until it is available.
+    pub fn lock(&self) -> MutexLock<'_, T> {
+        MutexLock {
+            mutex: self,
+            key: None,
+        }
+    }
+
+    /// Acquires the lock if it is available right away.
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        let mut state = self.state.lock();
+        if state.locked {
+            return None;
+        }
+        state.locked = true;
+        Some(MutexGuard { mutex: self })
+    }
+
+    /// Returns a mutable reference to the underlying data.
+    ///
+    /// No locking is needed, because the mutex is borrowed mutably.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
+
+impl<T: Default> Default for Mutex<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Mutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Mutex").finish_non_exhaustive()
+    }
+}
+
+/// Future returned by [`Mutex::lock`].
+pub struct MutexLock<'a, T: ?Sized> {
+    mutex: &'a Mutex<T>,
+    key: Option<usize>,
+}
+
+impl<'a, T: ?Sized> Future for MutexLock<'a, T> {
+    type Output = MutexGuard<'a, T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mutex = self.mutex;
+        let mut state = mutex.state.lock();
+
+        if !state.locked {
+            state.locked = true;
+            if let Some(key) = self.key.take() {
+                state.queue.remove(key);
+            }
+            return Poll::Ready(MutexGuard { mutex });
+        }
+
+        state.queue.register(&mut self.key, cx.waker());
+        Poll::Pending
+    }
+}
+
+impl<T: ?Sized> Drop for MutexLock<'_, T> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            let mut state = self.mutex.state.lock();
+            // Pass on a wakeup that this future didn't use.
+            if !state.queue.remove(key) {
+                state.queue.wake_one();
+            }
+        }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for MutexLock<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MutexLock").finish_non_exhaustive()
+    }
+}
+
+/// A guard releasing the lock of a [`Mutex`] when dropped.
+pub struct MutexGuard<'a, T: ?Sized> {
+    mutex: &'a Mutex<T>,
+}
+
+// safety: the guard only gives access to the value.
+unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for MutexGuard<'_, T> {
+    fn drop(&mut self) {
+        let mut state = self.mutex.state.lock();
+        state.locked = false;
+        state.queue.wake_one();
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for MutexGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
@@ -0,0 +1,145 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use super::{SpinLock, WaitQueue};
+
+/// Notifies a single task or all waiting tasks.
+///
+/// [`Notify::notify_one`] wakes up the longest waiting task. If no task is
+/// waiting, a permit is stored and the next call to [`Notify::notified`]
+/// completes right away. [`Notify::notify_waiters`] wakes up all tasks that
+/// are currently waiting, without storing a permit.
+pub struct Notify {
+    state: SpinLock<State>,
+}
+
+struct State {
+    permit: bool,
+    /// Incremented by every call to [`Notify::notify_waiters`].
+    generation: usize,
+    queue: WaitQueue,
+}
+
+impl Notify {
+    /// Creates a new `Notify` without a stored permit.
+    pub const fn new() -> Self {
+        Self {
+            state: SpinLock::new(State {
+                permit: false,
+                generation: 0,
+                queue: WaitQueue::new(),
+            }),
+        }
+    }
+
+    /// Waits for a notification.
+    ///
+    /// The returned future also completes if [`Notify::notify_waiters`] is
+    /// called after this, even before the future is polled for the first
+    /// time.
+    pub fn notified(&self) -> Notified<'_> {
+        Notified {
+            notify: self,
+            generation: self.state.lock().generation,
+            key: None,
+            done: false,
+        }
+    }
+
+    /// Wakes up the longest waiting task, or stores a permit for the next one.
+    pub fn notify_one(&self) {
+        let mut state = self.state.lock();
+        if !state.queue.wake_one() {
+            state.permit = true;
+        }
+    }
+
+    /// Wakes up all currently waiting tasks.
+    pub fn notify_waiters(&self) {
+        let mut state = self.state.lock();
+        state.generation = state.generation.wrapping_add(1);
+        state.queue.wake_all();
+    }
+}
+
+impl Default for Notify {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Notify {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Notify").finish_non_exhaustive()
+    }
+}
+
+/// Future returned by [`Notify::notified`].
+pub struct Notified<'a> {
+    notify: &'a Notify,
+    generation: usize,
+    key: Option<usize>,
+    done: bool,
+}
+
+impl Future for Notified<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.done {
+            return Poll::Ready(());
+        }
+
+        let notify = self.notify;
+        let mut state = notify.state.lock();
+
+        let notified = if state.generation != self.generation {
+            // Woken up by `notify_waiters`.
+            if let Some(key) = self.key {
+                state.queue.remove(key);
+            }
+            true
+        } else if let Some(key) = self.key {
+            // Woken up by `notify_one`.
+            !state.queue.contains(key)
+        } else if state.permit {
+            state.permit = false;
+            true
+        } else {
+            false
+        };
+
+        if notified {
+            self.key = None;
+            self.done = true;
+            return Poll::Ready(());
+        }
+
+        state.queue.register(&mut self.key, cx.waker());
+        Poll::Pending
+    }
+}
+
+impl Drop for Notified<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            let mut state = self.notify.state.lock();
+            // Pass on a notification from `notify_one` that this future
+            // didn't use.
+            if !state.queue.remove(key)
+                && state.generation == self.generation
+                && !state.queue.wake_one()
+            {
+                state.permit = true;
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Notified<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Notified").finish_non_exhaustive()
+    }
+}
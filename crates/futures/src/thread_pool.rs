@@ -0,0 +1,178 @@
+//! A pool of reusable threads.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use futures_channel::{mpsc, oneshot};
+use futures_util::stream::FuturesUnordered;
+use futures_util::{select_biased, StreamExt};
+
+use crate::spawn_local;
+use crate::thread::{thread_hold, thread_release};
+use crate::thread_spawn::{join_handle, JoinHandle};
+
+/// A job creating the future of a task on the worker thread.
+type Job = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()>>> + Send>;
+
+/// A pool of threads that run tasks.
+///
+/// Spawning a thread is expensive, because each thread instantiates the
+/// WebAssembly module in a new worker. A thread pool keeps a fixed number
+/// of held threads alive and dispatches tasks to them in turn. Each thread
+/// runs the tasks dispatched to it concurrently.
+///
+/// If a worker thread dies, for example because creating the future of a
+/// task panicked, all tasks running on it fail with a [`JoinError`] for
+/// which [`JoinError::is_failed`] returns true. Further tasks are dispatched
+/// to the remaining threads.
+///
+/// Dropping the pool lets all threads exit once their tasks have completed.
+/// Use [`ThreadPool::shutdown`] to also wait for that.
+///
+/// [`JoinError`]: crate::JoinError
+/// [`JoinError::is_failed`]: crate::JoinError::is_failed
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    next: AtomicUsize,
+}
+
+struct Worker {
+    job_tx: mpsc::UnboundedSender<Job>,
+    exit_rx: oneshot::Receiver<()>,
+}
+
+impl fmt::Debug for ThreadPool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ThreadPool")
+            .field("threads", &self.workers.len())
+            .finish()
+    }
+}
+
+/// Releases the current thread when dropped, also if the worker panicked.
+struct ReleaseGuard;
+
+impl Drop for ReleaseGuard {
+    fn drop(&mut self) {
+        unsafe { thread_release() };
+    }
+}
+
+impl ThreadPool {
+    /// Creates a thread pool with the given number of threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero or a thread cannot be spawned.
+    pub fn new(threads: usize) -> ThreadPool {
+        assert!(threads > 0, "a thread pool needs at least one thread");
+
+        let workers = (0..threads)
+            .map(|_| {
+                let (job_tx, job_rx) = mpsc::unbounded::<Job>();
+                let (exit_tx, exit_rx) = oneshot::channel();
+
+                std::thread::spawn(move || {
+                    unsafe { thread_hold() };
+
+                    spawn_local(async move {
+                        let _release = ReleaseGuard;
+                        let mut jobs = job_rx;
+                        let mut tasks = FuturesUnordered::new();
+
+                        // Run tasks until the pool is dropped and all tasks
+                        // have completed.
+                        loop {
+                            select_biased! {
+                                job = jobs.select_next_some() => tasks.push(job()),
+                                () = tasks.select_next_some() => (),
+                                complete => break,
+                            }
+                        }
+
+                        let _ = exit_tx.send(());
+                    });
+                });
+
+                Worker { job_tx, exit_rx }
+            })
+            .collect();
+
+        ThreadPool {
+            workers,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of threads in the pool.
+    pub fn threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Spawns a `Future` on a thread of the pool.
+    ///
+    /// The closure is sent to the thread and called there to create the
+    /// future, which therefore doesn't need to be `Send`.
+    pub fn spawn<T, F, U>(&self, f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> U + Send + 'static,
+        U: Future<Output = T> + 'static,
+        T: Send + 'static,
+    {
+        let (handle, runner) = join_handle();
+        let mut job: Job =
+            Box::new(move || -> Pin<Box<dyn Future<Output = ()>>> { Box::pin(runner.run(f())) });
+
+        // Skip threads that have died. If all have, the runner is dropped
+        // together with the job and the task is reported as failed.
+        for _ in 0..self.workers.len() {
+            let index = self.next.fetch_add(1, Ordering::Relaxed) % self.workers.len();
+            match self.workers[index].job_tx.unbounded_send(job) {
+                Ok(()) => break,
+                Err(err) => job = err.into_inner(),
+            }
+        }
+
+        handle
+    }
+
+    /// Spawns a `Send` future on a thread of the pool.
+    pub fn spawn_future<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.spawn(move || future)
+    }
+
+    /// Runs a closure on a thread of the pool.
+    pub fn execute<T, F>(&self, f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        self.spawn(move || async move { f() })
+    }
+
+    /// Shuts down the pool gracefully.
+    ///
+    /// Already spawned tasks run to completion. The returned future resolves
+    /// once all threads have exited.
+    pub async fn shutdown(self) {
+        let exits: Vec<_> = self
+            .workers
+            .into_iter()
+            .map(|worker| {
+                worker.job_tx.close_channel();
+                worker.exit_rx
+            })
+            .collect();
+
+        for exit in exits {
+            // A thread that died has exited as well.
+            let _ = exit.await;
+        }
+    }
+}
@@ -10,6 +10,8 @@ use std::task::{ready, Context, Poll};
 
 use futures_channel::oneshot;
 use futures_util::{select_biased, FutureExt};
+use js_sys::Object;
+use wasm_bindgen::prelude::*;
 
 use crate::spawn_local;
 use crate::sync_wrapper::SyncWrapper;
@@ -132,43 +134,229 @@ impl<T> JoinHandle<T> {
     }
 }
 
-/// Spawns a `Future` on a new thread.
-pub fn spawn_thread<T, F, U>(f: F) -> JoinHandle<T>
-where
-    F: FnOnce() -> U + Send + 'static,
-    U: Future<Output = T> + 'static,
-    T: Send + 'static,
-{
-    let (result_tx, result_rx) = oneshot::channel();
-    let (abort_tx, mut abort_rx) = oneshot::channel();
+/// Hook called with the payload of a panicking task.
+type PanicHook = Box<dyn FnOnce(&(dyn Any + Send)) + Send + 'static>;
 
-    std::thread::spawn(move || {
-        unsafe { thread_hold() };
+/// The receiving end of a [`JoinHandle`], running the task on its thread.
+pub(crate) struct TaskRunner<T> {
+    result_tx: oneshot::Sender<Result<T, JoinError>>,
+    abort_rx: oneshot::Receiver<()>,
+    panic_hook: Option<PanicHook>,
+}
 
-        spawn_local(async move {
-            let future = pin!(AssertUnwindSafe(f()).catch_unwind());
-            let mut future = future.fuse();
+impl<T> TaskRunner<T> {
+    /// Runs the future to completion, unless it is aborted, and sends its
+    /// result to the join handle.
+    ///
+    /// If the runner is dropped before that, the task is reported as failed.
+    pub(crate) async fn run(self, future: impl Future<Output = T>) {
+        let Self {
+            result_tx,
+            mut abort_rx,
+            panic_hook,
+        } = self;
 
-            let res = loop {
-                select_biased! {
-                    res = &mut future => {
-                        break res.map_err(|err| JoinError(Repr::Panicked(SyncWrapper::new(err))));
-                    }
-                    res = abort_rx => {
-                        if res.is_ok() {
-                            break Err(JoinError(Repr::Aborted));
+        let future = pin!(AssertUnwindSafe(future).catch_unwind());
+        let mut future = future.fuse();
+
+        let res = loop {
+            select_biased! {
+                res = &mut future => {
+                    break res.map_err(|err| {
+                        if let Some(hook) = panic_hook {
+                            hook(&*err);
                         }
+                        JoinError(Repr::Panicked(SyncWrapper::new(err)))
+                    });
+                }
+                res = abort_rx => {
+                    if res.is_ok() {
+                        break Err(JoinError(Repr::Aborted));
                     }
                 }
-            };
+            }
+        };
+
+        let _ = result_tx.send(res);
+    }
+}
 
-            let _ = result_tx.send(res);
-            unsafe { thread_release() };
-        });
-    });
+/// Creates a join handle together with the runner of its task.
+pub(crate) fn join_handle<T>() -> (JoinHandle<T>, TaskRunner<T>) {
+    let (result_tx, result_rx) = oneshot::channel();
+    let (abort_tx, abort_rx) = oneshot::channel();
 
-    JoinHandle {
+    let handle = JoinHandle {
         result_rx,
         abort_tx: Some(abort_tx),
+    };
+    let runner = TaskRunner {
+        result_tx,
+        abort_rx,
+        panic_hook: None,
+    };
+    (handle, runner)
+}
+
+/// Thread factory, which can be used in order to configure the properties of
+/// a new thread.
+///
+/// Threads are held after their start function has returned, so that the
+/// spawned future can run on the event loop of the thread. By default the
+/// thread is released once the future has completed.
+pub struct ThreadBuilder {
+    builder: std::thread::Builder,
+    panic_hook: Option<PanicHook>,
+    keep_alive: bool,
+}
+
+impl fmt::Debug for ThreadBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ThreadBuilder")
+            .field("builder", &self.builder)
+            .field("keep_alive", &self.keep_alive)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for ThreadBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThreadBuilder {
+    /// Creates a builder with the default configuration.
+    pub fn new() -> Self {
+        Self {
+            builder: std::thread::Builder::new(),
+            panic_hook: None,
+            keep_alive: false,
+        }
+    }
+
+    /// Names the thread.
+    ///
+    /// The name is returned by [`std::thread::Thread::name`] on the new
+    /// thread and included in its panic messages. It is also set as `name`
+    /// of the JS global scope of the thread, e.g. `self.name` in a worker, so
+    /// JS code and the devtools console of the worker can see it.
+    ///
+    /// The `Worker` itself is created by the WASI runtime before the thread
+    /// starts, so the name passed to its constructor can't be changed.
+    pub fn name(mut self, name: String) -> Self {
+        self.builder = self.builder.name(name);
+        self
     }
+
+    /// Sets the size of the stack, in bytes, for the new thread.
+    pub fn stack_size(mut self, size: usize) -> Self {
+        self.builder = self.builder.stack_size(size);
+        self
+    }
+
+    /// Sets a hook that is called on the new thread with the payload of the
+    /// panic if the spawned future panics.
+    ///
+    /// The panic is still reported through the [`JoinHandle`].
+    pub fn on_panic<H>(mut self, hook: H) -> Self
+    where
+        H: FnOnce(&(dyn Any + Send)) + Send + 'static,
+    {
+        self.panic_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Keeps holding the thread after the spawned future has completed, so
+    /// that tasks it started with [`spawn_local`] can continue to run.
+    ///
+    /// Such a thread has to be released by calling
+    /// [`thread_release`](crate::thread::thread_release) on it.
+    pub fn keep_alive(mut self, keep_alive: bool) -> Self {
+        self.keep_alive = keep_alive;
+        self
+    }
+
+    /// Spawns a `Future` on a new thread with this configuration.
+    ///
+    /// Fails if the thread could not be spawned.
+    pub fn spawn<T, F, U>(self, f: F) -> std::io::Result<JoinHandle<T>>
+    where
+        F: FnOnce() -> U + Send + 'static,
+        U: Future<Output = T> + 'static,
+        T: Send + 'static,
+    {
+        let Self {
+            builder,
+            panic_hook,
+            keep_alive,
+        } = self;
+
+        let (handle, mut runner) = join_handle();
+        runner.panic_hook = panic_hook;
+
+        builder.spawn(move || {
+            if let Some(name) = std::thread::current().name() {
+                set_global_name(name);
+            }
+
+            unsafe { thread_hold() };
+
+            spawn_local(async move {
+                runner.run(f()).await;
+                if !keep_alive {
+                    unsafe { thread_release() };
+                }
+            });
+        })?;
+
+        Ok(handle)
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    type Descriptor;
+
+    #[wasm_bindgen(method, setter)]
+    fn set_value(this: &Descriptor, value: &str);
+
+    #[wasm_bindgen(method, setter)]
+    fn set_configurable(this: &Descriptor, configurable: bool);
+
+    #[wasm_bindgen(js_namespace = Object, js_name = defineProperty, catch)]
+    fn define_property(
+        obj: &Object,
+        prop: &str,
+        descriptor: &Descriptor,
+    ) -> Result<Object, JsValue>;
+}
+
+/// Sets `name` of the global scope of the current thread.
+///
+/// `DedicatedWorkerGlobalScope` only has a getter for `name`, so it is shadowed
+/// by a property of the global object instead of being assigned.
+fn set_global_name(name: &str) {
+    let descriptor: Descriptor = Object::new().unchecked_into();
+    descriptor.set_value(name);
+    descriptor.set_configurable(true);
+    let _ = define_property(&js_sys::global(), "name", &descriptor);
+}
+
+/// Spawns a `Future` on a new thread.
+///
+/// Use [`ThreadBuilder`] to configure the thread.
+///
+/// # Panics
+///
+/// Panics if the thread could not be spawned.
+pub fn spawn_thread<T, F, U>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> U + Send + 'static,
+    U: Future<Output = T> + 'static,
+    T: Send + 'static,
+{
+    ThreadBuilder::new()
+        .spawn(f)
+        .expect("failed to spawn thread")
 }
@@ -202,6 +202,219 @@ async fn wait_async_promise_callback_runs_without_wake() {
     done_rx.await.expect("task finished");
 }
 
+#[cfg(target_feature = "atomics")]
+#[wasm_bindgen_test]
+async fn sync_mutex_suspends_until_unlocked() {
+    use std::sync::Arc;
+    use wasm_bindgen_futures::sync::Mutex;
+
+    let mutex = Arc::new(Mutex::new(0));
+    let guard = mutex.lock().await;
+    assert!(mutex.try_lock().is_none());
+
+    let (done_tx, done_rx) = oneshot::channel::<()>();
+    spawn_local({
+        let mutex = mutex.clone();
+        async move {
+            *mutex.lock().await += 1;
+            done_tx.send(()).ok();
+        }
+    });
+
+    JsFuture::from(Promise::resolve(&JsValue::NULL))
+        .await
+        .unwrap();
+    drop(guard);
+    done_rx.await.expect("task finished");
+    assert_eq!(*mutex.lock().await, 1);
+}
+
+#[cfg(target_feature = "atomics")]
+#[wasm_bindgen_test]
+async fn sync_notify_wakes_waiter() {
+    use std::sync::Arc;
+    use wasm_bindgen_futures::sync::Notify;
+
+    let notify = Arc::new(Notify::new());
+    let (done_tx, done_rx) = oneshot::channel::<()>();
+    spawn_local({
+        let notify = notify.clone();
+        async move {
+            notify.notified().await;
+            done_tx.send(()).ok();
+        }
+    });
+
+    notify.notify_one();
+    done_rx.await.expect("task finished");
+}
+
+#[cfg(target_feature = "atomics")]
+#[wasm_bindgen_test]
+async fn sync_mpsc_applies_backpressure() {
+    use wasm_bindgen_futures::sync::mpsc::{self, TrySendError};
+
+    let (tx, mut rx) = mpsc::channel(1);
+    tx.send(1).await.unwrap();
+    assert_eq!(tx.try_send(2), Err(TrySendError::Full(2)));
+
+    spawn_local(async move {
+        tx.send(2).await.unwrap();
+        tx.send(3).await.unwrap();
+    });
+
+    assert_eq!(rx.recv().await, Some(1));
+    assert_eq!(rx.recv().await, Some(2));
+    assert_eq!(rx.recv().await, Some(3));
+    assert_eq!(rx.recv().await, None);
+}
+
+#[cfg(all(target_feature = "atomics", target_os = "wasi"))]
+#[wasm_bindgen_test]
+async fn thread_builder_names_thread() {
+    use wasm_bindgen_futures::ThreadBuilder;
+
+    let (name, global_name) = ThreadBuilder::new()
+        .name("worker".to_string())
+        .spawn(|| async {
+            let global_name = js_sys::Reflect::get(&js_sys::global(), &"name".into()).unwrap();
+            (
+                std::thread::current().name().map(String::from),
+                global_name.as_string(),
+            )
+        })
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(name.as_deref(), Some("worker"));
+    assert_eq!(global_name.as_deref(), Some("worker"));
+}
+
+#[cfg(all(target_feature = "atomics", target_os = "wasi"))]
+#[wasm_bindgen_test]
+async fn thread_builder_sets_stack_size() {
+    use wasm_bindgen_futures::ThreadBuilder;
+
+    // Needs more than the default stack size of a thread.
+    #[inline(never)]
+    fn sum_large_buffer() -> usize {
+        let buf = std::hint::black_box([1u8; 2 << 20]);
+        buf.iter().map(|&b| usize::from(b)).sum()
+    }
+
+    let sum = ThreadBuilder::new()
+        .stack_size(4 << 20)
+        .spawn(|| async { sum_large_buffer() })
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(sum, 2 << 20);
+}
+
+#[cfg(all(target_feature = "atomics", target_os = "wasi", panic = "unwind"))]
+#[wasm_bindgen_test]
+async fn thread_builder_calls_panic_hook() {
+    use wasm_bindgen_futures::ThreadBuilder;
+
+    let (hook_tx, hook_rx) = oneshot::channel::<String>();
+    let err = ThreadBuilder::new()
+        .on_panic(move |payload| {
+            let msg = payload.downcast_ref::<&str>().copied().unwrap_or_default();
+            hook_tx.send(msg.to_string()).ok();
+        })
+        .spawn(|| async { panic!("boom") })
+        .unwrap()
+        .await
+        .unwrap_err();
+
+    assert!(err.is_panic());
+    assert_eq!(hook_rx.await.unwrap(), "boom");
+}
+
+#[cfg(all(target_feature = "atomics", target_os = "wasi"))]
+#[wasm_bindgen_test]
+async fn thread_builder_keeps_thread_alive() {
+    use wasm_bindgen_futures::thread::thread_release;
+    use wasm_bindgen_futures::ThreadBuilder;
+
+    let (go_tx, go_rx) = oneshot::channel::<()>();
+    let (done_tx, done_rx) = oneshot::channel::<()>();
+    ThreadBuilder::new()
+        .keep_alive(true)
+        .spawn(move || async move {
+            // Outlives the spawned future, which completes right away.
+            spawn_local(async move {
+                go_rx.await.unwrap();
+                done_tx.send(()).ok();
+                unsafe { thread_release() };
+            });
+        })
+        .unwrap()
+        .await
+        .unwrap();
+
+    go_tx.send(()).unwrap();
+    done_rx.await.expect("task on the kept thread finished");
+}
+
+#[cfg(all(target_feature = "atomics", target_os = "wasi"))]
+#[wasm_bindgen_test]
+async fn thread_pool_runs_tasks() {
+    use wasm_bindgen_futures::ThreadPool;
+
+    let pool = ThreadPool::new(2);
+    assert_eq!(pool.threads(), 2);
+
+    let main = std::thread::current().id();
+    let id = pool
+        .spawn(|| async { std::thread::current().id() })
+        .await
+        .unwrap();
+    assert_ne!(id, main);
+
+    assert_eq!(pool.spawn_future(async { 1 }).await.unwrap(), 1);
+    assert_eq!(pool.execute(|| 2).await.unwrap(), 2);
+}
+
+#[cfg(all(target_feature = "atomics", target_os = "wasi"))]
+#[wasm_bindgen_test]
+async fn thread_pool_shutdown_waits_for_tasks() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use wasm_bindgen_futures::ThreadPool;
+
+    let pool = ThreadPool::new(1);
+    let finished = Arc::new(AtomicBool::new(false));
+    let (go_tx, go_rx) = oneshot::channel::<()>();
+    let task = pool.spawn_future({
+        let finished = finished.clone();
+        async move {
+            go_rx.await.unwrap();
+            finished.store(true, Ordering::SeqCst);
+        }
+    });
+
+    let shutdown = pool.shutdown();
+    go_tx.send(()).unwrap();
+    shutdown.await;
+    assert!(finished.load(Ordering::SeqCst));
+    task.await.unwrap();
+}
+
+#[cfg(all(target_feature = "atomics", target_os = "wasi", panic = "unwind"))]
+#[wasm_bindgen_test]
+async fn thread_pool_reports_dead_worker_as_failed() {
+    use wasm_bindgen_futures::ThreadPool;
+
+    let pool = ThreadPool::new(2);
+    // Panicking while creating the future kills the worker thread.
+    let err = pool
+        .spawn(|| -> std::future::Ready<()> { panic!("boom") })
+        .await
+        .unwrap_err();
+    assert!(err.is_failed());
+}
+
 #[cfg(feature = "futures-core-03-stream")]
 #[wasm_bindgen_test]
 async fn can_use_an_async_iterable_as_stream() {
@@ -224,6 +437,19 @@ async fn can_use_an_async_iterable_as_stream() {
     assert_eq!(stream.next().await, None);
 }
 
+#[cfg(feature = "futures-core-03-stream")]
+#[wasm_bindgen_test]
+async fn can_pass_a_stream_as_async_iterable() {
+    use futures_lite::stream::{self, StreamExt};
+    use wasm_bindgen_futures::stream::{JsAsyncIterator, JsStream};
+
+    let async_iter = JsValue::from(JsAsyncIterator::new(stream::iter([42u32, 24])));
+    let mut stream = JsStream::from(async_iter.unchecked_into::<js_sys::AsyncIterator>());
+    assert_eq!(stream.next().await, Some(Ok(JsValue::from(42))));
+    assert_eq!(stream.next().await, Some(Ok(JsValue::from(24))));
+    assert_eq!(stream.next().await, None);
+}
+
 #[wasm_bindgen_test]
 #[should_panic]
 async fn should_panic() {
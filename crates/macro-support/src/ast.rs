@@ -23,6 +23,10 @@ pub struct Program {
     pub enums: Vec<Enum>,
     /// rust structs
     pub structs: Vec<Struct>,
+    /// rust traits implementable from javascript
+    pub traits: Vec<Trait>,
+    /// rust enums thrown to javascript as errors
+    pub errors: Vec<ErrorEnum>,
     /// custom typescript sections to be included in the definition file
     pub typescript_custom_sections: Vec<LitOrExpr>,
     /// Inline JS snippets
@@ -43,6 +47,8 @@ impl Default for Program {
             linked_modules: Default::default(),
             enums: Default::default(),
             structs: Default::default(),
+            traits: Default::default(),
+            errors: Default::default(),
             typescript_custom_sections: Default::default(),
             inline_js: Default::default(),
             wasm_bindgen: syn::parse_quote! { wasm_bindgen },
@@ -95,6 +101,9 @@ pub struct Export {
     /// Whether or not this function should be flagged as the Wasm start
     /// function.
     pub start: bool,
+    /// Whether this function is exported as a JSPI `WebAssembly.promising`
+    /// function returning a `Promise`
+    pub promising: bool,
     /// Path to wasm_bindgen
     pub wasm_bindgen: Path,
     /// Path to wasm_bindgen_futures
@@ -179,6 +188,9 @@ pub struct ImportFunction {
     pub catch: bool,
     /// Whether the function is variadic on the JS side
     pub variadic: bool,
+    /// Whether the returned `Promise` suspends WebAssembly until it settles,
+    /// using JSPI
+    pub suspending: bool,
     /// Whether the function should use structural type checking
     pub structural: bool,
     /// Causes the Builder (See cli-support::js::binding::Builder) to error out if
@@ -396,6 +408,8 @@ pub struct Function {
     pub generate_jsdoc: bool,
     /// Whether this is a function with a variadict parameter
     pub variadic: bool,
+    /// Whether maps are passed to and from JS as plain objects instead of `Map`s
+    pub map_as_object: bool,
 }
 
 /// Information about a function's return
@@ -443,6 +457,9 @@ pub struct Struct {
     pub comments: Vec<String>,
     /// Whether this struct is inspectable (provides toJSON/toString properties to JS)
     pub is_inspectable: bool,
+    /// Whether this struct is passed by value as a plain JS object instead of
+    /// being wrapped in a class
+    pub plain_object: bool,
     /// Whether to generate a typescript definition for this struct
     pub generate_typescript: bool,
     /// Whether to skip exporting this struct from the module exports
@@ -453,6 +470,40 @@ pub struct Struct {
     pub wasm_bindgen: Path,
 }
 
+/// A Rust trait which JS objects can implement, through a generated proxy
+/// type forwarding each method to the JS object
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[derive(Clone)]
+pub struct Trait {
+    /// The name of the trait in Rust code
+    pub rust_name: Ident,
+    /// The name of the interface in JS code
+    pub js_name: String,
+    /// The name of the imported type which implements the trait
+    pub proxy: Ident,
+    /// The methods of the trait
+    pub methods: Vec<TraitMethod>,
+    /// The doc comments on this trait, if provided
+    pub comments: Vec<String>,
+    /// Whether to generate a typescript definition for this trait
+    pub generate_typescript: bool,
+}
+
+/// A method of a trait implementable from JS
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[derive(Clone)]
+pub struct TraitMethod {
+    /// The signature of the method in the trait, with every argument bound
+    /// to a plain identifier
+    pub sig: syn::Signature,
+    /// The name of the imported method the proxy forwards to
+    pub import: Ident,
+    /// The shim of the imported method
+    pub shim: Ident,
+    /// The doc comments on this method, if any
+    pub comments: Vec<String>,
+}
+
 /// The field of a struct
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 #[derive(Clone)]
@@ -514,6 +565,25 @@ pub struct Enum {
     pub wasm_bindgen: Path,
 }
 
+/// The metadata for a `#[wasm_bindgen(error)]` enum, which is converted to
+/// an instance of a generated JS `Error` subclass
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
+#[derive(Clone)]
+pub struct ErrorEnum {
+    /// The name of this enum in Rust code
+    pub rust_name: Ident,
+    /// The name of the error class in JS code
+    pub js_name: String,
+    /// The variants of this enum, used as the `kind` of the error
+    pub variants: Vec<Ident>,
+    /// The doc comments on this enum, if any
+    pub comments: Vec<String>,
+    /// Whether to generate a typescript definition for this error class
+    pub generate_typescript: bool,
+    /// Path to wasm_bindgen
+    pub wasm_bindgen: Path,
+}
+
 /// The variant of an enum
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
 #[derive(Clone)]
@@ -10,6 +10,7 @@ use quote::{quote, ToTokens};
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use syn::ext::IdentExt;
 use syn::parse_quote;
 use syn::spanned::Spanned;
 use syn::{Attribute, Meta, MetaList};
@@ -84,6 +85,12 @@ impl TryToTokens for ast::Program {
         for e in self.enums.iter() {
             e.to_tokens(tokens);
         }
+        for t in self.traits.iter() {
+            t.to_tokens(tokens);
+        }
+        for e in self.errors.iter() {
+            e.to_tokens(tokens);
+        }
 
         Diagnostic::from_vec(errors)?;
 
@@ -218,6 +225,9 @@ impl TryToTokens for ast::LinkToModule {
 
 impl ToTokens for ast::Struct {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.plain_object {
+            return plain_object_to_tokens(self, tokens);
+        }
         let name = &self.rust_name;
         let name_str = self.qualified_name.to_string();
         let name_len = name_str.len() as u32;
@@ -461,6 +471,205 @@ impl ToTokens for ast::Struct {
     }
 }
 
+/// Generates the conversions for a `#[wasm_bindgen(plain_object)]` struct,
+/// which is passed by value as a plain JS object rather than wrapped in a
+/// class.
+fn plain_object_to_tokens(s: &ast::Struct, tokens: &mut TokenStream) {
+    let name = &s.rust_name;
+    let name_str = &s.js_name;
+    let name_len = name_str.len() as u32;
+    let name_chars: Vec<u32> = name_str.chars().map(|c| c as u32).collect();
+    let wasm_bindgen = &s.wasm_bindgen;
+    let expected = format!("expected a `{name_str}` object");
+
+    // Spanned to the field types so that fields which can't be converted to
+    // or from a `JsValue` are reported on the field itself.
+    let set_fields = s.fields.iter().map(|field| {
+        let member = &field.rust_name;
+        let js_name = &field.js_name;
+        quote_spanned! {field.ty.span()=>
+            #wasm_bindgen::__rt::plain_object_set(&obj, #js_name, value.#member);
+        }
+    });
+    let get_fields = s.fields.iter().map(|field| {
+        let member = &field.rust_name;
+        let js_name = &field.js_name;
+        let ty = &field.ty;
+        quote_spanned! {field.ty.span()=>
+            #member: #wasm_bindgen::__rt::plain_object_get::<#ty>(value, #js_name)?,
+        }
+    });
+    // When converting from an argument, the field which failed to convert is
+    // reported instead of just the struct.
+    let get_fields_or_throw = s.fields.iter().map(|field| {
+        let member = &field.rust_name;
+        let js_name = &field.js_name;
+        let ty = &field.ty;
+        let expected = format!(
+            "expected field `{js_name}` of a `{name_str}` object to be a `{}`",
+            type_to_string(ty),
+        );
+        quote_spanned! {field.ty.span()=>
+            #member: match #wasm_bindgen::__rt::plain_object_get::<#ty>(&value, #js_name) {
+                #wasm_bindgen::__rt::core::option::Option::Some(field) => field,
+                #wasm_bindgen::__rt::core::option::Option::None => #wasm_bindgen::throw_str(#expected),
+            },
+        }
+    });
+
+    (quote! {
+        #[automatically_derived]
+        impl #wasm_bindgen::describe::WasmDescribe for #name {
+            fn describe() {
+                use #wasm_bindgen::describe::*;
+                inform(NAMED_EXTERNREF);
+                inform(#name_len);
+                #(inform(#name_chars);)*
+            }
+        }
+
+        #[automatically_derived]
+        impl #wasm_bindgen::__rt::core::convert::From<#name> for
+            #wasm_bindgen::JsValue
+        {
+            fn from(value: #name) -> Self {
+                let obj = #wasm_bindgen::__rt::plain_object_new();
+                #(#set_fields)*
+                obj
+            }
+        }
+
+        #[automatically_derived]
+        impl #wasm_bindgen::convert::TryFromJsValue for #name {
+            fn try_from_js_value_ref(value: &#wasm_bindgen::JsValue) -> #wasm_bindgen::__rt::core::option::Option<Self> {
+                if !value.is_object() {
+                    return #wasm_bindgen::__rt::core::option::Option::None;
+                }
+                #wasm_bindgen::__rt::core::option::Option::Some(#name {
+                    #(#get_fields)*
+                })
+            }
+        }
+
+        #[automatically_derived]
+        impl #wasm_bindgen::convert::IntoWasmAbi for #name {
+            type Abi = u32;
+
+            #[inline]
+            fn into_abi(self) -> u32 {
+                <#wasm_bindgen::JsValue as #wasm_bindgen::__rt::core::convert::From<#name>>::from(self)
+                    .into_abi()
+            }
+        }
+
+        #[automatically_derived]
+        impl #wasm_bindgen::convert::FromWasmAbi for #name {
+            type Abi = u32;
+
+            unsafe fn from_abi(js: u32) -> Self {
+                let value = <#wasm_bindgen::JsValue as #wasm_bindgen::convert::FromWasmAbi>::from_abi(js);
+                if !value.is_object() {
+                    #wasm_bindgen::throw_str(#expected);
+                }
+                #name {
+                    #(#get_fields_or_throw)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #wasm_bindgen::convert::OptionIntoWasmAbi for #name {
+            #[inline]
+            fn none() -> Self::Abi {
+                <#wasm_bindgen::JsValue as #wasm_bindgen::convert::OptionIntoWasmAbi>::none()
+            }
+        }
+
+        #[automatically_derived]
+        impl #wasm_bindgen::convert::OptionFromWasmAbi for #name {
+            #[inline]
+            fn is_none(abi: &Self::Abi) -> bool {
+                <#wasm_bindgen::JsValue as #wasm_bindgen::convert::OptionFromWasmAbi>::is_none(abi)
+            }
+        }
+
+        #[automatically_derived]
+        impl #wasm_bindgen::describe::WasmDescribeVector for #name {
+            fn describe_vector() {
+                use #wasm_bindgen::describe::*;
+                inform(VECTOR);
+                inform(NAMED_EXTERNREF);
+                inform(#name_len);
+                #(inform(#name_chars);)*
+            }
+        }
+
+        #[automatically_derived]
+        impl #wasm_bindgen::convert::VectorIntoWasmAbi for #name {
+            type Abi = <
+                #wasm_bindgen::__rt::alloc::boxed::Box<[#wasm_bindgen::JsValue]>
+                as #wasm_bindgen::convert::IntoWasmAbi
+            >::Abi;
+
+            fn vector_into_abi(
+                vector: #wasm_bindgen::__rt::alloc::boxed::Box<[#name]>
+            ) -> Self::Abi {
+                #wasm_bindgen::convert::js_value_vector_into_abi(vector)
+            }
+        }
+
+        #[automatically_derived]
+        impl #wasm_bindgen::convert::VectorFromWasmAbi for #name {
+            type Abi = <
+                #wasm_bindgen::__rt::alloc::boxed::Box<[#wasm_bindgen::JsValue]>
+                as #wasm_bindgen::convert::FromWasmAbi
+            >::Abi;
+
+            unsafe fn vector_from_abi(
+                js: Self::Abi
+            ) -> #wasm_bindgen::__rt::alloc::boxed::Box<[#name]> {
+                #wasm_bindgen::convert::js_value_vector_from_abi(js)
+            }
+        }
+    })
+    .to_tokens(tokens);
+
+    // The CLI reads the field types from the getter descriptors to generate
+    // the TypeScript interface, even though no getters are exported.
+    for field in s.fields.iter() {
+        let ty = &field.ty;
+        Descriptor {
+            ident: &field.getter,
+            inner: quote! {
+                <#ty as WasmDescribe>::describe();
+            },
+            attrs: vec![],
+            wasm_bindgen: &field.wasm_bindgen,
+        }
+        .to_tokens(tokens);
+    }
+}
+
+/// Formats a type for error messages, without the spaces `quote` puts
+/// between all tokens.
+fn type_to_string(ty: &syn::Type) -> String {
+    let tokens = ty.to_token_stream().to_string();
+    let mut out = String::with_capacity(tokens.len());
+    let mut chars = tokens.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ' ' {
+            let word = |c: char| c.is_alphanumeric() || c == '_';
+            let prev = out.chars().last().unwrap_or(' ');
+            let next = chars.peek().copied().unwrap_or(' ');
+            if !(word(prev) && word(next) || prev == ',') {
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
 impl ToTokens for ast::StructField {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let rust_name = &self.rust_name;
@@ -921,11 +1130,33 @@ impl TryToTokens for ast::Export {
                 #describe_args
                 #describe_ret
             },
-            attrs,
+            attrs: attrs.clone(),
             wasm_bindgen: &self.wasm_bindgen,
         }
         .to_tokens(into);
 
+        // The error class a `Result` export throws is described separately,
+        // as the error type isn't part of the signature's descriptor. For an
+        // `async` export `syn_ret` is the output of the future, whose error
+        // rejects the returned promise.
+        if !self.start {
+            let throws = Ident::new(&shared::throws_function(&export_name), Span::call_site());
+            Descriptor {
+                ident: &throws,
+                inner: quote! {
+                    #[allow(unused_imports)]
+                    use #wasm_bindgen::__rt::{DescribeThrowsError as _, DescribeThrowsNothing as _};
+                    (&&#wasm_bindgen::__rt::Throws::<#syn_ret>(
+                        #wasm_bindgen::__rt::core::marker::PhantomData
+                    ))
+                    .describe_throws();
+                },
+                attrs,
+                wasm_bindgen: &self.wasm_bindgen,
+            }
+            .to_tokens(into);
+        }
+
         Ok(())
     }
 }
@@ -2394,6 +2625,116 @@ impl ToTokens for ast::Enum {
     }
 }
 
+impl ToTokens for ast::ErrorEnum {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        let name = &self.rust_name;
+        let name_str = &self.js_name;
+        let name_len = name_str.len() as u32;
+        let name_chars: Vec<u32> = name_str.chars().map(|c| c as u32).collect();
+        let new_fn = Ident::new(&shared::error_new_function(name_str), Span::call_site());
+        let wasm_bindgen = &self.wasm_bindgen;
+        let kinds = self.variants.iter().map(|variant| {
+            let kind = variant.unraw().to_string();
+            quote! { #name::#variant { .. } => #kind, }
+        });
+
+        (quote! {
+            #[automatically_derived]
+            impl #wasm_bindgen::describe::WasmDescribe for #name {
+                fn describe() {
+                    use #wasm_bindgen::describe::*;
+                    inform(NAMED_EXTERNREF);
+                    inform(#name_len);
+                    #(inform(#name_chars);)*
+                }
+            }
+
+            #[automatically_derived]
+            impl #wasm_bindgen::__rt::WasmError for #name {}
+
+            #[automatically_derived]
+            impl #wasm_bindgen::__rt::core::convert::From<#name> for
+                #wasm_bindgen::JsValue
+            {
+                fn from(value: #name) -> Self {
+                    let kind = match &value {
+                        #(#kinds)*
+                    };
+                    let message = #wasm_bindgen::__rt::alloc::string::ToString::to_string(&value);
+                    let cause = #wasm_bindgen::__rt::error_cause(&value);
+
+                    #[link(wasm_import_module = "__wbindgen_placeholder__")]
+                    #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none", target_os = "wasi")))]
+                    extern "C" {
+                        fn #new_fn(kind: u32, message: u32, cause: u32) -> u32;
+                    }
+
+                    #[cfg(not(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none", target_os = "wasi"))))]
+                    unsafe fn #new_fn(_: u32, _: u32, _: u32) -> u32 {
+                        panic!("cannot convert to JsValue outside of the Wasm target")
+                    }
+
+                    use #wasm_bindgen::convert::IntoWasmAbi;
+                    unsafe {
+                        <#wasm_bindgen::JsValue as #wasm_bindgen::convert::FromWasmAbi>::from_abi(#new_fn(
+                            #wasm_bindgen::JsValue::from_str(kind).into_abi(),
+                            #wasm_bindgen::JsValue::from(message).into_abi(),
+                            cause.into_abi(),
+                        ))
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl #wasm_bindgen::convert::IntoWasmAbi for #name {
+                type Abi = u32;
+
+                #[inline]
+                fn into_abi(self) -> u32 {
+                    <#wasm_bindgen::JsValue as #wasm_bindgen::__rt::core::convert::From<#name>>::from(self)
+                        .into_abi()
+                }
+            }
+
+            #[automatically_derived]
+            impl #wasm_bindgen::convert::OptionIntoWasmAbi for #name {
+                #[inline]
+                fn none() -> Self::Abi {
+                    <#wasm_bindgen::JsValue as #wasm_bindgen::convert::OptionIntoWasmAbi>::none()
+                }
+            }
+        })
+        .to_tokens(into);
+    }
+}
+
+impl ToTokens for ast::Trait {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        let rust_name = &self.rust_name;
+        let proxy = &self.proxy;
+        let methods = self.methods.iter().map(|method| {
+            let sig = &method.sig;
+            let import = &method.import;
+            let args = sig.inputs.iter().filter_map(|arg| match arg {
+                syn::FnArg::Typed(arg) => Some(&arg.pat),
+                syn::FnArg::Receiver(_) => None,
+            });
+            quote! {
+                #sig {
+                    self.#import(#(#args),*)
+                }
+            }
+        });
+        (quote! {
+            #[automatically_derived]
+            impl #rust_name for #proxy {
+                #(#methods)*
+            }
+        })
+        .to_tokens(into);
+    }
+}
+
 impl ToTokens for ast::ImportStatic {
     fn to_tokens(&self, into: &mut TokenStream) {
         let ty = &self.ty;
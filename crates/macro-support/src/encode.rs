@@ -150,6 +150,16 @@ fn shared_program<'a>(
             .iter()
             .map(|a| shared_struct(a, intern))
             .collect(),
+        traits: prog
+            .traits
+            .iter()
+            .map(|a| shared_trait(a, intern))
+            .collect(),
+        errors: prog
+            .errors
+            .iter()
+            .map(|a| shared_error_enum(a, intern))
+            .collect(),
         enums: prog.enums.iter().map(|a| shared_enum(a, intern)).collect(),
         imports: prog
             .imports
@@ -215,6 +225,7 @@ fn shared_export<'a>(
             .map(|ns| ns.iter().map(|s| &**s).collect()),
         method_kind,
         start: export.start,
+        promising: export.promising,
     })
 }
 
@@ -246,9 +257,42 @@ fn shared_function<'a>(func: &'a ast::Function, _intern: &'a Interner) -> Functi
         generate_typescript: func.generate_typescript,
         generate_jsdoc: func.generate_jsdoc,
         variadic: func.variadic,
+        map_as_object: func.map_as_object,
         ret_ty_override: func.ret.as_ref().and_then(|v| v.js_type.as_deref()),
         ret_desc: func.ret.as_ref().and_then(|v| v.desc.as_deref()),
+        location: shared_location(func.name_span),
+    }
+}
+
+/// The location of an item in Rust code, used by the CLI to generate source
+/// maps. Locations are only available with the `spans` feature, and require a
+/// compiler exposing span locations to proc macros.
+#[cfg(feature = "spans")]
+fn shared_location(span: Span) -> Option<SourceLocation> {
+    let start = span.start();
+    if start.line == 0 {
+        return None;
     }
+    // Source maps resolve relative paths against the generated JS file, not
+    // the directory `rustc` runs in, so the path on disk is made absolute.
+    let file = match span.local_file() {
+        Some(path) => std::env::current_dir()
+            .map(|dir| dir.join(&path))
+            .unwrap_or(path)
+            .to_string_lossy()
+            .into_owned(),
+        None => span.file(),
+    };
+    Some(SourceLocation {
+        file,
+        line: start.line as u32,
+        column: start.column as u32,
+    })
+}
+
+#[cfg(not(feature = "spans"))]
+fn shared_location(_span: Span) -> Option<SourceLocation> {
+    None
 }
 
 fn shared_enum<'a>(e: &'a ast::Enum, intern: &'a Interner) -> Enum<'a> {
@@ -369,6 +413,7 @@ fn shared_import_function<'a>(
         structural: i.structural,
         function: shared_function(&i.function, intern),
         variadic: i.variadic,
+        suspending: i.suspending,
     })
 }
 
@@ -418,12 +463,39 @@ fn shared_struct<'a>(s: &'a ast::Struct, intern: &'a Interner) -> Struct<'a> {
             .collect(),
         comments: s.comments.iter().map(|s| &**s).collect(),
         is_inspectable: s.is_inspectable,
+        plain_object: s.plain_object,
         generate_typescript: s.generate_typescript,
         js_namespace: s
             .js_namespace
             .as_ref()
             .map(|ns| ns.iter().map(|s| &**s).collect()),
         private: s.private,
+        location: shared_location(s.rust_name.span()),
+    }
+}
+
+fn shared_trait<'a>(t: &'a ast::Trait, intern: &'a Interner) -> Trait<'a> {
+    Trait {
+        name: &t.js_name,
+        methods: t
+            .methods
+            .iter()
+            .map(|m| TraitMethod {
+                shim: intern.intern(&m.shim),
+                comments: m.comments.iter().map(|s| &**s).collect(),
+            })
+            .collect(),
+        comments: t.comments.iter().map(|s| &**s).collect(),
+        generate_typescript: t.generate_typescript,
+    }
+}
+
+fn shared_error_enum<'a>(e: &'a ast::ErrorEnum, _intern: &'a Interner) -> ErrorEnum<'a> {
+    ErrorEnum {
+        name: &e.js_name,
+        variants: e.variants.iter().map(|v| v.unraw().to_string()).collect(),
+        comments: e.comments.iter().map(|s| &**s).collect(),
+        generate_typescript: e.generate_typescript,
     }
 }
 
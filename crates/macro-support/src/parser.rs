@@ -94,13 +94,18 @@ macro_rules! attrgen {
             (js_class, false, JsClass(Span, String, Span)),
             (reexport, false, Reexport(Span, Option<String>)),
             (inspectable, false, Inspectable(Span)),
+            (plain_object, false, PlainObject(Span)),
+            (error, false, Error(Span)),
             (is_type_of, false, IsTypeOf(Span, syn::Expr)),
             (extends, false, Extends(Span, syn::Path)),
             (no_deref, false, NoDeref(Span)),
             (no_upcast, false, NoUpcast(Span)),
             (no_promising, false, NoPromising(Span)),
+            (suspending, false, Suspending(Span)),
+            (promising, false, Promising(Span)),
             (vendor_prefix, false, VendorPrefix(Span, Ident)),
             (variadic, false, Variadic(Span)),
+            (map_as, false, MapAs(Span, String, Span)),
             (typescript_custom_section, false, TypescriptCustomSection(Span)),
             (skip_typescript, false, SkipTypescript(Span)),
             (skip_jsdoc, false, SkipJsDoc(Span)),
@@ -494,12 +499,25 @@ impl ConvertToAst<&ast::Program> for &mut syn::ItemStruct {
         }
 
         let is_inspectable = attrs.inspectable().is_some();
+        let plain_object = attrs.plain_object().is_some();
         let getter_with_clone = attrs.getter_with_clone();
         let js_namespace = attrs.js_namespace().map(|(ns, _)| ns.0);
+        if plain_object && js_namespace.is_some() {
+            bail_span!(
+                self.ident,
+                "`plain_object` structs cannot be placed in a `js_namespace`"
+            );
+        }
         let qualified_name = wasm_bindgen_shared::qualified_name(js_namespace.as_deref(), &js_name);
         for (i, field) in self.fields.iter_mut().enumerate() {
             match field.vis {
                 syn::Visibility::Public(..) => {}
+                // Plain objects are rebuilt from every field when they come
+                // back from JS, so none of them can be left out.
+                _ if plain_object => bail_span!(
+                    field,
+                    "all fields of a `plain_object` struct must be public"
+                ),
                 _ => continue,
             }
             let (js_field_name, member) = match &field.ident {
@@ -508,7 +526,13 @@ impl ConvertToAst<&ast::Program> for &mut syn::ItemStruct {
             };
 
             let attrs = BindgenAttrs::find(&mut field.attrs)?;
-            if attrs.skip().is_some() {
+            if let Some(span) = attrs.skip() {
+                if plain_object {
+                    return Err(Diagnostic::span_error(
+                        *span,
+                        "fields of a `plain_object` struct cannot be skipped",
+                    ));
+                }
                 attrs.check_used();
                 continue;
             }
@@ -549,6 +573,7 @@ impl ConvertToAst<&ast::Program> for &mut syn::ItemStruct {
             fields,
             comments,
             is_inspectable,
+            plain_object,
             generate_typescript,
             private,
             js_namespace,
@@ -593,6 +618,7 @@ impl<'a> ConvertToAst<(&ast::Program, BindgenAttrs, &'a Option<ast::ImportModule
         )?;
         let catch = opts.catch().is_some();
         let variadic = opts.variadic().is_some();
+        let suspending = opts.suspending().is_some();
         let js_ret = if catch {
             // TODO: this assumes a whole bunch:
             //
@@ -608,6 +634,25 @@ impl<'a> ConvertToAst<(&ast::Program, BindgenAttrs, &'a Option<ast::ImportModule
 
         let operation_kind = operation_kind(&opts);
 
+        if let Some(span) = opts.suspending() {
+            let msg = if wasm.r#async {
+                Some(
+                    "`suspending` cannot be used with `async fn`, \
+                     the returned `Promise` is already awaited by WebAssembly",
+                )
+            } else if !matches!(
+                operation_kind,
+                ast::OperationKind::Regular | ast::OperationKind::RegularThis
+            ) {
+                Some("`suspending` cannot be used on getters, setters or indexing operations")
+            } else {
+                None
+            };
+            if let Some(msg) = msg {
+                return Err(Diagnostic::span_error(*span, msg));
+            }
+        }
+
         let kind = if opts.method().is_some() {
             let class = wasm.arguments.first().ok_or_else(|| {
                 err_span!(self, "imported methods must have at least one argument")
@@ -791,6 +836,7 @@ impl<'a> ConvertToAst<(&ast::Program, BindgenAttrs, &'a Option<ast::ImportModule
             js_ret,
             catch,
             variadic,
+            suspending,
             structural: opts.structural().is_some() || opts.r#final().is_none(),
             rust_name: self.sig.ident,
             shim: Ident::new(&shim, Span::call_site()),
@@ -1194,6 +1240,17 @@ fn function_from_decl(
         }
     }
 
+    let map_as_object = match opts.map_as() {
+        None | Some(("map", _)) => false,
+        Some(("object", _)) => true,
+        Some((_, span)) => {
+            return Err(Diagnostic::span_error(
+                span,
+                "`map_as` must be either \"map\" or \"object\"",
+            ))
+        }
+    };
+
     let (name, name_span) = if let Some((js_name, js_name_span)) = opts.js_name() {
         let kind = operation_kind(opts);
         let prefix = match kind {
@@ -1216,6 +1273,7 @@ fn function_from_decl(
             generate_typescript: opts.skip_typescript().is_none(),
             generate_jsdoc: opts.skip_jsdoc().is_none(),
             variadic: opts.variadic().is_some(),
+            map_as_object,
             ret,
             arguments: arguments
                 .into_iter()
@@ -1408,6 +1466,19 @@ impl<'a> MacroParse<(Option<BindgenAttrs>, &'a mut TokenStream)> for syn::Item {
                 });
                 let rust_name = f.sig.ident.clone();
                 let start = opts.start().is_some();
+                let promising = opts.promising().is_some();
+                if let Some(span) = opts.promising() {
+                    let msg = if f.sig.asyncness.is_some() {
+                        Some("`promising` cannot be used with `async fn`")
+                    } else if start {
+                        Some("the start function cannot be `promising`")
+                    } else {
+                        None
+                    };
+                    if let Some(msg) = msg {
+                        return Err(Diagnostic::span_error(*span, msg));
+                    }
+                }
 
                 if opts.this().is_some() && f.sig.inputs.is_empty() {
                     bail_span!(
@@ -1427,6 +1498,7 @@ impl<'a> MacroParse<(Option<BindgenAttrs>, &'a mut TokenStream)> for syn::Item {
                     rust_class: None,
                     rust_name,
                     start,
+                    promising,
                     wasm_bindgen: program.wasm_bindgen.clone(),
                     wasm_bindgen_futures: program.wasm_bindgen_futures.clone(),
                 });
@@ -1457,11 +1529,18 @@ impl<'a> MacroParse<(Option<BindgenAttrs>, &'a mut TokenStream)> for syn::Item {
                 };
                 c.macro_parse(program, opts)?;
             }
+            syn::Item::Trait(mut t) => {
+                let opts = match opts {
+                    Some(opts) => opts,
+                    None => BindgenAttrs::find(&mut t.attrs)?,
+                };
+                t.macro_parse(program, (tokens, opts))?;
+            }
             _ => {
                 bail_span!(
                     self,
                     "#[wasm_bindgen] can only be applied to a function, \
-                     struct, enum, impl, or extern block",
+                     struct, enum, trait, impl, or extern block",
                 );
             }
         }
@@ -1631,6 +1710,26 @@ impl MacroParse<&ClassMarker> for &mut syn::ImplItemFn {
             ast::MethodKind::Operation(ast::Operation { is_static, kind })
         };
 
+        let promising = opts.promising().is_some();
+        if let Some(span) = opts.promising() {
+            let msg = if self.sig.asyncness.is_some() {
+                Some("`promising` cannot be used with `async fn`")
+            } else if !matches!(
+                method_kind,
+                ast::MethodKind::Operation(ast::Operation {
+                    kind: ast::OperationKind::Regular,
+                    ..
+                })
+            ) {
+                Some("`promising` cannot be used on constructors, getters or setters")
+            } else {
+                None
+            };
+            if let Some(msg) = msg {
+                return Err(Diagnostic::span_error(*span, msg));
+            }
+        }
+
         // Validate that js_namespace is not used on methods
         if let Some((_, span)) = opts.js_namespace() {
             return Err(Diagnostic::span_error(
@@ -1650,6 +1749,7 @@ impl MacroParse<&ClassMarker> for &mut syn::ImplItemFn {
             rust_class: Some(class.clone()),
             rust_name: self.sig.ident.clone(),
             start: false,
+            promising,
             wasm_bindgen: program.wasm_bindgen.clone(),
             wasm_bindgen_futures: program.wasm_bindgen_futures.clone(),
         });
@@ -1758,6 +1858,9 @@ impl<'a> MacroParse<(&'a mut TokenStream, BindgenAttrs)> for syn::ItemEnum {
         program: &mut ast::Program,
         (tokens, opts): (&'a mut TokenStream, BindgenAttrs),
     ) -> Result<(), Diagnostic> {
+        if opts.error().is_some() {
+            return error_enum(self, program, tokens, opts);
+        }
         if self.variants.is_empty() {
             bail_span!(self, "cannot export empty enums to JS");
         }
@@ -1953,6 +2056,214 @@ impl MacroParse<BindgenAttrs> for syn::ItemConst {
     }
 }
 
+/// Represents a `#[wasm_bindgen(error)]` enum, which is thrown to JS as an
+/// instance of a generated `Error` subclass. Unlike other exported enums, its
+/// variants may hold data.
+fn error_enum(
+    enum_: syn::ItemEnum,
+    program: &mut ast::Program,
+    tokens: &mut TokenStream,
+    opts: BindgenAttrs,
+) -> Result<(), Diagnostic> {
+    if !enum_.generics.params.is_empty() {
+        bail_span!(
+            enum_.generics,
+            "error enums with #[wasm_bindgen] cannot have lifetime or type parameters currently"
+        );
+    }
+    if enum_.variants.is_empty() {
+        bail_span!(enum_, "cannot export empty error enums to JS");
+    }
+    let js_name = opts
+        .js_name()
+        .map(|s| s.0.to_string())
+        .unwrap_or_else(|| enum_.ident.unraw().to_string());
+    if is_js_keyword(&js_name) {
+        bail_span!(
+            enum_.ident,
+            "error enum cannot use the JS keyword `{}` as its name",
+            js_name
+        );
+    }
+    let generate_typescript = opts.skip_typescript().is_none();
+    opts.check_used();
+
+    program.errors.push(ast::ErrorEnum {
+        rust_name: enum_.ident.clone(),
+        js_name,
+        variants: enum_.variants.iter().map(|v| v.ident.clone()).collect(),
+        comments: extract_doc_comments(&enum_.attrs),
+        generate_typescript,
+        wasm_bindgen: program.wasm_bindgen.clone(),
+    });
+    enum_.to_tokens(tokens);
+    Ok(())
+}
+
+impl<'a> MacroParse<(&'a mut TokenStream, BindgenAttrs)> for syn::ItemTrait {
+    fn macro_parse(
+        mut self,
+        program: &mut ast::Program,
+        (tokens, opts): (&'a mut TokenStream, BindgenAttrs),
+    ) -> Result<(), Diagnostic> {
+        if self.unsafety.is_some() {
+            bail_span!(
+                self.unsafety,
+                "#[wasm_bindgen] unsafe traits are not supported"
+            );
+        }
+        if self.auto_token.is_some() {
+            bail_span!(
+                self.auto_token,
+                "#[wasm_bindgen] auto traits are not supported"
+            );
+        }
+        if !self.generics.params.is_empty() || self.generics.where_clause.is_some() {
+            bail_span!(
+                self.generics,
+                "#[wasm_bindgen] traits cannot have lifetime or type parameters currently"
+            );
+        }
+        if !self.supertraits.is_empty() {
+            bail_span!(
+                self.supertraits,
+                "#[wasm_bindgen] traits cannot have supertraits"
+            );
+        }
+
+        let js_name = opts
+            .js_name()
+            .map(|s| s.0.to_string())
+            .unwrap_or_else(|| self.ident.unraw().to_string());
+        let generate_typescript = opts.skip_typescript().is_none();
+        opts.check_used();
+
+        // The proxy is an ordinary imported type, typed as the trait's
+        // interface in TypeScript.
+        let proxy = Ident::new(&format!("Js{}", self.ident.unraw()), self.ident.span());
+        let vis = &self.vis;
+        let doc = format!(" A JS object implementing [`{}`].", self.ident);
+        let ty: syn::ForeignItemType = syn::parse_quote! {
+            #[doc = #doc]
+            #vis type #proxy;
+        };
+        let ty_opts: BindgenAttrs = syn::parse_quote!(typescript_type = #js_name);
+        program.imports.push(ast::Import {
+            module: None,
+            js_namespace: None,
+            reexport: None,
+            kind: ty.convert((&*program, ty_opts))?,
+        });
+
+        let mut methods = Vec::new();
+        let mut errors = Vec::new();
+        for item in self.items.iter_mut() {
+            match trait_method(program, &proxy, item) {
+                Ok(method) => methods.push(method),
+                Err(e) => errors.push(e),
+            }
+        }
+        Diagnostic::from_vec(errors)?;
+
+        program.traits.push(ast::Trait {
+            comments: extract_doc_comments(&self.attrs),
+            rust_name: self.ident.clone(),
+            js_name,
+            proxy,
+            methods,
+            generate_typescript,
+        });
+        self.to_tokens(tokens);
+        Ok(())
+    }
+}
+
+/// Imports a trait method as a structural method of the trait's proxy type,
+/// removing any `#[wasm_bindgen]` attributes from the trait item.
+fn trait_method(
+    program: &mut ast::Program,
+    proxy: &Ident,
+    item: &mut syn::TraitItem,
+) -> Result<ast::TraitMethod, Diagnostic> {
+    let method = match item {
+        syn::TraitItem::Fn(method) => method,
+        other => bail_span!(other, "#[wasm_bindgen] traits can only contain methods"),
+    };
+    let mut opts = BindgenAttrs::find(&mut method.attrs)?;
+    if let Some(default) = &method.default {
+        bail_span!(
+            default,
+            "methods of #[wasm_bindgen] traits cannot have a default implementation"
+        );
+    }
+    let mut sig = method.sig.clone();
+    if sig.asyncness.is_some() {
+        bail_span!(
+            sig.asyncness,
+            "methods of #[wasm_bindgen] traits cannot be `async`"
+        );
+    }
+    if !sig.generics.params.is_empty() {
+        bail_span!(
+            sig.generics,
+            "methods of #[wasm_bindgen] traits cannot have lifetime or type parameters"
+        );
+    }
+    match sig.receiver() {
+        Some(receiver) if receiver.reference.is_some() && receiver.mutability.is_none() => {}
+        _ => bail_span!(sig, "methods of #[wasm_bindgen] traits must take `&self`"),
+    }
+
+    // Every argument needs a name to be forwarded to the import.
+    for (i, arg) in sig.inputs.iter_mut().enumerate() {
+        if let syn::FnArg::Typed(arg) = arg {
+            if !matches!(&*arg.pat, syn::Pat::Ident(_)) {
+                let ident = Ident::new(&format!("arg{i}"), arg.pat.span());
+                *arg.pat = syn::parse_quote!(#ident);
+            }
+        }
+    }
+
+    let name = sig.ident.unraw().to_string();
+    let import = Ident::new(&format!("__wbg_{name}"), sig.ident.span());
+    let mut extra: BindgenAttrs = if opts.js_name().is_some() {
+        syn::parse_quote!(method, structural)
+    } else {
+        syn::parse_quote!(method, structural, js_name = #name)
+    };
+    opts.attrs.append(&mut extra.attrs);
+    extra.check_used();
+
+    let attrs = &method.attrs;
+    let args = sig
+        .inputs
+        .iter()
+        .filter(|arg| matches!(arg, syn::FnArg::Typed(_)));
+    let output = &sig.output;
+    let f: syn::ForeignItemFn = syn::parse_quote! {
+        #(#attrs)*
+        fn #import(this: &#proxy, #(#args),*) #output;
+    };
+    let kind = f.convert((&*program, opts, &None))?;
+    let shim = match &kind {
+        ast::ImportKind::Function(f) => f.shim.clone(),
+        _ => unreachable!(),
+    };
+    program.imports.push(ast::Import {
+        module: None,
+        js_namespace: None,
+        reexport: None,
+        kind,
+    });
+
+    Ok(ast::TraitMethod {
+        sig,
+        import,
+        shim,
+        comments: extract_doc_comments(&method.attrs),
+    })
+}
+
 impl MacroParse<BindgenAttrs> for syn::ItemForeignMod {
     fn macro_parse(self, program: &mut ast::Program, opts: BindgenAttrs) -> Result<(), Diagnostic> {
         let mut errors = Vec::new();
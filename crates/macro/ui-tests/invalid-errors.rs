@@ -0,0 +1,16 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(error)]
+pub enum A {}
+
+#[wasm_bindgen(error)]
+pub enum B<T> {
+    X(T),
+}
+
+#[wasm_bindgen(error, js_name = class)]
+pub enum C {
+    X,
+}
+
+fn main() {}
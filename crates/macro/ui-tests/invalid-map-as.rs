@@ -0,0 +1,14 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(map_as = "array")]
+    fn import_map(map: std::collections::HashMap<String, u32>);
+}
+
+#[wasm_bindgen(map_as = "record")]
+pub fn export_map() -> std::collections::HashMap<String, u32> {
+    std::collections::HashMap::new()
+}
+
+fn main() {}
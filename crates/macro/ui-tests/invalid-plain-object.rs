@@ -0,0 +1,29 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(plain_object)]
+pub struct PrivateField {
+    pub a: u32,
+    b: u32,
+}
+
+#[wasm_bindgen(plain_object)]
+pub struct SkippedField {
+    pub a: u32,
+    #[wasm_bindgen(skip)]
+    pub b: u32,
+}
+
+#[wasm_bindgen(plain_object, js_namespace = config)]
+pub struct Namespaced {
+    pub a: u32,
+}
+
+pub struct NotConvertible;
+
+#[wasm_bindgen(plain_object)]
+pub struct BadField {
+    pub a: u32,
+    pub b: NotConvertible,
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub trait Generic<T> {
+    fn get(&self) -> T;
+}
+
+#[wasm_bindgen]
+pub trait Super: Clone {
+    fn get(&self) -> u32;
+}
+
+#[wasm_bindgen]
+pub trait Items {
+    const A: u32;
+    type B;
+}
+
+#[wasm_bindgen]
+pub trait Methods {
+    fn default_body(&self) {}
+    fn by_value(self);
+    fn by_mut(&mut self);
+    fn no_receiver();
+    async fn asyncness(&self);
+    fn generic<T>(&self, x: T);
+}
+
+fn main() {}
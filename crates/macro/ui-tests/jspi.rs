@@ -0,0 +1,36 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    type Foo;
+
+    #[wasm_bindgen(suspending)]
+    async fn fetch_async();
+
+    #[wasm_bindgen(method, getter, suspending)]
+    fn value(this: &Foo) -> u32;
+}
+
+#[wasm_bindgen(promising)]
+pub async fn promising_async() {}
+
+#[wasm_bindgen(start, promising)]
+pub fn promising_start() {}
+
+#[wasm_bindgen]
+pub struct Bar;
+
+#[wasm_bindgen]
+impl Bar {
+    #[wasm_bindgen(constructor, promising)]
+    pub fn new() -> Bar {
+        Bar
+    }
+
+    #[wasm_bindgen(getter, promising)]
+    pub fn value(&self) -> u32 {
+        1
+    }
+}
+
+fn main() {}
@@ -24,6 +24,8 @@ macro_rules! shared_api {
             enums: Vec<Enum<'a>>,
             imports: Vec<Import<'a>>,
             structs: Vec<Struct<'a>>,
+            traits: Vec<Trait<'a>>,
+            errors: Vec<ErrorEnum<'a>>,
             // NOTE: Originally typescript_custom_sections are just some strings
             // But the expression type can only be parsed into a string during compilation
             // So when encoding, LitOrExpr contains two types, one is that expressions are parsed into strings during compilation, and the other is can be parsed directly.
@@ -66,6 +68,7 @@ macro_rules! shared_api {
             shim: &'a str,
             catch: bool,
             variadic: bool,
+            suspending: bool,
             assert_no_shim: bool,
             method: Option<MethodData<'a>>,
             structural: bool,
@@ -129,6 +132,7 @@ macro_rules! shared_api {
             js_namespace: Option<Vec<&'a str>>,
             method_kind: MethodKind<'a>,
             start: bool,
+            promising: bool,
         }
 
         struct Enum<'a> {
@@ -154,8 +158,10 @@ macro_rules! shared_api {
             generate_typescript: bool,
             generate_jsdoc: bool,
             variadic: bool,
+            map_as_object: bool,
             ret_ty_override: Option<&'a str>,
             ret_desc: Option<&'a str>,
+            location: Option<SourceLocation>,
         }
 
         struct FunctionArgumentData<'a> {
@@ -165,15 +171,23 @@ macro_rules! shared_api {
             desc: Option<&'a str>,
         }
 
+        struct SourceLocation {
+            file: String,
+            line: u32,
+            column: u32,
+        }
+
         struct Struct<'a> {
             name: &'a str,
             rust_name: &'a str,
             fields: Vec<StructField<'a>>,
             comments: Vec<&'a str>,
             is_inspectable: bool,
+            plain_object: bool,
             generate_typescript: bool,
             js_namespace: Option<Vec<&'a str>>,
             private: bool,
+            location: Option<SourceLocation>,
         }
 
         struct StructField<'a> {
@@ -184,6 +198,25 @@ macro_rules! shared_api {
             generate_jsdoc: bool,
         }
 
+        struct Trait<'a> {
+            name: &'a str,
+            methods: Vec<TraitMethod<'a>>,
+            comments: Vec<&'a str>,
+            generate_typescript: bool,
+        }
+
+        struct TraitMethod<'a> {
+            shim: &'a str,
+            comments: Vec<&'a str>,
+        }
+
+        struct ErrorEnum<'a> {
+            name: &'a str,
+            variants: Vec<String>,
+            comments: Vec<&'a str>,
+            generate_typescript: bool,
+        }
+
         struct LocalModule<'a> {
             identifier: &'a str,
             contents: &'a str,
@@ -221,6 +254,19 @@ pub fn new_function(struct_name: &str) -> String {
     name
 }
 
+pub fn error_new_function(error_name: &str) -> String {
+    let mut name = "__wbg_".to_string();
+    name.extend(error_name.chars().flat_map(|s| s.to_lowercase()));
+    name.push_str("_error_new");
+    name
+}
+
+pub fn throws_function(export_name: &str) -> String {
+    let mut name = "__wbg_throws_".to_string();
+    name.push_str(export_name);
+    name
+}
+
 pub fn free_function(struct_name: &str) -> String {
     let mut name = "__wbg_".to_string();
     name.extend(struct_name.chars().flat_map(|s| s.to_lowercase()));
@@ -41,4 +41,8 @@ tys! {
     UNIT
     CLAMPED
     NONNULL
+    TUPLE
+    MAP
+    ITERATOR
+    ASYNC_ITERATOR
 }
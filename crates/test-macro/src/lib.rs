@@ -82,6 +82,17 @@ fn bindgen(
     }
     let ident = find_ident(&mut body).expect("expected a function name");
 
+    let timeout_par = match &attributes.timeout {
+        Some(lit) if !attributes.r#async && !is_bench => {
+            return compile_error(lit.span(), "`timeout` is only supported for async tests");
+        }
+        Some(lit) => match parse_timeout(lit) {
+            Ok(ms) => quote! { ::core::option::Option::Some(#ms) },
+            Err(error) => return error.to_compile_error().into(),
+        },
+        None => quote! { ::core::option::Option::None },
+    };
+
     let mut tokens = Vec::<TokenTree>::new();
 
     let should_panic_par = match &should_panic {
@@ -120,12 +131,13 @@ fn bindgen(
     };
 
     let test_body = if attributes.r#async || is_bench {
-        quote! { cx.execute_async(test_name, #exec_ident, #should_panic_par, #ignore_par); }
+        quote! { cx.execute_async(test_name, #exec_ident, #should_panic_par, #ignore_par, #timeout_par); }
     } else {
         quote! { cx.execute_sync(test_name, #exec_ident, #should_panic_par, #ignore_par); }
     };
 
     let ignore_name = if ignore.is_some() { "$" } else { "" };
+    let isolated_name = if attributes.isolated { "!" } else { "" };
 
     let wasm_bindgen_path = attributes.wasm_bindgen_path;
     let prefix = if is_bench { "__wbgb_" } else { "__wbgt_" };
@@ -133,7 +145,7 @@ fn bindgen(
         quote! {
             const _: () = {
                 #wasm_bindgen_path::__rt::wasm_bindgen::__wbindgen_coverage! {
-                #[export_name = ::core::concat!(#prefix, #ignore_name, "_", ::core::module_path!(), "::", ::core::stringify!(#ident))]
+                #[export_name = ::core::concat!(#prefix, #ignore_name, #isolated_name, "_", ::core::module_path!(), "::", ::core::stringify!(#ident))]
                 #[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none", target_os = "wasi")))]
                 extern "C" fn __wbgt_test(cx: &#wasm_bindgen_path::__rt::Context) {
                     let test_name = ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#ident));
@@ -322,6 +334,39 @@ fn parse_ignore(
     Err(compile_error(span, "malformed `#[ignore]` attribute"))
 }
 
+/// Parses a duration like `"500ms"`, `"5s"` or `"2m"` into milliseconds.
+fn parse_timeout(lit: &syn::LitStr) -> syn::Result<u32> {
+    let malformed = || {
+        syn::Error::new(
+            lit.span(),
+            "malformed `timeout`, expected a duration like \"500ms\", \"5s\" or \"2m\"",
+        )
+    };
+
+    let value = lit.value();
+    let (number, factor) = if let Some(number) = value.strip_suffix("ms") {
+        (number, 1)
+    } else if let Some(number) = value.strip_suffix('s') {
+        (number, 1000)
+    } else if let Some(number) = value.strip_suffix('m') {
+        (number, 60 * 1000)
+    } else {
+        return Err(malformed());
+    };
+
+    let ms = number
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| malformed())?
+        .saturating_mul(factor);
+
+    // The maximum delay supported by `setTimeout`.
+    match u32::try_from(ms) {
+        Ok(ms) if ms > 0 && ms <= i32::MAX as u32 => Ok(ms),
+        _ => Err(syn::Error::new(lit.span(), "`timeout` is out of range")),
+    }
+}
+
 fn find_ident(iter: &mut impl Iterator<Item = TokenTree>) -> Option<Ident> {
     match iter.next()? {
         TokenTree::Ident(i) => Some(i),
@@ -340,6 +385,8 @@ struct Attributes {
     r#async: bool,
     wasm_bindgen_path: syn::Path,
     unsupported: Option<syn::Meta>,
+    timeout: Option<syn::LitStr>,
+    isolated: bool,
 }
 
 impl Default for Attributes {
@@ -348,6 +395,8 @@ impl Default for Attributes {
             r#async: false,
             wasm_bindgen_path: syn::parse_quote!(::wasm_bindgen_test),
             unsupported: None,
+            timeout: None,
+            isolated: false,
         }
     }
 }
@@ -360,6 +409,10 @@ impl Attributes {
             self.wasm_bindgen_path = meta.value()?.parse::<syn::Path>()?;
         } else if meta.path.is_ident("unsupported") {
             self.unsupported = Some(meta.value()?.parse::<syn::Meta>()?);
+        } else if meta.path.is_ident("timeout") {
+            self.timeout = Some(meta.value()?.parse::<syn::LitStr>()?);
+        } else if meta.path.is_ident("isolated") {
+            self.isolated = true;
         } else {
             return Err(meta.error("unknown attribute"));
         }
@@ -0,0 +1,34 @@
+#![no_implicit_prelude]
+
+extern crate wasm_bindgen_test_macro;
+
+use wasm_bindgen_test_macro::wasm_bindgen_test;
+
+#[wasm_bindgen_test(timeout = "500ms")]
+async fn success_1() {}
+
+#[wasm_bindgen_test(timeout = "5s")]
+async fn success_2() {}
+
+#[wasm_bindgen_test(timeout = "2m")]
+async fn success_3() {}
+
+#[wasm_bindgen_test(timeout = "5s")]
+fn fail_1() {}
+
+#[wasm_bindgen_test(timeout = "5")]
+async fn fail_2() {}
+
+#[wasm_bindgen_test(timeout = "5h")]
+async fn fail_3() {}
+
+#[wasm_bindgen_test(timeout = "0s")]
+async fn fail_4() {}
+
+#[wasm_bindgen_test(timeout = "100000m")]
+async fn fail_5() {}
+
+#[wasm_bindgen_test(timeout = 5)]
+async fn fail_6() {}
+
+fn main() {}
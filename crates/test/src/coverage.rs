@@ -18,6 +18,13 @@ pub fn __wbgtest_cov_dump() -> Option<Vec<u8>> {
     Some(coverage)
 }
 
+/// Tells the test runner that coverage is collected. Only the instance dumping
+/// it may run tests, so they can't be spread across threads.
+#[cfg(wasm_bindgen_unstable_test_coverage)]
+#[link_section = "__wasm_bindgen_test_coverage"]
+#[used]
+static __WBG_TEST_COVERAGE: [u8; 1] = [0x01];
+
 #[cfg(not(wasm_bindgen_unstable_test_coverage))]
 #[wasm_bindgen]
 pub fn __wbgtest_cov_dump() -> Option<Vec<u8>> {
@@ -55,6 +55,8 @@ macro_rules! console_log {
 ///   node.js, which is the default for executing tests.
 /// * `run_in_service_worker` - requires that this test is run in a service worker rather than
 ///   node.js, which is the default for executing tests.
+/// * `run_in_bun` - requires that this test is run in Bun rather than node.js,
+///   which is the default for executing tests.
 ///
 /// This macro may be invoked at most one time per test suite (an entire binary
 /// like `tests/foo.rs`, not per module)
@@ -108,6 +110,14 @@ macro_rules! wasm_bindgen_test_configure {
             $crate::wasm_bindgen_test_configure!($($others)*);
         };
     );
+    (run_in_bun $($others:tt)*) => (
+        const _: () = {
+            #[link_section = "__wasm_bindgen_test_unstable"]
+            #[cfg(target_arch = "wasm32")]
+            pub static __WBG_TEST_RUN_IN_BUN: [u8; 1] = [0x06];
+            $crate::wasm_bindgen_test_configure!($($others)*);
+        };
+    );
     () => ()
 }
 
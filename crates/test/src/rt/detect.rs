@@ -18,6 +18,11 @@ extern "C" {
 
     type Deno;
 
+    #[wasm_bindgen(method, getter, structural, js_name = Bun)]
+    fn bun(me: &Scope) -> Option<Bun>;
+
+    type Bun;
+
     type Constructor;
     #[wasm_bindgen(method, getter, structural)]
     fn name(me: &Constructor) -> String;
@@ -33,10 +38,8 @@ pub fn detect() -> Runtime {
             "DedicatedWorkerGlobalScope"
             | "SharedWorkerGlobalScope"
             | "ServiceWorkerGlobalScope" => Runtime::Worker,
-            _ => match scope.deno() {
-                Some(_) => Runtime::Node,
-                _ => Runtime::Browser,
-            },
+            _ if scope.deno().is_some() || scope.bun().is_some() => Runtime::Node,
+            _ => Runtime::Browser,
         },
         None => Runtime::Node,
     }
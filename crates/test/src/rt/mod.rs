@@ -103,7 +103,9 @@ use js_sys::{Array, Function, Number, Promise, Undefined};
 pub use wasm_bindgen;
 
 use wasm_bindgen::prelude::*;
-use wasm_bindgen_futures::future_to_promise;
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+
+use self::report::{OutputFormat, Report, Summary, WorkerEvent};
 
 // Maximum number of tests to execute concurrently. Eventually this should be a
 // configuration option specified at runtime or at compile time rather than
@@ -120,6 +122,7 @@ pub mod browser;
 pub mod criterion;
 pub mod detect;
 pub mod node;
+mod report;
 mod scoped_tls;
 /// Directly depending on wasm-bindgen-test-based libraries should be avoided,
 /// as it creates a circular dependency that breaks their usage within `wasm-bindgen-test`.
@@ -145,6 +148,12 @@ struct State {
     /// Include ignored tests.
     include_ignored: Cell<bool>,
 
+    /// Format of the test results.
+    format: Cell<OutputFormat>,
+
+    /// Results collected for the JUnit report.
+    reports: RefCell<Vec<Report>>,
+
     /// Counter of the number of tests that have succeeded.
     succeeded_count: Cell<usize>,
 
@@ -156,9 +165,9 @@ struct State {
 
     /// A list of all tests which have failed.
     ///
-    /// Each test listed here is paired with a `JsValue` that represents the
-    /// exception thrown which caused the test to fail.
-    failures: RefCell<Vec<(Test, Failure)>>,
+    /// Each test listed here is paired with an explanation of why it failed,
+    /// including its captured output.
+    failures: RefCell<Vec<(String, String)>>,
 
     /// Remaining tests to execute, when empty we're just waiting on the
     /// `Running` tests to finish.
@@ -168,10 +177,18 @@ struct State {
     /// of asynchronous work, so they're sitting on the running list.
     running: RefCell<Vec<Test>>,
 
+    /// Resolves once the harness is done with the page of the last failed
+    /// test, no other test is started until then.
+    paused: RefCell<Option<JsFuture>>,
+
     /// How to actually format output, either node.js or browser-specific
     /// implementation.
     formatter: Box<dyn Formatter>,
 
+    /// Whether the output is forwarded to the `Context` of another thread,
+    /// which then always uses the JSON format.
+    forwarded: bool,
+
     /// Timing the total duration.
     timer: Option<Timer>,
 }
@@ -185,6 +202,8 @@ enum Failure {
     /// A test that `should_panic` with a specific message,
     /// but panicked with a different message.
     ShouldPanicExpected,
+    /// A test that didn't complete within its timeout.
+    Timeout,
 }
 
 /// Representation of one test that needs to be executed.
@@ -196,6 +215,10 @@ struct Test {
     future: Pin<Box<dyn Future<Output = Result<(), JsValue>>>>,
     output: Rc<RefCell<Output>>,
     should_panic: Option<Option<&'static str>>,
+    /// Timeout in milliseconds.
+    timeout: Option<u32>,
+    /// When the test started running, see [`State::now`].
+    started: Option<f64>,
 }
 
 /// Captured output of each test.
@@ -208,6 +231,7 @@ struct Output {
     error: String,
     panic: String,
     should_panic: bool,
+    timed_out: bool,
 }
 
 enum TestResult {
@@ -252,6 +276,22 @@ trait Formatter {
     fn stringify_error(&self, val: &JsValue) -> String;
 }
 
+/// Formatter passing all output to the `Context` of another thread.
+struct Forward {
+    post: Function,
+    inner: Box<dyn Formatter>,
+}
+
+impl Formatter for Forward {
+    fn writeln(&self, line: &str) {
+        let _ = self.post.call1(&JsValue::null(), &JsValue::from_str(line));
+    }
+
+    fn stringify_error(&self, val: &JsValue) -> String {
+        self.inner.stringify_error(val)
+    }
+}
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = console, js_name = log)]
@@ -279,6 +319,17 @@ extern "C" {
     /// Binding to [`Performance.now()`](https://developer.mozilla.org/en-US/docs/Web/API/Performance/now).
     #[wasm_bindgen(method)]
     fn now(this: &Performance) -> f64;
+
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &Function, timeout: u32) -> JsValue;
+
+    #[wasm_bindgen(js_name = clearTimeout)]
+    fn clear_timeout(id: &JsValue);
+
+    /// Set by the headless browser harness to snapshot the page when a test
+    /// fails, see `State::on_failure`.
+    #[wasm_bindgen(thread_local_v2, js_name = __wbg_test_on_failure)]
+    static ON_FAILURE: Option<Function>;
 }
 
 /// Internal implementation detail of the `console_log!` macro.
@@ -298,8 +349,12 @@ impl Context {
     /// A `Context` is the main structure through which test execution is
     /// coordinated, and this will collect output and results for all executed
     /// tests.
+    ///
+    /// If `forward` is given, all output is passed to it instead of being
+    /// printed, to be aggregated by the `Context` of the main thread with
+    /// `report`. This is used by `--test-threads` to run tests in workers.
     #[wasm_bindgen(constructor)]
-    pub fn new(is_bench: bool) -> Context {
+    pub fn new(is_bench: bool, forward: Option<Function>) -> Context {
         fn panic_handling(mut message: String) {
             let should_panic = if !CURRENT_OUTPUT.is_set() {
                 false
@@ -353,6 +408,14 @@ impl Context {
             detect::Runtime::Node => Box::new(node::Node::new()) as Box<dyn Formatter>,
             detect::Runtime::Worker => Box::new(worker::Worker::new()) as Box<dyn Formatter>,
         };
+        let forwarded = forward.is_some();
+        let formatter = match forward {
+            Some(post) => Box::new(Forward {
+                post,
+                inner: formatter,
+            }),
+            None => formatter,
+        };
 
         let timer = Timer::new();
 
@@ -360,13 +423,21 @@ impl Context {
             state: Rc::new(State {
                 is_bench,
                 include_ignored: Default::default(),
+                format: Cell::new(if forwarded {
+                    OutputFormat::Json
+                } else {
+                    OutputFormat::Pretty
+                }),
+                reports: Default::default(),
                 failures: Default::default(),
                 succeeded_count: Default::default(),
                 filtered_count: Default::default(),
                 ignored_count: Default::default(),
                 remaining: Default::default(),
                 running: Default::default(),
+                paused: Default::default(),
                 formatter,
+                forwarded,
                 timer,
             }),
         }
@@ -382,6 +453,13 @@ impl Context {
         self.state.filtered_count.set(filtered);
     }
 
+    /// Handle `--format` flag.
+    pub fn format(&mut self, format: &str) {
+        if !self.state.forwarded {
+            self.state.format.set(OutputFormat::parse(format));
+        }
+    }
+
     /// Executes a list of tests, returning a promise representing their
     /// eventual completion.
     ///
@@ -392,12 +470,7 @@ impl Context {
     /// The promise returned resolves to either `true` if all tests passed or
     /// `false` if at least one test failed.
     pub fn run(&self, tests: Vec<JsValue>) -> Promise {
-        if !self.state.is_bench {
-            let noun = if tests.len() == 1 { "test" } else { "tests" };
-            self.state
-                .formatter
-                .writeln(&format!("running {} {noun}", tests.len()));
-        }
+        self.start(tests.len());
 
         // Execute all our test functions through their Wasm shims (unclear how
         // to pass native function pointers around here). Each test will
@@ -428,6 +501,64 @@ impl Context {
             Ok(JsValue::from(passed))
         })
     }
+
+    /// Starts a test suite whose tests are executed by the contexts of other
+    /// threads, which forward their output to `report`.
+    pub fn start(&self, test_count: usize) {
+        if self.state.is_bench {
+            return;
+        }
+        match self.state.format.get() {
+            OutputFormat::Pretty => {
+                let noun = if test_count == 1 { "test" } else { "tests" };
+                self.state
+                    .formatter
+                    .writeln(&format!("running {test_count} {noun}"));
+            }
+            OutputFormat::Json => self
+                .state
+                .formatter
+                .writeln(&report::json_suite_started(test_count)),
+            OutputFormat::Junit => (),
+        }
+    }
+
+    /// Records a line of output forwarded by the context of another thread.
+    pub fn report(&self, line: &str) {
+        match report::parse_json_event(line) {
+            Some(WorkerEvent::Started(name)) => {
+                if self.state.format.get() == OutputFormat::Json && !self.state.is_bench {
+                    self.state
+                        .formatter
+                        .writeln(&report::json_test_started(&name));
+                }
+            }
+            Some(WorkerEvent::Finished(report)) => self.state.log_report(report),
+            // The summary of the other thread is superseded by our own.
+            None if line.starts_with('{') => (),
+            None => self.state.formatter.writeln(line),
+        }
+    }
+
+    /// Records an error of another thread which prevented some of its tests
+    /// from reporting their result, e.g. because it crashed.
+    pub fn report_error(&self, error: &JsValue) {
+        self.state.log_report(Report {
+            name: "test thread".to_string(),
+            result: TestResult::Err(JsValue::NULL),
+            exec_time: None,
+            output: self.state.formatter.stringify_error(error),
+        });
+    }
+
+    /// Finishes a test suite started with `start`, printing the results of
+    /// all reported tests.
+    ///
+    /// Returns `true` if all tests passed.
+    pub fn finish(&self) -> bool {
+        self.state.print_results();
+        self.state.failures.borrow().is_empty()
+    }
 }
 
 crate::scoped_thread_local!(static CURRENT_OUTPUT: RefCell<Output>);
@@ -519,18 +650,28 @@ impl Context {
         should_panic: Option<Option<&'static str>>,
         ignore: Option<Option<&'static str>>,
     ) {
-        self.execute(name, async { f().into_js_result() }, should_panic, ignore);
+        self.execute(
+            name,
+            async { f().into_js_result() },
+            should_panic,
+            ignore,
+            None,
+        );
     }
 
     /// Entry point for an asynchronous in wasm. The
     /// `#[wasm_bindgen_test(async)]` macro generates invocations of this
     /// method.
+    ///
+    /// If a `timeout` in milliseconds is given, the test fails if it doesn't
+    /// complete in time and the remaining tests continue.
     pub fn execute_async<F>(
         &self,
         name: &str,
         f: impl FnOnce() -> F + 'static,
         should_panic: Option<Option<&'static str>>,
         ignore: Option<Option<&'static str>>,
+        timeout: Option<u32>,
     ) where
         F: Future + 'static,
         F::Output: Termination,
@@ -540,6 +681,7 @@ impl Context {
             async { f().await.into_js_result() },
             should_panic,
             ignore,
+            timeout,
         )
     }
 
@@ -549,6 +691,7 @@ impl Context {
         test: impl Future<Output = Result<(), JsValue>> + 'static,
         should_panic: Option<Option<&'static str>>,
         ignore: Option<Option<&'static str>>,
+        timeout: Option<u32>,
     ) {
         // Remove the crate name to mimic libtest more closely.
         // This also removes our `__wbgt_` or `__wbgb_` prefix and the `ignored` and `should_panic` modifiers.
@@ -556,13 +699,12 @@ impl Context {
 
         if let Some(ignore) = ignore {
             if !self.state.include_ignored.get() {
-                self.state.formatter.log_test(
-                    self.state.is_bench,
-                    name,
-                    &TestResult::Ignored(ignore.map(str::to_owned)),
-                );
-                let ignored = self.state.ignored_count.get();
-                self.state.ignored_count.set(ignored + 1);
+                self.state.log_report(Report {
+                    name: name.to_string(),
+                    result: TestResult::Ignored(ignore.map(str::to_owned)),
+                    exec_time: None,
+                    output: String::new(),
+                });
                 return;
             }
         }
@@ -578,11 +720,22 @@ impl Context {
             output: output.clone(),
             test,
         };
+        let future: Pin<Box<dyn Future<Output = _>>> = match timeout {
+            Some(ms) => Box::pin(Timeout {
+                test: Box::pin(future),
+                ms,
+                output: output.clone(),
+                timer: None,
+            }),
+            None => Box::pin(future),
+        };
         self.state.remaining.borrow_mut().push(Test {
             name: name.to_string(),
-            future: Pin::from(Box::new(future)),
+            future,
             output,
             should_panic,
+            timeout,
+            started: None,
         });
     }
 }
@@ -595,6 +748,16 @@ impl Future for ExecuteTests {
     fn poll(self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<bool> {
         let mut running = self.0.running.borrow_mut();
         let mut remaining = self.0.remaining.borrow_mut();
+        let mut paused = self.0.paused.borrow_mut();
+
+        // Wait for the harness to be done with the page of a failed test
+        // before anything else changes it.
+        if let Some(future) = paused.as_mut() {
+            if Pin::new(future).poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            *paused = None;
+        }
 
         // First up, try to make progress on all active tests. Remove any
         // finished tests.
@@ -604,20 +767,23 @@ impl Future for ExecuteTests {
                 Poll::Pending => continue,
             };
             let test = running.remove(i);
-            self.0.log_test_result(test, result.into());
+            if let Some(future) = self.0.log_test_result(test, result.into()) {
+                *paused = Some(future);
+            }
         }
 
         // Next up, try to schedule as many tests as we can. Once we get a test
         // we `poll` it once to ensure we'll receive notifications. We only
         // want to schedule up to a maximum amount of work though, so this may
         // not schedule all tests.
-        while running.len() < CONCURRENCY {
+        while running.len() < CONCURRENCY && paused.is_none() {
             let mut test = match remaining.pop() {
                 Some(test) => test,
                 None => break,
             };
             // Output test invocation log for debugging failures with --nocapture
             console_log!("Invoking test: {}", test.name);
+            self.0.log_test_started(&mut test);
             let result = match test.future.as_mut().poll(cx) {
                 Poll::Ready(result) => result,
                 Poll::Pending => {
@@ -625,11 +791,16 @@ impl Future for ExecuteTests {
                     continue;
                 }
             };
-            self.0.log_test_result(test, result.into());
+            *paused = self.0.log_test_result(test, result.into());
         }
 
-        // Tests are still executing, we're registered to get a notification,
-        // keep going.
+        // Tests are still executing or the harness is looking at a failed
+        // test, we're registered to get a notification or get one now to poll
+        // the harness, keep going.
+        if paused.is_some() {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
         if !running.is_empty() {
             return Poll::Pending;
         }
@@ -645,60 +816,145 @@ impl Future for ExecuteTests {
 }
 
 impl State {
-    fn log_test_result(&self, test: Test, result: TestResult) {
-        // Save off the test for later processing when we print the final
-        // results.
-        if let Some(should_panic) = test.should_panic {
-            if let TestResult::Err(_e) = result {
-                if let Some(expected) = should_panic {
-                    if !test.output.borrow().panic.contains(expected) {
-                        self.formatter.log_test(
-                            self.is_bench,
-                            &test.name,
-                            &TestResult::Err(JsValue::NULL),
-                        );
-                        self.failures
-                            .borrow_mut()
-                            .push((test, Failure::ShouldPanicExpected));
-                        return;
-                    }
-                }
+    fn log_test_started(&self, test: &mut Test) {
+        test.started = self.now();
+        if self.format.get() == OutputFormat::Json && !self.is_bench {
+            self.formatter
+                .writeln(&report::json_test_started(&test.name));
+        }
+    }
 
-                self.formatter
-                    .log_test(self.is_bench, &test.name, &TestResult::Ok);
-                self.succeeded_count.set(self.succeeded_count.get() + 1);
-            } else {
-                self.formatter
-                    .log_test(self.is_bench, &test.name, &TestResult::Err(JsValue::NULL));
-                self.failures
-                    .borrow_mut()
-                    .push((test, Failure::ShouldPanic));
+    /// Logs the result of a finished test, returning a future to wait for
+    /// before starting another test if it failed, see [`State::on_failure`].
+    fn log_test_result(&self, test: Test, result: TestResult) -> Option<JsFuture> {
+        let failure = if test.output.borrow().timed_out {
+            Some(Failure::Timeout)
+        } else if let Some(should_panic) = test.should_panic {
+            match (result, should_panic) {
+                (TestResult::Err(_), Some(expected))
+                    if !test.output.borrow().panic.contains(expected) =>
+                {
+                    Some(Failure::ShouldPanicExpected)
+                }
+                (TestResult::Err(_), _) => None,
+                _ => Some(Failure::ShouldPanic),
             }
         } else {
-            self.formatter.log_test(self.is_bench, &test.name, &result);
-
             match result {
-                TestResult::Ok => self.succeeded_count.set(self.succeeded_count.get() + 1),
-                TestResult::Err(e) => self.failures.borrow_mut().push((test, Failure::Error(e))),
-                _ => (),
+                TestResult::Err(e) => Some(Failure::Error(e)),
+                _ => None,
+            }
+        };
+
+        let exec_time = test
+            .started
+            .zip(self.now())
+            .map(|(started, now)| (now - started) / 1000.);
+        let result = match failure {
+            Some(_) => TestResult::Err(JsValue::NULL),
+            None => TestResult::Ok,
+        };
+        let output = match &failure {
+            Some(failure) => self.failure_output(&test, failure),
+            None => self.console_output(&test.output.borrow()),
+        };
+        self.log_report(Report {
+            name: test.name.clone(),
+            result,
+            exec_time,
+            output,
+        });
+        failure.and_then(|_| self.on_failure(&test.name))
+    }
+
+    /// Lets the harness look at the page while it's still in the state the
+    /// failed test `name` left it in, such as the headless browser runner
+    /// taking a screenshot.
+    ///
+    /// Returns the future the harness resolves once it's done, or `None` if
+    /// the harness doesn't snapshot failed tests.
+    fn on_failure(&self, name: &str) -> Option<JsFuture> {
+        let hook = ON_FAILURE.with(Option::clone)?;
+        let done = hook
+            .call1(&JsValue::null(), &JsValue::from_str(name))
+            .ok()?;
+        Some(JsFuture::from(Promise::resolve(&done)))
+    }
+
+    /// Logs the result of a finished test and saves it off for later
+    /// processing when we print the final results.
+    fn log_report(&self, mut report: Report) {
+        match report.result {
+            TestResult::Ok => self.succeeded_count.set(self.succeeded_count.get() + 1),
+            TestResult::Err(_) => self
+                .failures
+                .borrow_mut()
+                .push((report.name.clone(), report.output.clone())),
+            TestResult::Ignored(_) => self.ignored_count.set(self.ignored_count.get() + 1),
+        }
+        // The pretty format prints the output of failed tests at the end.
+        if self.format.get() == OutputFormat::Pretty {
+            report.output = String::new();
+        }
+        self.log_test(report);
+    }
+
+    /// Logs the result of a finished or ignored test in the configured
+    /// format.
+    fn log_test(&self, report: Report) {
+        match self.format.get() {
+            OutputFormat::Pretty => {
+                self.formatter
+                    .log_test(self.is_bench, &report.name, &report.result)
             }
+            OutputFormat::Json if !self.is_bench => {
+                self.formatter.writeln(&report::json_test(&report))
+            }
+            OutputFormat::Json => (),
+            OutputFormat::Junit => self.reports.borrow_mut().push(report),
         }
     }
 
+    /// Milliseconds since an arbitrary point in time, if supported.
+    fn now(&self) -> Option<f64> {
+        self.timer.as_ref().map(Timer::now)
+    }
+
     fn print_results(&self) {
         let failures = self.failures.borrow();
+        let summary = Summary {
+            passed: self.succeeded_count.get(),
+            failed: failures.len(),
+            ignored: self.ignored_count.get(),
+            filtered_out: self.filtered_count.get(),
+            exec_time: self.timer.as_ref().map(Timer::elapsed),
+        };
+        match self.format.get() {
+            OutputFormat::Pretty => (),
+            OutputFormat::Json => {
+                self.formatter.writeln(&report::json_suite(&summary));
+                return;
+            }
+            OutputFormat::Junit => {
+                let reports = self.reports.borrow();
+                self.formatter.writeln(&report::junit(&reports, &summary));
+                return;
+            }
+        }
+
         if !failures.is_empty() {
             self.formatter.writeln("\nfailures:\n");
-            for (test, failure) in failures.iter() {
-                self.print_failure(test, failure);
+            for (name, output) in failures.iter() {
+                let msg = format!("---- {name} output ----\n{}", tab(output));
+                self.formatter.writeln(&msg);
             }
             self.formatter.writeln("failures:\n");
-            for (test, _) in failures.iter() {
-                self.formatter.writeln(&format!("    {}", test.name));
+            for (name, _) in failures.iter() {
+                self.formatter.writeln(&format!("    {name}"));
             }
         }
-        let finished_in = if let Some(timer) = &self.timer {
-            format!("; finished in {:.2?}s", timer.elapsed())
+        let finished_in = if let Some(exec_time) = summary.exec_time {
+            format!("; finished in {exec_time:.2?}s")
         } else {
             String::new()
         };
@@ -711,10 +967,10 @@ impl State {
              {} filtered out\
              {finished_in}\n",
             if failures.is_empty() { "ok" } else { "FAILED" },
-            self.succeeded_count.get(),
-            failures.len(),
-            self.ignored_count.get(),
-            self.filtered_count.get()
+            summary.passed,
+            summary.failed,
+            summary.ignored,
+            summary.filtered_out,
         ));
     }
 
@@ -728,7 +984,19 @@ impl State {
         logs.push('\n');
     }
 
-    fn print_failure(&self, test: &Test, failure: &Failure) {
+    /// Captured console output of a test.
+    fn console_output(&self, output: &Output) -> String {
+        let mut logs = String::new();
+        self.accumulate_console_output(&mut logs, "debug", &output.debug);
+        self.accumulate_console_output(&mut logs, "log", &output.log);
+        self.accumulate_console_output(&mut logs, "info", &output.info);
+        self.accumulate_console_output(&mut logs, "warn", &output.warn);
+        self.accumulate_console_output(&mut logs, "error", &output.error);
+        logs
+    }
+
+    /// Explanation of why a test failed, including its console output.
+    fn failure_output(&self, test: &Test, failure: &Failure) -> String {
         let mut logs = String::new();
         let output = test.output.borrow();
 
@@ -747,14 +1015,17 @@ impl State {
                     test.should_panic.unwrap().unwrap()
                 ));
             }
+            Failure::Timeout => {
+                logs.push_str(&format!(
+                    "note: {} did not complete within {}ms\n\n",
+                    test.name,
+                    test.timeout.unwrap()
+                ));
+            }
             _ => (),
         }
 
-        self.accumulate_console_output(&mut logs, "debug", &output.debug);
-        self.accumulate_console_output(&mut logs, "log", &output.log);
-        self.accumulate_console_output(&mut logs, "info", &output.info);
-        self.accumulate_console_output(&mut logs, "warn", &output.warn);
-        self.accumulate_console_output(&mut logs, "error", &output.error);
+        logs.push_str(&self.console_output(&output));
 
         if let Failure::Error(error) = failure {
             logs.push_str("JS exception that was thrown:\n");
@@ -762,8 +1033,7 @@ impl State {
             logs.push_str(&tab(&error_string));
         }
 
-        let msg = format!("---- {} output ----\n{}", test.name, tab(&logs));
-        self.formatter.writeln(&msg);
+        logs
     }
 }
 
@@ -830,6 +1100,54 @@ impl<F: Future<Output = Result<(), JsValue>>> Future for TestFuture<F> {
     }
 }
 
+/// A wrapper future around tests with a timeout.
+///
+/// The timer is started when the test is polled for the first time, so time
+/// spent waiting for other tests to finish doesn't count. If it fires first,
+/// the test is dropped and fails.
+struct Timeout<F> {
+    test: Pin<Box<F>>,
+    ms: u32,
+    output: Rc<RefCell<Output>>,
+    /// The timer future and the ID to clear the timer with.
+    timer: Option<(JsFuture, JsValue)>,
+}
+
+impl<F: Future<Output = Result<(), JsValue>>> Future for Timeout<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        if let Poll::Ready(result) = self.test.as_mut().poll(cx) {
+            return Poll::Ready(result);
+        }
+
+        let ms = self.ms;
+        let (timer, _) = self.timer.get_or_insert_with(|| {
+            let mut id = JsValue::UNDEFINED;
+            let promise = Promise::new(&mut |resolve, _reject| id = set_timeout(&resolve, ms));
+            (JsFuture::from(promise), id)
+        });
+
+        match Pin::new(timer).poll(cx) {
+            Poll::Ready(_) => {
+                self.output.borrow_mut().timed_out = true;
+                Poll::Ready(Err(
+                    JsError::new(&format!("test timed out after {ms}ms")).into()
+                ))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<F> Drop for Timeout<F> {
+    fn drop(&mut self) {
+        if let Some((_, id)) = &self.timer {
+            clear_timeout(id);
+        }
+    }
+}
+
 fn tab(s: &str) -> String {
     let mut result = String::new();
     for line in s.lines() {
@@ -859,7 +1177,11 @@ impl Timer {
         })
     }
 
+    fn now(&self) -> f64 {
+        self.performance.now()
+    }
+
     fn elapsed(&self) -> f64 {
-        (self.performance.now() - self.started) / 1000.
+        (self.now() - self.started) / 1000.
     }
 }
@@ -0,0 +1,201 @@
+//! Machine-readable test result formats.
+//!
+//! `--format json` emits the same event stream as libtest's unstable JSON
+//! output, one object per line, while `--format junit` collects all results
+//! and emits a single JUnit XML report at the end of the test suite.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use serde_json::{json, Map, Value};
+use wasm_bindgen::JsValue;
+
+use super::TestResult;
+
+/// Format of the test results, forwarded from `--format`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum OutputFormat {
+    /// Human-readable output like libtest.
+    Pretty,
+    /// libtest-compatible JSON events.
+    Json,
+    /// A JUnit XML report.
+    Junit,
+}
+
+impl OutputFormat {
+    pub(super) fn parse(format: &str) -> Self {
+        match format {
+            "json" => Self::Json,
+            "junit" => Self::Junit,
+            _ => Self::Pretty,
+        }
+    }
+}
+
+/// Result of a single test as reported by the machine-readable formats.
+pub(super) struct Report {
+    pub(super) name: String,
+    pub(super) result: TestResult,
+    /// Execution time in seconds, if it could be measured.
+    pub(super) exec_time: Option<f64>,
+    /// Captured console output and failure notes.
+    pub(super) output: String,
+}
+
+/// Summary of the whole test suite.
+pub(super) struct Summary {
+    pub(super) passed: usize,
+    pub(super) failed: usize,
+    pub(super) ignored: usize,
+    pub(super) filtered_out: usize,
+    pub(super) exec_time: Option<f64>,
+}
+
+/// The event emitted when the test suite starts.
+pub(super) fn json_suite_started(test_count: usize) -> String {
+    json!({ "type": "suite", "event": "started", "test_count": test_count }).to_string()
+}
+
+/// The event emitted when a test starts.
+pub(super) fn json_test_started(name: &str) -> String {
+    json!({ "type": "test", "event": "started", "name": name }).to_string()
+}
+
+/// The event emitted when a test finished or was ignored.
+pub(super) fn json_test(report: &Report) -> String {
+    let mut event = Map::new();
+    event.insert("type".into(), "test".into());
+    event.insert("name".into(), report.name.as_str().into());
+
+    let kind = match &report.result {
+        TestResult::Ok => "ok",
+        TestResult::Err(_) => "failed",
+        TestResult::Ignored(reason) => {
+            if let Some(reason) = reason {
+                event.insert("message".into(), reason.as_str().into());
+            }
+            "ignored"
+        }
+    };
+    event.insert("event".into(), kind.into());
+
+    if let Some(exec_time) = report.exec_time {
+        event.insert("exec_time".into(), exec_time.into());
+    }
+    if !report.output.is_empty() {
+        event.insert("stdout".into(), report.output.as_str().into());
+    }
+
+    Value::Object(event).to_string()
+}
+
+/// A test event forwarded by the `Context` of another thread.
+pub(super) enum WorkerEvent {
+    Started(String),
+    Finished(Report),
+}
+
+/// Parses an event emitted by [`json_test_started`] or [`json_test`].
+pub(super) fn parse_json_event(line: &str) -> Option<WorkerEvent> {
+    let event: Value = serde_json::from_str(line).ok()?;
+    if event["type"] != "test" {
+        return None;
+    }
+    let name = event["name"].as_str()?.to_string();
+    let result = match event["event"].as_str()? {
+        "started" => return Some(WorkerEvent::Started(name)),
+        "ok" => TestResult::Ok,
+        "failed" => TestResult::Err(JsValue::NULL),
+        "ignored" => TestResult::Ignored(event["message"].as_str().map(String::from)),
+        _ => return None,
+    };
+    Some(WorkerEvent::Finished(Report {
+        name,
+        result,
+        exec_time: event["exec_time"].as_f64(),
+        output: event["stdout"].as_str().unwrap_or_default().to_string(),
+    }))
+}
+
+/// The event emitted when the test suite finished.
+pub(super) fn json_suite(summary: &Summary) -> String {
+    let mut event = json!({
+        "type": "suite",
+        "event": if summary.failed == 0 { "ok" } else { "failed" },
+        "passed": summary.passed,
+        "failed": summary.failed,
+        "ignored": summary.ignored,
+        "measured": 0,
+        "filtered_out": summary.filtered_out,
+    });
+    if let Some(exec_time) = summary.exec_time {
+        event["exec_time"] = exec_time.into();
+    }
+    event.to_string()
+}
+
+/// Renders all results as a JUnit XML report.
+pub(super) fn junit(reports: &[Report], summary: &Summary) -> String {
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push_str("<testsuites>");
+    xml.push_str(&format!(
+        r#"<testsuite name="test" package="test" id="0" errors="0" failures="{}" tests="{}" skipped="{}" time="{}">"#,
+        summary.failed,
+        reports.len(),
+        summary.ignored,
+        summary.exec_time.unwrap_or_default(),
+    ));
+
+    for report in reports {
+        // Mirror libtest, which uses the module path as the class name.
+        let (class_name, name) = report
+            .name
+            .rsplit_once("::")
+            .unwrap_or(("integration", &report.name));
+        xml.push_str(&format!(
+            r#"<testcase classname="{}" name="{}" time="{}""#,
+            escape(class_name),
+            escape(name),
+            report.exec_time.unwrap_or_default(),
+        ));
+
+        let body = match &report.result {
+            TestResult::Err(_) => format!(
+                r#"<failure type="assert">{}</failure>"#,
+                escape(&report.output)
+            ),
+            TestResult::Ignored(_) => "<skipped/>".into(),
+            TestResult::Ok if !report.output.is_empty() => {
+                format!("<system-out>{}</system-out>", escape(&report.output))
+            }
+            TestResult::Ok => String::new(),
+        };
+        if body.is_empty() {
+            xml.push_str("/>");
+        } else {
+            xml.push_str(&format!(">{body}</testcase>"));
+        }
+    }
+
+    xml.push_str("<system-out/><system-err/></testsuite></testsuites>");
+    xml
+}
+
+/// Escapes text for use in XML content and attributes.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters other than whitespace aren't allowed in XML.
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => escaped.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,11 @@
+import {
+  return_iterator,
+  return_words,
+} from './guide_supported_types_examples';
+
+for (const i of return_iterator(3)) {
+  console.log(i); // 0, 1, 2
+}
+
+let words = [...return_words('hello iterable world')];
+console.log(words); // ["hello", "iterable", "world"]
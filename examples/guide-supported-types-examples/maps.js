@@ -0,0 +1,20 @@
+import {
+  take_map_by_value,
+  return_map,
+  take_object,
+  return_option_object,
+} from './guide_supported_types_examples';
+
+take_map_by_value(new Map([['a', 1], ['b', 2]]));
+
+let m = return_map();
+console.log(m instanceof Map, m.get(1)); // true "one"
+
+take_object({ enabled: false });
+
+let o = return_option_object();
+if (o == null) {
+  // ...
+} else {
+  console.log(o.enabled); // true
+}
@@ -0,0 +1,13 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsIterator;
+
+#[wasm_bindgen]
+pub fn return_iterator(n: u32) -> JsIterator<u32> {
+    JsIterator::new(0..n)
+}
+
+#[wasm_bindgen]
+pub fn return_words(text: String) -> JsIterator<String> {
+    let words: Vec<String> = text.split_whitespace().map(String::from).collect();
+    JsIterator::new(words)
+}
@@ -6,9 +6,12 @@ pub mod boxed_number_slices;
 pub mod char;
 pub mod exported_types;
 pub mod imported_types;
+pub mod iterators;
 pub mod js_value;
+pub mod maps;
 pub mod number_slices;
 pub mod numbers;
 pub mod pointers;
 pub mod str;
 pub mod string;
+pub mod tuples;
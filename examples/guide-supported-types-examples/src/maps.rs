@@ -0,0 +1,18 @@
+use std::collections::{BTreeMap, HashMap};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn take_map_by_value(x: HashMap<String, u32>) {}
+
+#[wasm_bindgen]
+pub fn return_map() -> BTreeMap<u32, String> {
+    BTreeMap::from([(1, "one".to_string())])
+}
+
+#[wasm_bindgen(map_as = "object")]
+pub fn take_object(x: HashMap<String, bool>) {}
+
+#[wasm_bindgen(map_as = "object")]
+pub fn return_option_object() -> Option<HashMap<String, bool>> {
+    Some(HashMap::from([("enabled".to_string(), true)]))
+}
@@ -0,0 +1,17 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn take_tuple_by_value(x: (u32, String)) {}
+
+#[wasm_bindgen]
+pub fn return_tuple() -> (u32, String) {
+    (1, "one".to_string())
+}
+
+#[wasm_bindgen]
+pub fn take_option_tuple(x: Option<(f64, bool)>) {}
+
+#[wasm_bindgen]
+pub fn return_option_tuple() -> Option<(f64, bool)> {
+    Some((1.5, true))
+}
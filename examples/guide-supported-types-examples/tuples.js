@@ -0,0 +1,22 @@
+import {
+  take_tuple_by_value,
+  return_tuple,
+  take_option_tuple,
+  return_option_tuple,
+} from './guide_supported_types_examples';
+
+take_tuple_by_value([1, 'one']);
+
+let [n, s] = return_tuple();
+console.log(typeof n, typeof s); // "number" "string"
+
+take_option_tuple(null);
+take_option_tuple(undefined);
+take_option_tuple([1.5, true]);
+
+let t = return_option_tuple();
+if (t == null) {
+  // ...
+} else {
+  console.log(Array.isArray(t)); // true
+}
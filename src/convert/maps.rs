@@ -0,0 +1,134 @@
+//! Rust maps are passed to and from JS as `Map`s.
+//!
+//! Keys and values are converted with `JsValue::from` on the way out and with
+//! `TryFromJsValue` on the way in. Functions annotated with
+//! `#[wasm_bindgen(map_as = "object")]` use plain objects instead, which the
+//! generated JS glue converts to and from a `Map`.
+
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::convert::{
+    FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi, TryFromJsValue,
+};
+use crate::describe::{inform, WasmDescribe, MAP};
+use crate::{throw_str, JsCast, JsValue};
+
+macro_rules! maps {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $map:ty;)*) => ($(
+        $(#[$attr])*
+        impl<$($generics)*> WasmDescribe for $map
+        where
+            K: WasmDescribe,
+            V: WasmDescribe,
+        {
+            #[cfg_attr(wasm_bindgen_unstable_test_coverage, coverage(off))]
+            fn describe() {
+                inform(MAP);
+                K::describe();
+                V::describe();
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($generics)*> From<$map> for JsValue
+        where
+            JsValue: From<K> + From<V>,
+        {
+            fn from(map: $map) -> JsValue {
+                let js = crate::__WbindgenMap::new();
+                for (key, value) in map {
+                    js.set(key.into(), value.into());
+                }
+                js.into()
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($generics)*> TryFromJsValue for $map
+        where
+            K: TryFromJsValue,
+            V: TryFromJsValue,
+        {
+            fn try_from_js_value_ref(value: &JsValue) -> Option<Self> {
+                if !value.is_instance_of::<crate::__WbindgenMap>() {
+                    return None;
+                }
+                let entries = crate::__wbindgen_array_from(value);
+                // Every entry is a `[key, value]` array, so the first
+                // `undefined` marks the end of the entries.
+                (0..)
+                    .map(|i| crate::__wbindgen_reflect_get(&entries, i))
+                    .take_while(|entry| !entry.is_undefined())
+                    .map(|entry| <(K, V)>::try_from_js_value(entry).ok())
+                    .collect()
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($generics)*> IntoWasmAbi for $map
+        where
+            K: WasmDescribe,
+            V: WasmDescribe,
+            JsValue: From<K> + From<V>,
+        {
+            type Abi = u32;
+
+            #[inline]
+            fn into_abi(self) -> u32 {
+                <JsValue as From<Self>>::from(self).into_abi()
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($generics)*> OptionIntoWasmAbi for $map
+        where
+            K: WasmDescribe,
+            V: WasmDescribe,
+            JsValue: From<K> + From<V>,
+        {
+            #[inline]
+            fn none() -> u32 {
+                JsValue::none()
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($generics)*> FromWasmAbi for $map
+        where
+            K: WasmDescribe + TryFromJsValue,
+            V: WasmDescribe + TryFromJsValue,
+        {
+            type Abi = u32;
+
+            #[inline]
+            unsafe fn from_abi(js: u32) -> Self {
+                match Self::try_from_js_value(JsValue::from_abi(js)) {
+                    Ok(map) => map,
+                    Err(_) => throw_str("expected a Map with valid keys and values"),
+                }
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($generics)*> OptionFromWasmAbi for $map
+        where
+            K: WasmDescribe + TryFromJsValue,
+            V: WasmDescribe + TryFromJsValue,
+        {
+            #[inline]
+            fn is_none(js: &u32) -> bool {
+                JsValue::is_none(js)
+            }
+        }
+    )*)
+}
+
+maps! {
+    #[cfg(feature = "std")]
+    [K: Eq + Hash, V, S: BuildHasher + Default] HashMap<K, V, S>;
+    [K: Ord, V] BTreeMap<K, V>;
+}
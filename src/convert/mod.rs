@@ -7,8 +7,10 @@
 
 mod closures;
 mod impls;
+mod maps;
 mod slices;
 mod traits;
+mod tuples;
 
 pub use self::impls::*;
 pub use self::slices::WasmSlice;
@@ -0,0 +1,100 @@
+//! Rust tuples are passed to and from JS as arrays.
+//!
+//! Each element is converted with `JsValue::from` on the way out and with
+//! `TryFromJsValue` on the way in, so tuples can contain anything that can be
+//! stored in a `JsValue`, including other tuples.
+
+use crate::convert::{
+    FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi, TryFromJsValue,
+};
+use crate::describe::{inform, WasmDescribe, TUPLE};
+use crate::{throw_str, JsValue};
+
+macro_rules! tuples {
+    ($($len:literal => ($($i:tt $t:ident)*))*) => ($(
+        impl<$($t: WasmDescribe),*> WasmDescribe for ($($t,)*) {
+            #[cfg_attr(wasm_bindgen_unstable_test_coverage, coverage(off))]
+            fn describe() {
+                inform(TUPLE);
+                inform($len);
+                $(<$t>::describe();)*
+            }
+        }
+
+        impl<$($t),*> From<($($t,)*)> for JsValue
+        where
+            $(JsValue: From<$t>,)*
+        {
+            fn from(tuple: ($($t,)*)) -> JsValue {
+                let array = crate::__wbindgen_array_new();
+                $(crate::__wbindgen_reflect_set(&array, $i, JsValue::from(tuple.$i));)*
+                array
+            }
+        }
+
+        impl<$($t: TryFromJsValue),*> TryFromJsValue for ($($t,)*) {
+            fn try_from_js_value_ref(value: &JsValue) -> Option<Self> {
+                if !value.is_array()
+                    || crate::__wbindgen_reflect_get_str(value, "length").as_f64() != Some($len as f64)
+                {
+                    return None;
+                }
+                Some(($(
+                    <$t>::try_from_js_value(crate::__wbindgen_reflect_get(value, $i)).ok()?,
+                )*))
+            }
+        }
+
+        impl<$($t: WasmDescribe),*> IntoWasmAbi for ($($t,)*)
+        where
+            $(JsValue: From<$t>,)*
+        {
+            type Abi = u32;
+
+            #[inline]
+            fn into_abi(self) -> u32 {
+                <JsValue as From<Self>>::from(self).into_abi()
+            }
+        }
+
+        impl<$($t: WasmDescribe),*> OptionIntoWasmAbi for ($($t,)*)
+        where
+            $(JsValue: From<$t>,)*
+        {
+            #[inline]
+            fn none() -> u32 {
+                JsValue::none()
+            }
+        }
+
+        impl<$($t: WasmDescribe + TryFromJsValue),*> FromWasmAbi for ($($t,)*) {
+            type Abi = u32;
+
+            #[inline]
+            unsafe fn from_abi(js: u32) -> Self {
+                match Self::try_from_js_value(JsValue::from_abi(js)) {
+                    Ok(tuple) => tuple,
+                    Err(_) => throw_str(concat!("expected an array of ", $len, " elements")),
+                }
+            }
+        }
+
+        impl<$($t: WasmDescribe + TryFromJsValue),*> OptionFromWasmAbi for ($($t,)*) {
+            #[inline]
+            fn is_none(js: &u32) -> bool {
+                JsValue::is_none(js)
+            }
+        }
+    )*)
+}
+
+tuples! {
+    1 => (0 A)
+    2 => (0 A 1 B)
+    3 => (0 A 1 B 2 C)
+    4 => (0 A 1 B 2 C 3 D)
+    5 => (0 A 1 B 2 C 3 D 4 E)
+    6 => (0 A 1 B 2 C 3 D 4 E 5 F)
+    7 => (0 A 1 B 2 C 3 D 4 E 5 F 6 G)
+    8 => (0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H)
+}
@@ -0,0 +1,108 @@
+//! Exposing Rust iterators to JS as iterables.
+//!
+//! The iterator is moved into a closure which JS calls for each item, so it
+//! is only freed once the JS iterator is exhausted, closed with `return()` or
+//! garbage collected.
+
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::closure::Closure;
+use crate::convert::{IntoWasmAbi, OptionIntoWasmAbi};
+use crate::describe::{inform, WasmDescribe, ITERATOR};
+use crate::JsValue;
+
+/// A Rust iterator which is passed to JS as an iterable iterator.
+///
+/// Each item is converted with `JsValue::from` when JS requests it, for
+/// example from a `for...of` loop or the spread operator, and the generated
+/// TypeScript types it as `IterableIterator<T>`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use wasm_bindgen::prelude::*;
+/// use wasm_bindgen::JsIterator;
+///
+/// #[wasm_bindgen]
+/// pub fn squares(n: u32) -> JsIterator<u32> {
+///     JsIterator::new((0..n).map(|i| i * i))
+/// }
+/// ```
+///
+/// ```js
+/// for (const square of squares(4)) {
+///     console.log(square);
+/// }
+/// ```
+pub struct JsIterator<T> {
+    iter: Box<dyn Iterator<Item = T>>,
+}
+
+impl<T> JsIterator<T> {
+    /// Wraps an iterator, or anything that can be turned into one, to be
+    /// passed to JS.
+    pub fn new<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: 'static,
+    {
+        JsIterator {
+            iter: Box::new(iter.into_iter()),
+        }
+    }
+}
+
+impl<T> fmt::Debug for JsIterator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsIterator").finish_non_exhaustive()
+    }
+}
+
+impl<T: WasmDescribe> WasmDescribe for JsIterator<T> {
+    #[cfg_attr(wasm_bindgen_unstable_test_coverage, coverage(off))]
+    fn describe() {
+        inform(ITERATOR);
+        T::describe();
+    }
+}
+
+impl<T: 'static> From<JsIterator<T>> for JsValue
+where
+    JsValue: From<T>,
+{
+    fn from(iter: JsIterator<T>) -> JsValue {
+        let mut iter = iter.iter;
+        // JS passes a sentinel which is returned once the iterator is done,
+        // since any other value could also be an item.
+        let next = Closure::<dyn FnMut(JsValue) -> JsValue>::own_assert_unwind_safe(
+            move |done: JsValue| match iter.next() {
+                Some(item) => JsValue::from(item),
+                None => done,
+            },
+        );
+        crate::__wbindgen_make_iterator(next.into_js_value())
+    }
+}
+
+impl<T: WasmDescribe + 'static> IntoWasmAbi for JsIterator<T>
+where
+    JsValue: From<T>,
+{
+    type Abi = u32;
+
+    #[inline]
+    fn into_abi(self) -> u32 {
+        <JsValue as From<Self>>::from(self).into_abi()
+    }
+}
+
+impl<T: WasmDescribe + 'static> OptionIntoWasmAbi for JsIterator<T>
+where
+    JsValue: From<T>,
+{
+    #[inline]
+    fn none() -> u32 {
+        JsValue::none()
+    }
+}
@@ -141,6 +141,9 @@ pub use crate::convert::JsGeneric;
 mod cast;
 pub use crate::cast::JsCast;
 
+mod iterator;
+pub use crate::iterator::JsIterator;
+
 mod cache;
 pub use cache::intern::{intern, unintern};
 
@@ -1206,6 +1209,36 @@ extern "C" {
 
     #[wasm_bindgen(js_name = Number)]
     fn __wbindgen_as_number(v: &JsValue) -> f64;
+
+    #[wasm_bindgen(js_name = Array)]
+    fn __wbindgen_array_new() -> JsValue;
+
+    #[wasm_bindgen(js_namespace = Reflect, js_name = get)]
+    fn __wbindgen_reflect_get(target: &JsValue, key: u32) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = Reflect, js_name = set)]
+    fn __wbindgen_reflect_set(target: &JsValue, key: u32, value: JsValue);
+
+    #[wasm_bindgen(js_namespace = Array, js_name = from)]
+    fn __wbindgen_array_from(v: &JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_name = Object)]
+    fn __wbindgen_object_new() -> JsValue;
+
+    #[wasm_bindgen(js_namespace = Reflect, js_name = get)]
+    fn __wbindgen_reflect_get_str(target: &JsValue, key: &str) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = Reflect, js_name = set)]
+    fn __wbindgen_reflect_set_str(target: &JsValue, key: &str, value: JsValue);
+
+    #[wasm_bindgen(js_name = Map)]
+    type __WbindgenMap;
+
+    #[wasm_bindgen(constructor, js_class = "Map")]
+    fn new() -> __WbindgenMap;
+
+    #[wasm_bindgen(method, js_class = "Map")]
+    fn set(this: &__WbindgenMap, key: JsValue, value: JsValue);
 }
 
 // Intrinsics which are handled by cli-support but for which we can use
@@ -1270,6 +1303,9 @@ extern "C" {
     fn __wbindgen_memory() -> JsValue;
     fn __wbindgen_module() -> JsValue;
     fn __wbindgen_function_table() -> JsValue;
+
+    fn __wbindgen_make_iterator(next: JsValue) -> JsValue;
+    fn __wbindgen_make_async_iterator(next: JsValue) -> JsValue;
 }
 
 // Intrinsics that have to use raw imports because they're matched by other
@@ -1,5 +1,5 @@
-use crate::convert::{FromWasmAbi, IntoWasmAbi, WasmAbi, WasmRet};
-use crate::describe::inform;
+use crate::convert::{FromWasmAbi, IntoWasmAbi, TryFromJsValue, WasmAbi, WasmRet};
+use crate::describe::{inform, WasmDescribe};
 use crate::JsValue;
 #[cfg(all(target_arch = "wasm32", feature = "std", panic = "unwind"))]
 use core::any::Any;
@@ -8,11 +8,12 @@ use core::borrow::{Borrow, BorrowMut};
 use core::cell::UnsafeCell;
 use core::cell::{Cell, RefCell};
 use core::convert::Infallible;
+use core::marker::PhantomData;
 use core::ops::{Deref, DerefMut};
 use core::panic::{RefUnwindSafe, UnwindSafe};
 #[cfg(target_feature = "atomics")]
 use core::sync::atomic::{AtomicU8, Ordering};
-use wasm_bindgen_shared::tys::FUNCTION;
+use wasm_bindgen_shared::tys::{FUNCTION, UNIT};
 
 use alloc::alloc::{alloc, dealloc, realloc, Layout};
 use alloc::rc::Rc;
@@ -277,6 +278,92 @@ pub fn assert_not_null<T>(s: *mut T) {
     }
 }
 
+/// Creates the object a `#[wasm_bindgen(plain_object)]` struct is converted
+/// into.
+#[inline]
+pub fn plain_object_new() -> JsValue {
+    crate::__wbindgen_object_new()
+}
+
+/// Sets the field `name` of a `plain_object` struct on its JS object.
+#[inline]
+pub fn plain_object_set<T>(obj: &JsValue, name: &str, value: T)
+where
+    JsValue: From<T>,
+{
+    crate::__wbindgen_reflect_set_str(obj, name, value.into());
+}
+
+/// Reads the field `name` of a `plain_object` struct from a JS object,
+/// returning `None` if it can't be converted.
+#[inline]
+pub fn plain_object_get<T: TryFromJsValue>(obj: &JsValue, name: &str) -> Option<T> {
+    T::try_from_js_value(crate::__wbindgen_reflect_get_str(obj, name)).ok()
+}
+
+/// Wraps a closure returning a `Promise` for the next item of a Rust stream,
+/// or the sentinel it's called with once the stream is done, in a JS async
+/// iterable iterator.
+#[inline]
+pub fn make_async_iterator(next: JsValue) -> JsValue {
+    crate::__wbindgen_make_async_iterator(next)
+}
+
+/// Implemented by `#[wasm_bindgen(error)]` enums, which are converted to
+/// instances of a generated JS `Error` subclass.
+pub trait WasmError: WasmDescribe {}
+
+/// Describes the error class an export with the return type `R` can throw.
+///
+/// Method resolution picks [`DescribeThrowsError`] for `&&Throws<R>` if `R`
+/// is a `Result` whose error is a [`WasmError`], and otherwise falls back to
+/// [`DescribeThrowsNothing`].
+pub struct Throws<R>(pub PhantomData<R>);
+
+pub trait DescribeThrowsError {
+    fn describe_throws(&self);
+}
+
+impl<T, E: WasmError> DescribeThrowsError for &Throws<Result<T, E>> {
+    fn describe_throws(&self) {
+        E::describe();
+    }
+}
+
+pub trait DescribeThrowsNothing {
+    fn describe_throws(&self) {
+        inform(UNIT);
+    }
+}
+
+impl<R> DescribeThrowsNothing for Throws<R> {}
+
+/// Creates the `cause` of the JS error a `#[wasm_bindgen(error)]` enum is
+/// converted into, with a JS `Error` for each `source` of the Rust error.
+#[cfg(feature = "std")]
+pub fn error_cause(error: &dyn std::error::Error) -> JsValue {
+    match error.source() {
+        Some(source) => {
+            let cause = JsValue::from(crate::JsError::new(&alloc::string::ToString::to_string(
+                source,
+            )));
+            let next = error_cause(source);
+            if !next.is_undefined() {
+                crate::__wbindgen_reflect_set_str(&cause, "cause", next);
+            }
+            cause
+        }
+        None => JsValue::UNDEFINED,
+    }
+}
+
+/// Without `std` errors have no `source` chain, so the JS error has no
+/// `cause`.
+#[cfg(not(feature = "std"))]
+pub fn error_cause<E: ?Sized>(_error: &E) -> JsValue {
+    JsValue::UNDEFINED
+}
+
 #[cold]
 #[inline(never)]
 fn throw_null() -> ! {
@@ -0,0 +1,48 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_thrown_errors = () => {
+    assert.strictEqual(wasm.errors_parse('42'), 42);
+
+    assert.throws(() => wasm.errors_parse(''), err => {
+        assert.ok(err instanceof wasm.ParseError);
+        assert.ok(err instanceof Error);
+        assert.strictEqual(err.name, 'ParseError');
+        assert.strictEqual(err.kind, 'Empty');
+        assert.strictEqual(err.message, 'empty input');
+        assert.ok(!('cause' in err));
+        return true;
+    });
+
+    assert.throws(() => wasm.errors_parse('4x'), err => {
+        assert.ok(err instanceof wasm.ParseError);
+        assert.strictEqual(err.kind, 'InvalidDigit');
+        assert.strictEqual(err.message, 'invalid digit `x`');
+        return true;
+    });
+
+    assert.throws(() => wasm.errors_fail(), err => {
+        assert.ok(err instanceof wasm.RenamedError);
+        assert.strictEqual(err.name, 'RenamedError');
+        assert.strictEqual(err.kind, 'Oops');
+        assert.strictEqual(err.message, 'oops');
+        return true;
+    });
+};
+
+exports.js_error_causes = () => {
+    assert.throws(() => wasm.errors_read(), err => {
+        assert.ok(err instanceof wasm.ParseError);
+        assert.strictEqual(err.kind, 'Io');
+        assert.strictEqual(err.message, 'failed to read input');
+        assert.ok(err.cause instanceof Error);
+        assert.strictEqual(err.cause.message, 'disk unplugged');
+        return true;
+    });
+};
+
+exports.js_error_into_js_value = err => {
+    assert.ok(err instanceof wasm.ParseError);
+    assert.strictEqual(err.kind, 'InvalidDigit');
+    assert.strictEqual(err.message, 'invalid digit `x`');
+};
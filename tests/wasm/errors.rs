@@ -0,0 +1,99 @@
+use std::fmt;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/errors.js")]
+extern "C" {
+    fn js_thrown_errors();
+    fn js_error_causes();
+    fn js_error_into_js_value(err: JsValue);
+}
+
+#[derive(Debug)]
+pub struct IoError;
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("disk unplugged")
+    }
+}
+
+impl std::error::Error for IoError {}
+
+/// Errors raised while parsing numbers.
+#[wasm_bindgen(error)]
+#[derive(Debug)]
+pub enum ParseError {
+    Empty,
+    InvalidDigit(char),
+    Io { inner: IoError },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => f.write_str("empty input"),
+            ParseError::InvalidDigit(c) => write!(f, "invalid digit `{c}`"),
+            ParseError::Io { .. } => f.write_str("failed to read input"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Io { inner } => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[wasm_bindgen(error, js_name = RenamedError)]
+#[derive(Debug)]
+pub enum Failure {
+    Oops,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("oops")
+    }
+}
+
+impl std::error::Error for Failure {}
+
+#[wasm_bindgen]
+pub fn errors_parse(input: &str) -> Result<u32, ParseError> {
+    if input.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    input.chars().try_fold(0, |acc, c| match c.to_digit(10) {
+        Some(d) => Ok(acc * 10 + d),
+        None => Err(ParseError::InvalidDigit(c)),
+    })
+}
+
+#[wasm_bindgen]
+pub fn errors_read() -> Result<(), ParseError> {
+    Err(ParseError::Io { inner: IoError })
+}
+
+#[wasm_bindgen]
+pub fn errors_fail() -> Result<(), Failure> {
+    Err(Failure::Oops)
+}
+
+#[wasm_bindgen_test]
+fn thrown_errors() {
+    js_thrown_errors();
+}
+
+#[wasm_bindgen_test]
+fn error_causes() {
+    js_error_causes();
+}
+
+#[wasm_bindgen_test]
+fn error_into_js_value() {
+    js_error_into_js_value(ParseError::InvalidDigit('x').into());
+}
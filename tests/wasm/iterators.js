@@ -0,0 +1,36 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_sum = iter => {
+    let sum = 0;
+    for (const n of iter) {
+        sum += n;
+    }
+    assert.deepStrictEqual(iter.next(), { done: true, value: undefined });
+    return sum;
+};
+
+exports.js_works = () => {
+    assert.deepStrictEqual([...wasm.rust_squares(5)], [0, 1, 4, 9, 16]);
+    assert.deepStrictEqual(Array.from(wasm.rust_words(' a bc  d ')), ['a', 'bc', 'd']);
+    assert.deepStrictEqual([...wasm.rust_maybe_iter(false)], [1, undefined, 3]);
+    assert.strictEqual(wasm.rust_maybe_iter(true), undefined);
+
+    const squares = wasm.rust_squares(3);
+    assert.strictEqual(squares[Symbol.iterator](), squares);
+    assert.deepStrictEqual(squares.next(), { done: false, value: 0 });
+    assert.deepStrictEqual(squares.next(), { done: false, value: 1 });
+    assert.deepStrictEqual(squares.next(), { done: false, value: 4 });
+    assert.deepStrictEqual(squares.next(), { done: true, value: undefined });
+    assert.deepStrictEqual(squares.next(), { done: true, value: undefined });
+
+    // Breaking out of a loop calls `return()`, which frees the iterator.
+    const taken = [];
+    const endless = wasm.rust_endless();
+    for (const n of endless) {
+        if (n === 3) break;
+        taken.push(n);
+    }
+    assert.deepStrictEqual(taken, [0, 1, 2]);
+    assert.deepStrictEqual(endless.next(), { done: true, value: undefined });
+};
@@ -0,0 +1,45 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsIterator;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/iterators.js")]
+extern "C" {
+    fn js_sum(iter: JsIterator<u32>) -> u32;
+    fn js_works();
+}
+
+#[wasm_bindgen]
+pub fn rust_squares(n: u32) -> JsIterator<u32> {
+    JsIterator::new((0..n).map(|i| i * i))
+}
+
+#[wasm_bindgen]
+pub fn rust_words(text: String) -> JsIterator<String> {
+    let words: Vec<String> = text.split_whitespace().map(String::from).collect();
+    JsIterator::new(words)
+}
+
+#[wasm_bindgen]
+pub fn rust_maybe_iter(empty: bool) -> Option<JsIterator<Option<u8>>> {
+    if empty {
+        None
+    } else {
+        Some(JsIterator::new([Some(1), None, Some(3)]))
+    }
+}
+
+#[wasm_bindgen]
+pub fn rust_endless() -> JsIterator<f64> {
+    JsIterator::new((0..).map(f64::from))
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+}
+
+#[wasm_bindgen_test]
+fn to_js() {
+    assert_eq!(js_sum(JsIterator::new(vec![1, 2, 3, 4])), 10);
+    assert_eq!(js_sum(JsIterator::new(std::iter::empty())), 0);
+}
@@ -28,6 +28,7 @@ pub mod duplicate_deps;
 pub mod duplicates;
 pub mod enum_vecs;
 pub mod enums;
+pub mod errors;
 #[path = "final.rs"]
 pub mod final_;
 pub mod futures;
@@ -39,6 +40,7 @@ pub mod import_class;
 pub mod imports;
 pub mod inner_self;
 pub mod intrinsics;
+pub mod iterators;
 pub mod js_keywords;
 pub mod js_namespace_exports;
 pub mod js_objects;
@@ -46,6 +48,7 @@ pub mod js_vec;
 pub mod jscast;
 pub mod link_to;
 pub mod macro_rules;
+pub mod maps;
 pub mod math;
 pub mod memory_growth;
 pub mod no_shims;
@@ -53,6 +56,7 @@ pub mod node;
 pub mod nullable;
 pub mod option;
 pub mod optional_primitives;
+pub mod plain_object;
 pub mod reexport;
 pub mod result;
 pub mod result_jserror;
@@ -64,8 +68,10 @@ pub mod slice_jsvalue;
 pub mod string_vecs;
 pub mod struct_vecs;
 pub mod structural;
+pub mod traits;
 pub mod truthy_falsy;
 pub mod try_from_js_value;
+pub mod tuples;
 #[cfg(all(panic = "unwind", feature = "std"))]
 pub mod unwind;
 pub mod usize;
@@ -0,0 +1,32 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_identity = map => {
+    assert.ok(map instanceof Map);
+    return map;
+};
+
+exports.js_object = obj => {
+    assert.deepStrictEqual(obj, { 1: 'one', 2: 'two' });
+    return obj;
+};
+
+exports.js_optional_object = () => ({ yes: true });
+
+exports.js_works = () => {
+    assert.deepStrictEqual(wasm.rust_map(), new Map([['a', 1], ['b', 2]]));
+
+    const map = new Map([[1n, [true, 'one']], [-2n, [false, 'two']]]);
+    assert.deepStrictEqual(wasm.rust_map_identity(map), map);
+
+    const nested = new Map([['a', new Map([[1, 1.5]])], ['b', new Map()]]);
+    assert.deepStrictEqual(wasm.rust_map_nested(nested), nested);
+
+    assert.deepStrictEqual(wasm.rust_object({ 3: 'three', 1: 'one' }), { 1: 'one', 3: 'three' });
+    assert.deepStrictEqual(wasm.rust_bigint_keys({ 10: 1 }), { 10: 1 });
+    assert.strictEqual(wasm.rust_bigint_keys(undefined), undefined);
+    assert.strictEqual(wasm.rust_bigint_keys(null), undefined);
+
+    assert.throws(() => wasm.rust_map_identity({}), /expected a Map/);
+    assert.throws(() => wasm.rust_map_identity(new Map([['a', [true, 'a']]])), /expected a Map/);
+};
@@ -0,0 +1,59 @@
+use std::collections::{BTreeMap, HashMap};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/maps.js")]
+extern "C" {
+    fn js_identity(map: HashMap<String, u32>) -> HashMap<String, u32>;
+    #[wasm_bindgen(map_as = "object")]
+    fn js_object(map: BTreeMap<u32, String>) -> BTreeMap<u32, String>;
+    #[wasm_bindgen(map_as = "object")]
+    fn js_optional_object() -> Option<HashMap<String, bool>>;
+    fn js_works();
+}
+
+#[wasm_bindgen]
+pub fn rust_map() -> BTreeMap<String, u32> {
+    BTreeMap::from([("a".to_string(), 1), ("b".to_string(), 2)])
+}
+
+#[wasm_bindgen]
+pub fn rust_map_identity(map: HashMap<i64, (bool, String)>) -> HashMap<i64, (bool, String)> {
+    map
+}
+
+#[wasm_bindgen]
+pub fn rust_map_nested(
+    map: BTreeMap<String, BTreeMap<u8, f64>>,
+) -> BTreeMap<String, BTreeMap<u8, f64>> {
+    map
+}
+
+#[wasm_bindgen(map_as = "object")]
+pub fn rust_object(map: BTreeMap<u32, String>) -> BTreeMap<u32, String> {
+    map
+}
+
+#[wasm_bindgen(map_as = "object")]
+pub fn rust_bigint_keys(map: Option<HashMap<u64, u32>>) -> Option<HashMap<u64, u32>> {
+    map
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+}
+
+#[wasm_bindgen_test]
+fn from_js() {
+    let map = HashMap::from([("x".to_string(), 1), ("y".to_string(), 2)]);
+    assert_eq!(js_identity(map.clone()), map);
+
+    let map = BTreeMap::from([(1, "one".to_string()), (2, "two".to_string())]);
+    assert_eq!(js_object(map.clone()), map);
+
+    assert_eq!(
+        js_optional_object(),
+        Some(HashMap::from([("yes".to_string(), true)]))
+    );
+}
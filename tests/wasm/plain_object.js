@@ -0,0 +1,58 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_identity = config => {
+    assert.strictEqual(Object.getPrototypeOf(config), Object.prototype);
+    assert.strictEqual(config.maxRetries, 7);
+    assert.deepStrictEqual(config.target, { x: 5, y: 5 });
+    return JSON.parse(JSON.stringify(config));
+};
+
+exports.js_make_point = () => ({ x: 1, y: 2 });
+
+exports.js_works = () => {
+    const config = wasm.rust_default_config();
+    assert.deepStrictEqual(config, {
+        name: 'default',
+        maxRetries: 3,
+        verbose: false,
+        origin: { x: 0, y: 0 },
+        target: undefined,
+        label: undefined,
+    });
+    assert.ok(!Object.isFrozen(config));
+    assert.strictEqual(config.free, undefined);
+
+    config.target = { x: 3, y: 4 };
+    delete config.label;
+    assert.deepStrictEqual(wasm.rust_config_identity(config), {
+        name: 'default',
+        maxRetries: 3,
+        verbose: false,
+        origin: { x: 0, y: 0 },
+        target: { x: 3, y: 4 },
+        label: undefined,
+    });
+
+    assert.strictEqual(wasm.rust_distance({ x: 3, y: 4 }), 5);
+    assert.strictEqual(wasm.rust_distance({ x: 4, y: 6 }, { x: 1, y: 2 }), 5);
+
+    assert.deepStrictEqual(
+        wasm.rust_pairs([{ 0: 1, 1: 'a' }, { 0: 2, 1: 'b' }]),
+        [{ 0: 2, 1: 'b' }, { 0: 1, 1: 'a' }],
+    );
+
+    assert.throws(() => wasm.rust_distance(null), /expected a `Point` object/);
+    assert.throws(
+        () => wasm.rust_distance({ x: 1 }),
+        /expected field `y` of a `Point` object to be a `f64`/,
+    );
+    assert.throws(
+        () => wasm.rust_config_identity({ ...config, origin: 'nowhere' }),
+        /expected field `origin` of a `Config` object to be a `Point`/,
+    );
+    assert.throws(
+        () => wasm.rust_config_identity({ ...config, label: 1 }),
+        /expected field `label` of a `Config` object to be a `Option<String>`/,
+    );
+};
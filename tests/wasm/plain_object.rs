@@ -0,0 +1,80 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/plain_object.js")]
+extern "C" {
+    fn js_identity(config: Config) -> Config;
+    fn js_make_point() -> Point;
+    fn js_works();
+}
+
+#[wasm_bindgen(plain_object)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[wasm_bindgen(plain_object)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub name: String,
+    #[wasm_bindgen(js_name = maxRetries)]
+    pub max_retries: u32,
+    pub verbose: bool,
+    pub origin: Point,
+    pub target: Option<Point>,
+    pub label: Option<String>,
+}
+
+#[wasm_bindgen(plain_object)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pair(pub i32, pub String);
+
+#[wasm_bindgen]
+pub fn rust_default_config() -> Config {
+    Config {
+        name: "default".to_string(),
+        max_retries: 3,
+        verbose: false,
+        origin: Point { x: 0.0, y: 0.0 },
+        target: None,
+        label: None,
+    }
+}
+
+#[wasm_bindgen]
+pub fn rust_config_identity(config: Config) -> Config {
+    config
+}
+
+#[wasm_bindgen]
+pub fn rust_distance(a: Point, b: Option<Point>) -> f64 {
+    let b = b.unwrap_or(Point { x: 0.0, y: 0.0 });
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
+
+#[wasm_bindgen]
+pub fn rust_pairs(pairs: Vec<Pair>) -> Vec<Pair> {
+    pairs.into_iter().rev().collect()
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+}
+
+#[wasm_bindgen_test]
+fn from_js() {
+    assert_eq!(js_make_point(), Point { x: 1.0, y: 2.0 });
+
+    let config = Config {
+        name: "from rust".to_string(),
+        max_retries: 7,
+        verbose: true,
+        origin: Point { x: 1.0, y: -1.0 },
+        target: Some(Point { x: 5.0, y: 5.0 }),
+        label: Some("label".to_string()),
+    };
+    assert_eq!(js_identity(config.clone()), config);
+}
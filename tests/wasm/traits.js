@@ -0,0 +1,31 @@
+const messages = [];
+
+exports.js_logger = () => ({
+    log(msg) {
+        messages.push(msg);
+    },
+    logCount() {
+        return messages.length;
+    },
+});
+
+exports.js_logged_messages = () => messages;
+
+class Calculator {
+    add(a, b) {
+        return a + b;
+    }
+
+    name() {
+        return 'calc';
+    }
+
+    divide(a, b) {
+        if (b === 0) {
+            throw new Error('division by zero');
+        }
+        return a / b;
+    }
+}
+
+exports.js_calculator = () => new Calculator();
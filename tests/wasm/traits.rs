@@ -0,0 +1,44 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/traits.js")]
+extern "C" {
+    fn js_logger() -> JsLogger;
+    fn js_calculator() -> JsCalculator;
+    fn js_logged_messages() -> Vec<String>;
+}
+
+#[wasm_bindgen]
+pub trait Logger {
+    fn log(&self, msg: &str);
+    #[wasm_bindgen(js_name = logCount)]
+    fn log_count(&self) -> u32;
+}
+
+#[wasm_bindgen]
+pub trait Calculator {
+    fn add(&self, a: f64, b: f64) -> f64;
+    fn name(&self) -> Option<String>;
+    #[wasm_bindgen(catch)]
+    fn divide(&self, a: f64, b: f64) -> Result<f64, JsValue>;
+}
+
+fn log_all(logger: &dyn Logger, messages: &[&str]) -> u32 {
+    for msg in messages {
+        logger.log(msg);
+    }
+    logger.log_count()
+}
+
+#[wasm_bindgen_test]
+fn js_objects_implement_traits() {
+    let logger = js_logger();
+    assert_eq!(log_all(&logger, &["a", "b"]), 2);
+    assert_eq!(js_logged_messages(), ["a", "b"]);
+
+    let calculator = js_calculator();
+    assert_eq!(calculator.add(1.0, 2.5), 3.5);
+    assert_eq!(calculator.name(), Some("calc".to_string()));
+    assert_eq!(calculator.divide(6.0, 3.0).unwrap(), 2.0);
+    assert!(calculator.divide(1.0, 0.0).is_err());
+}
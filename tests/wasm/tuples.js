@@ -0,0 +1,27 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_identity = t => t;
+exports.js_nested = () => [true, [-5n, undefined]];
+exports.js_optional = t => t;
+
+exports.js_works = () => {
+    assert.deepStrictEqual(wasm.rust_pair(), [1, 'one']);
+    assert.deepStrictEqual(wasm.rust_tuple_identity([2, 'two']), [2, 'two']);
+    assert.deepStrictEqual(wasm.rust_tuple_nested([false, [10n, 1.5]]), [false, [10n, 1.5]]);
+    assert.deepStrictEqual(wasm.rust_tuple_nested([true, [0n, undefined]]), [true, [0n, undefined]]);
+
+    const obj = {};
+    const [num, value] = wasm.rust_optional([7, obj]);
+    assert.strictEqual(num, 7);
+    assert.strictEqual(value, obj);
+    assert.strictEqual(wasm.rust_optional(undefined), undefined);
+    assert.strictEqual(wasm.rust_optional(null), undefined);
+
+    assert.deepStrictEqual(wasm.rust_result(true), [2, 'two']);
+    assert.throws(() => wasm.rust_result(false), /not ok/);
+
+    assert.throws(() => wasm.rust_tuple_identity('not an array'), /expected an array of 2 elements/);
+    assert.throws(() => wasm.rust_tuple_identity([1]), /expected an array of 2 elements/);
+    assert.throws(() => wasm.rust_tuple_identity([1, 'one', 'extra']), /expected an array of 2 elements/);
+};
@@ -0,0 +1,52 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/tuples.js")]
+extern "C" {
+    fn js_identity(t: (u32, String)) -> (u32, String);
+    fn js_nested() -> (bool, (i64, Option<f64>));
+    fn js_optional(t: Option<(String,)>) -> Option<(String,)>;
+    fn js_works();
+}
+
+#[wasm_bindgen]
+pub fn rust_pair() -> (u32, String) {
+    (1, "one".to_string())
+}
+
+#[wasm_bindgen]
+pub fn rust_tuple_identity(t: (u32, String)) -> (u32, String) {
+    t
+}
+
+#[wasm_bindgen]
+pub fn rust_tuple_nested(t: (bool, (i64, Option<f64>))) -> (bool, (i64, Option<f64>)) {
+    t
+}
+
+#[wasm_bindgen]
+pub fn rust_optional(t: Option<(u8, JsValue)>) -> Option<(u8, JsValue)> {
+    t
+}
+
+#[wasm_bindgen]
+pub fn rust_result(ok: bool) -> Result<(u32, &'static str), JsError> {
+    if ok {
+        Ok((2, "two"))
+    } else {
+        Err(JsError::new("not ok"))
+    }
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+}
+
+#[wasm_bindgen_test]
+fn from_js() {
+    assert_eq!(js_identity((3, "three".into())), (3, "three".into()));
+    assert_eq!(js_nested(), (true, (-5, None)));
+    assert_eq!(js_optional(Some(("x".into(),))), Some(("x".into(),)));
+    assert_eq!(js_optional(None), None);
+}